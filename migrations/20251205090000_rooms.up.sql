@@ -0,0 +1,31 @@
+-- Room types table
+CREATE TABLE IF NOT EXISTS room_types (
+    id SERIAL PRIMARY KEY,
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    capacity INT NOT NULL CHECK (capacity > 0),
+    bed_configuration VARCHAR(100) NOT NULL,
+    base_price DECIMAL(10, 2) NOT NULL CHECK (base_price >= 0),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Rooms table
+CREATE TABLE IF NOT EXISTS rooms (
+    id SERIAL PRIMARY KEY,
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    room_type_id INT NOT NULL REFERENCES room_types(id) ON DELETE CASCADE,
+    room_number VARCHAR(20) NOT NULL,
+    floor INT,
+    status VARCHAR(20) NOT NULL DEFAULT 'available'
+        CHECK (status IN ('available', 'occupied', 'maintenance', 'out_of_service')),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (hotel_id, room_number)
+);
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_room_types_hotel_id ON room_types(hotel_id);
+CREATE INDEX IF NOT EXISTS idx_rooms_hotel_id ON rooms(hotel_id);
+CREATE INDEX IF NOT EXISTS idx_rooms_room_type_id ON rooms(room_type_id);
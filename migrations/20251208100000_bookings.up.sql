@@ -0,0 +1,21 @@
+-- Bookings table
+CREATE TABLE IF NOT EXISTS bookings (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    room_type_id INT NOT NULL REFERENCES room_types(id) ON DELETE CASCADE,
+    room_id INT REFERENCES rooms(id) ON DELETE SET NULL,
+    check_in DATE NOT NULL,
+    check_out DATE NOT NULL,
+    guests INT NOT NULL CHECK (guests > 0),
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'confirmed', 'checked_in', 'checked_out', 'cancelled', 'no_show')),
+    total_price DECIMAL(10, 2) NOT NULL CHECK (total_price >= 0),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (check_out > check_in)
+);
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_bookings_user_id ON bookings(user_id);
+CREATE INDEX IF NOT EXISTS idx_bookings_room_type_dates ON bookings(room_type_id, check_in, check_out);
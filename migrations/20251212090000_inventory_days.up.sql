@@ -0,0 +1,20 @@
+-- Per-day inventory counters and restrictions for each room type
+CREATE TABLE IF NOT EXISTS inventory_days (
+    room_type_id INT NOT NULL REFERENCES room_types(id) ON DELETE CASCADE,
+    day DATE NOT NULL,
+    sold INT NOT NULL DEFAULT 0 CHECK (sold >= 0),
+    held INT NOT NULL DEFAULT 0 CHECK (held >= 0),
+    stop_sell BOOLEAN NOT NULL DEFAULT FALSE,
+    closed_to_arrival BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (room_type_id, day)
+);
+
+-- Backfill counters from active bookings
+INSERT INTO inventory_days (room_type_id, day, sold)
+SELECT b.room_type_id, nights.day::DATE, COUNT(*)
+FROM bookings b
+CROSS JOIN LATERAL generate_series(b.check_in, b.check_out - 1, INTERVAL '1 day') AS nights(day)
+WHERE b.status IN ('pending', 'confirmed', 'checked_in')
+GROUP BY b.room_type_id, nights.day
+ON CONFLICT (room_type_id, day) DO NOTHING;
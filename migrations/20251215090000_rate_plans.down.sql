@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS booking_nights;
+ALTER TABLE bookings DROP COLUMN IF EXISTS rate_plan_id;
+DROP TABLE IF EXISTS rate_plan_seasons;
+DROP TABLE IF EXISTS rate_plans;
@@ -0,0 +1,45 @@
+-- Rate plans sold on top of a room type
+CREATE TABLE IF NOT EXISTS rate_plans (
+    id SERIAL PRIMARY KEY,
+    room_type_id INT NOT NULL REFERENCES room_types(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    base_price DECIMAL(10, 2) NOT NULL CHECK (base_price >= 0),
+    weekend_price DECIMAL(10, 2) CHECK (weekend_price >= 0),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Date-ranged seasonal prices, end date inclusive
+CREATE TABLE IF NOT EXISTS rate_plan_seasons (
+    id SERIAL PRIMARY KEY,
+    rate_plan_id INT NOT NULL REFERENCES rate_plans(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    price DECIMAL(10, 2) NOT NULL CHECK (price >= 0),
+    weekend_price DECIMAL(10, 2) CHECK (weekend_price >= 0),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (end_date >= start_date)
+);
+
+-- Priced nights stored with each booking
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS rate_plan_id INT REFERENCES rate_plans(id) ON DELETE SET NULL;
+
+CREATE TABLE IF NOT EXISTS booking_nights (
+    booking_id INT NOT NULL REFERENCES bookings(id) ON DELETE CASCADE,
+    night DATE NOT NULL,
+    price DECIMAL(10, 2) NOT NULL CHECK (price >= 0),
+    PRIMARY KEY (booking_id, night)
+);
+
+-- Backfill nights for existing bookings from their stored total
+INSERT INTO booking_nights (booking_id, night, price)
+SELECT b.id, nights.day::DATE, ROUND(b.total_price / (b.check_out - b.check_in), 2)
+FROM bookings b
+CROSS JOIN LATERAL generate_series(b.check_in, b.check_out - 1, INTERVAL '1 day') AS nights(day)
+ON CONFLICT (booking_id, night) DO NOTHING;
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_rate_plans_room_type_id ON rate_plans(room_type_id);
+CREATE INDEX IF NOT EXISTS idx_rate_plan_seasons_rate_plan_id ON rate_plan_seasons(rate_plan_id, start_date);
@@ -0,0 +1,5 @@
+ALTER TABLE bookings DROP COLUMN IF EXISTS quote_id;
+DROP TABLE IF EXISTS quotes;
+DROP TABLE IF EXISTS hotel_fees;
+ALTER TABLE hotels DROP COLUMN IF EXISTS vat_percent;
+ALTER TABLE hotels DROP COLUMN IF EXISTS city_tax_per_person_night;
@@ -0,0 +1,38 @@
+-- Per-hotel tax configuration
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS city_tax_per_person_night DECIMAL(10, 2) NOT NULL DEFAULT 0
+    CHECK (city_tax_per_person_night >= 0);
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS vat_percent DECIMAL(5, 2) NOT NULL DEFAULT 0
+    CHECK (vat_percent >= 0 AND vat_percent <= 100);
+
+-- Fixed per-stay fees
+CREATE TABLE IF NOT EXISTS hotel_fees (
+    id SERIAL PRIMARY KEY,
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    amount DECIMAL(10, 2) NOT NULL CHECK (amount >= 0),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Priced quotes that bookings can reference
+CREATE TABLE IF NOT EXISTS quotes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    room_type_id INT NOT NULL REFERENCES room_types(id) ON DELETE CASCADE,
+    rate_plan_id INT REFERENCES rate_plans(id) ON DELETE CASCADE,
+    check_in DATE NOT NULL,
+    check_out DATE NOT NULL,
+    guests INT NOT NULL CHECK (guests > 0),
+    lines JSONB NOT NULL,
+    room_total DECIMAL(10, 2) NOT NULL,
+    fees_total DECIMAL(10, 2) NOT NULL,
+    taxes_total DECIMAL(10, 2) NOT NULL,
+    total_price DECIMAL(10, 2) NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS quote_id UUID REFERENCES quotes(id) ON DELETE SET NULL;
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_hotel_fees_hotel_id ON hotel_fees(hotel_id);
+CREATE INDEX IF NOT EXISTS idx_quotes_expires_at ON quotes(expires_at);
@@ -0,0 +1,11 @@
+ALTER TABLE bookings DROP COLUMN IF EXISTS cancelled_at;
+ALTER TABLE bookings DROP COLUMN IF EXISTS refund_amount;
+ALTER TABLE bookings DROP COLUMN IF EXISTS penalty_amount;
+ALTER TABLE bookings DROP COLUMN IF EXISTS penalty_percent;
+ALTER TABLE bookings DROP COLUMN IF EXISTS cancellation_penalty;
+ALTER TABLE bookings DROP COLUMN IF EXISTS free_cancellation_hours;
+ALTER TABLE rate_plans DROP COLUMN IF EXISTS penalty_percent;
+ALTER TABLE rate_plans DROP COLUMN IF EXISTS cancellation_penalty;
+ALTER TABLE rate_plans DROP COLUMN IF EXISTS free_cancellation_hours;
+ALTER TABLE hotels DROP COLUMN IF EXISTS check_in_time;
+ALTER TABLE hotels DROP COLUMN IF EXISTS timezone;
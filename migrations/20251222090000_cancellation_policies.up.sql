@@ -0,0 +1,19 @@
+-- Hotel local time used for cancellation deadlines
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS timezone VARCHAR(64) NOT NULL DEFAULT 'UTC';
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS check_in_time TIME NOT NULL DEFAULT '15:00';
+
+-- Cancellation policy of each rate plan
+ALTER TABLE rate_plans ADD COLUMN IF NOT EXISTS free_cancellation_hours INT NOT NULL DEFAULT 24
+    CHECK (free_cancellation_hours >= 0);
+ALTER TABLE rate_plans ADD COLUMN IF NOT EXISTS cancellation_penalty VARCHAR(20) NOT NULL DEFAULT 'first_night'
+    CHECK (cancellation_penalty IN ('first_night', 'percentage', 'non_refundable'));
+ALTER TABLE rate_plans ADD COLUMN IF NOT EXISTS penalty_percent DECIMAL(5, 2)
+    CHECK (penalty_percent >= 0 AND penalty_percent <= 100);
+
+-- Policy snapshot and cancellation outcome stored on the booking
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS free_cancellation_hours INT NOT NULL DEFAULT 24;
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS cancellation_penalty VARCHAR(20) NOT NULL DEFAULT 'first_night';
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS penalty_percent DECIMAL(5, 2);
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS penalty_amount DECIMAL(10, 2);
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS refund_amount DECIMAL(10, 2);
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS cancelled_at TIMESTAMP;
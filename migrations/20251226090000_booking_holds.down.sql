@@ -0,0 +1,2 @@
+ALTER TABLE bookings DROP COLUMN IF EXISTS hold_id;
+DROP TABLE IF EXISTS booking_holds;
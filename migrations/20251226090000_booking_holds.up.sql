@@ -0,0 +1,22 @@
+-- Short-lived inventory holds taken during checkout
+CREATE TABLE IF NOT EXISTS booking_holds (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id INT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    room_type_id INT NOT NULL REFERENCES room_types(id) ON DELETE CASCADE,
+    check_in DATE NOT NULL,
+    check_out DATE NOT NULL,
+    guests INT NOT NULL CHECK (guests > 0),
+    status VARCHAR(20) NOT NULL DEFAULT 'active'
+        CHECK (status IN ('active', 'consumed', 'released', 'expired')),
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (check_out > check_in)
+);
+
+ALTER TABLE bookings ADD COLUMN IF NOT EXISTS hold_id UUID REFERENCES booking_holds(id) ON DELETE SET NULL;
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_booking_holds_user_id ON booking_holds(user_id);
+CREATE INDEX IF NOT EXISTS idx_booking_holds_active_expires_at ON booking_holds(expires_at) WHERE status = 'active';
@@ -0,0 +1,15 @@
+-- Guest reviews, one per checked-out booking
+CREATE TABLE IF NOT EXISTS reviews (
+    id SERIAL PRIMARY KEY,
+    booking_id INT NOT NULL UNIQUE REFERENCES bookings(id) ON DELETE CASCADE,
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    user_id INT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    score INT NOT NULL CHECK (score BETWEEN 1 AND 5),
+    comment TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_reviews_hotel_id_created_at ON reviews(hotel_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_reviews_user_id ON reviews(user_id);
@@ -0,0 +1,2 @@
+ALTER TABLE hotels DROP COLUMN IF EXISTS manager_id;
+ALTER TABLE users DROP COLUMN IF EXISTS role;
@@ -0,0 +1,9 @@
+-- Global user roles
+ALTER TABLE users ADD COLUMN IF NOT EXISTS role VARCHAR(20) NOT NULL DEFAULT 'guest'
+    CHECK (role IN ('guest', 'hotel_manager', 'admin'));
+
+-- Manager responsible for each hotel
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS manager_id INT REFERENCES users(id) ON DELETE SET NULL;
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_hotels_manager_id ON hotels(manager_id);
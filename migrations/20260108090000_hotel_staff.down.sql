@@ -0,0 +1,13 @@
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS manager_id INT REFERENCES users(id) ON DELETE SET NULL;
+
+UPDATE hotels h SET manager_id = s.user_id
+FROM (
+    SELECT DISTINCT ON (hotel_id) hotel_id, user_id FROM hotel_staff
+    WHERE role = 'owner'
+    ORDER BY hotel_id, created_at
+) s
+WHERE h.id = s.hotel_id;
+
+CREATE INDEX IF NOT EXISTS idx_hotels_manager_id ON hotels(manager_id);
+
+DROP TABLE IF EXISTS hotel_staff;
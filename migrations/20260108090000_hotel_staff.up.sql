@@ -0,0 +1,20 @@
+-- Per-hotel staff membership
+CREATE TABLE IF NOT EXISTS hotel_staff (
+    hotel_id INT NOT NULL REFERENCES hotels(id) ON DELETE CASCADE,
+    user_id INT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role VARCHAR(20) NOT NULL CHECK (role IN ('owner', 'manager', 'front_desk', 'housekeeping')),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (hotel_id, user_id)
+);
+
+-- Assigned managers become the owners of their hotels
+INSERT INTO hotel_staff (hotel_id, user_id, role)
+SELECT id, manager_id, 'owner' FROM hotels WHERE manager_id IS NOT NULL
+ON CONFLICT DO NOTHING;
+
+DROP INDEX IF EXISTS idx_hotels_manager_id;
+ALTER TABLE hotels DROP COLUMN IF EXISTS manager_id;
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_hotel_staff_user_id ON hotel_staff(user_id);
@@ -0,0 +1,2 @@
+-- Bumped to invalidate every access token issued to a user
+ALTER TABLE users ADD COLUMN IF NOT EXISTS token_version INT NOT NULL DEFAULT 0;
@@ -0,0 +1,15 @@
+-- Temporary account locks applied after repeated failed logins
+CREATE TABLE IF NOT EXISTS account_lockouts (
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    locked_until TIMESTAMP NOT NULL,
+    failed_attempts INT NOT NULL,
+    ip_address VARCHAR(45) NOT NULL,
+    unlocked_at TIMESTAMP,
+    unlocked_by INT REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_account_lockouts_user_id ON account_lockouts(user_id);
+CREATE INDEX IF NOT EXISTS idx_account_lockouts_locked_until ON account_lockouts(locked_until) WHERE unlocked_at IS NULL;
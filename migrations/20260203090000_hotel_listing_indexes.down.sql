@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_hotels_created_at_id;
+DROP INDEX IF EXISTS idx_hotels_rating_id;
+DROP INDEX IF EXISTS idx_hotels_name_id;
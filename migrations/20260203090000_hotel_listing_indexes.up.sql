@@ -0,0 +1,4 @@
+-- Keyset pagination indexes for GET /hotels, one per sort key with id as tie-breaker
+CREATE INDEX IF NOT EXISTS idx_hotels_name_id ON hotels(name, id);
+CREATE INDEX IF NOT EXISTS idx_hotels_rating_id ON hotels((COALESCE(rating, 0)), id);
+CREATE INDEX IF NOT EXISTS idx_hotels_created_at_id ON hotels(created_at, id);
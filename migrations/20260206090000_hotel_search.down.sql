@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_hotels_search_trigram;
+DROP INDEX IF EXISTS idx_hotels_search_vector;
+DROP FUNCTION IF EXISTS hotel_search_query(TEXT, TEXT);
+ALTER TABLE hotels DROP COLUMN IF EXISTS search_vector;
+ALTER TABLE hotels DROP COLUMN IF EXISTS language;
+DROP FUNCTION IF EXISTS hotel_search_config(TEXT);
+-- pg_trgm is left installed; other objects may depend on it
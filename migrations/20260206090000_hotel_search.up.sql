@@ -0,0 +1,62 @@
+-- Full-text hotel search with a typo-tolerant trigram fallback
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- Text search configuration for a hotel's ISO 639-1 language; languages
+-- without a configuration are indexed as-is, without stemming
+CREATE OR REPLACE FUNCTION hotel_search_config(language TEXT) RETURNS regconfig
+LANGUAGE sql IMMUTABLE PARALLEL SAFE AS $$
+    SELECT (CASE language
+        WHEN 'ar' THEN 'arabic'
+        WHEN 'ca' THEN 'catalan'
+        WHEN 'da' THEN 'danish'
+        WHEN 'de' THEN 'german'
+        WHEN 'el' THEN 'greek'
+        WHEN 'en' THEN 'english'
+        WHEN 'es' THEN 'spanish'
+        WHEN 'eu' THEN 'basque'
+        WHEN 'fi' THEN 'finnish'
+        WHEN 'fr' THEN 'french'
+        WHEN 'ga' THEN 'irish'
+        WHEN 'hi' THEN 'hindi'
+        WHEN 'hu' THEN 'hungarian'
+        WHEN 'hy' THEN 'armenian'
+        WHEN 'id' THEN 'indonesian'
+        WHEN 'it' THEN 'italian'
+        WHEN 'lt' THEN 'lithuanian'
+        WHEN 'ne' THEN 'nepali'
+        WHEN 'nl' THEN 'dutch'
+        WHEN 'no' THEN 'norwegian'
+        WHEN 'pt' THEN 'portuguese'
+        WHEN 'ro' THEN 'romanian'
+        WHEN 'ru' THEN 'russian'
+        WHEN 'sr' THEN 'serbian'
+        WHEN 'sv' THEN 'swedish'
+        WHEN 'ta' THEN 'tamil'
+        WHEN 'tr' THEN 'turkish'
+        WHEN 'yi' THEN 'yiddish'
+        ELSE 'simple'
+    END)::regconfig
+$$;
+
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS language VARCHAR(2) NOT NULL DEFAULT 'en';
+
+-- Stemmed in the hotel's language and weighted name > location > description,
+-- plus an unstemmed copy so queries in any language can match exact words
+ALTER TABLE hotels ADD COLUMN IF NOT EXISTS search_vector tsvector GENERATED ALWAYS AS (
+    setweight(to_tsvector(hotel_search_config(language), name), 'A') ||
+    setweight(to_tsvector(hotel_search_config(language), city || ' ' || country), 'B') ||
+    setweight(to_tsvector(hotel_search_config(language), COALESCE(description, '')), 'C') ||
+    to_tsvector('simple', name || ' ' || city || ' ' || country || ' ' || COALESCE(description, ''))
+) STORED;
+
+-- Matches any word of a free-text query, stemmed for the query's language
+-- and unstemmed; ranking puts hotels matching more words first
+CREATE OR REPLACE FUNCTION hotel_search_query(language TEXT, query TEXT) RETURNS tsquery
+LANGUAGE sql STABLE PARALLEL SAFE AS $$
+    SELECT replace(plainto_tsquery(hotel_search_config(language), query)::text, ' & ', ' | ')::tsquery
+        || replace(plainto_tsquery('simple', query)::text, ' & ', ' | ')::tsquery
+$$;
+
+-- Indexes for better query performance
+CREATE INDEX IF NOT EXISTS idx_hotels_search_vector ON hotels USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS idx_hotels_search_trigram ON hotels USING GIN ((name || ' ' || city || ' ' || country) gin_trgm_ops);
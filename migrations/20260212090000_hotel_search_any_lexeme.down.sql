@@ -0,0 +1,7 @@
+CREATE OR REPLACE FUNCTION hotel_search_query(language TEXT, query TEXT) RETURNS tsquery
+LANGUAGE sql STABLE PARALLEL SAFE AS $$
+    SELECT replace(plainto_tsquery(hotel_search_config(language), query)::text, ' & ', ' | ')::tsquery
+        || replace(plainto_tsquery('simple', query)::text, ' & ', ' | ')::tsquery
+$$;
+
+DROP FUNCTION IF EXISTS hotel_search_any_lexeme(regconfig, TEXT);
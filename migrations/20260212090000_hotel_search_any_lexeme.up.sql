@@ -0,0 +1,17 @@
+-- OR of the query's lexemes under a configuration; each lexeme is quoted so
+-- characters such as ':' or '!' are not read as tsquery syntax
+CREATE OR REPLACE FUNCTION hotel_search_any_lexeme(config regconfig, query TEXT) RETURNS tsquery
+LANGUAGE sql STABLE PARALLEL SAFE AS $$
+    SELECT COALESCE(string_agg(
+        '''' || replace(replace(lexeme, '\', '\\'), '''', '''''') || '''', ' | '
+    ), '')::tsquery
+    FROM unnest(tsvector_to_array(to_tsvector(config, query))) AS lexeme
+$$;
+
+-- Matches any word of a free-text query, stemmed for the query's language
+-- and unstemmed; ranking puts hotels matching more words first
+CREATE OR REPLACE FUNCTION hotel_search_query(language TEXT, query TEXT) RETURNS tsquery
+LANGUAGE sql STABLE PARALLEL SAFE AS $$
+    SELECT hotel_search_any_lexeme(hotel_search_config(language), query)
+        || hotel_search_any_lexeme('simple', query)
+$$;
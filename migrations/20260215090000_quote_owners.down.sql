@@ -0,0 +1,2 @@
+ALTER TABLE quotes DROP COLUMN IF EXISTS used_at;
+ALTER TABLE quotes DROP COLUMN IF EXISTS user_id;
@@ -0,0 +1,3 @@
+-- Quotes belong to the user who requested them and can back a single booking
+ALTER TABLE quotes ADD COLUMN IF NOT EXISTS user_id INT REFERENCES users(id) ON DELETE CASCADE;
+ALTER TABLE quotes ADD COLUMN IF NOT EXISTS used_at TIMESTAMP;
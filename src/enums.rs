@@ -1,9 +1,58 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
 #[derive(Debug)]
 pub enum AuthError {
     MissingToken,
     InvalidToken,
+    RevokedToken,
+    Forbidden,
+    Internal,
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type,
+)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+pub enum UserRole {
+    #[default]
+    Guest,
+    HotelManager,
+    Admin,
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserRole::Guest => write!(f, "guest"),
+            UserRole::HotelManager => write!(f, "hotel_manager"),
+            UserRole::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+pub enum StaffRole {
+    Owner,
+    Manager,
+    FrontDesk,
+    Housekeeping,
+}
+
+impl fmt::Display for StaffRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StaffRole::Owner => write!(f, "owner"),
+            StaffRole::Manager => write!(f, "manager"),
+            StaffRole::FrontDesk => write!(f, "front_desk"),
+            StaffRole::Housekeeping => write!(f, "housekeeping"),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -35,3 +84,383 @@ impl fmt::Display for ServiceName {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+pub enum RoomStatus {
+    Available,
+    Occupied,
+    Maintenance,
+    OutOfService,
+}
+
+impl fmt::Display for RoomStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomStatus::Available => write!(f, "available"),
+            RoomStatus::Occupied => write!(f, "occupied"),
+            RoomStatus::Maintenance => write!(f, "maintenance"),
+            RoomStatus::OutOfService => write!(f, "out_of_service"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+pub enum BookingStatus {
+    Pending,
+    Confirmed,
+    CheckedIn,
+    CheckedOut,
+    Cancelled,
+    NoShow,
+}
+
+impl BookingStatus {
+    pub fn can_transition_to(&self, next: BookingStatus) -> bool {
+        matches!(
+            (self, next),
+            (BookingStatus::Pending, BookingStatus::Confirmed)
+                | (BookingStatus::Pending, BookingStatus::Cancelled)
+                | (BookingStatus::Confirmed, BookingStatus::CheckedIn)
+                | (BookingStatus::Confirmed, BookingStatus::Cancelled)
+                | (BookingStatus::Confirmed, BookingStatus::NoShow)
+                | (BookingStatus::CheckedIn, BookingStatus::CheckedOut)
+        )
+    }
+
+    pub fn releases_inventory(&self) -> bool {
+        matches!(self, BookingStatus::Cancelled | BookingStatus::NoShow)
+    }
+}
+
+impl fmt::Display for BookingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookingStatus::Pending => write!(f, "pending"),
+            BookingStatus::Confirmed => write!(f, "confirmed"),
+            BookingStatus::CheckedIn => write!(f, "checked_in"),
+            BookingStatus::CheckedOut => write!(f, "checked_out"),
+            BookingStatus::Cancelled => write!(f, "cancelled"),
+            BookingStatus::NoShow => write!(f, "no_show"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+pub enum CancellationPenalty {
+    FirstNight,
+    Percentage,
+    NonRefundable,
+}
+
+impl fmt::Display for CancellationPenalty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CancellationPenalty::FirstNight => write!(f, "first_night"),
+            CancellationPenalty::Percentage => write!(f, "percentage"),
+            CancellationPenalty::NonRefundable => write!(f, "non_refundable"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+pub enum HoldStatus {
+    Active,
+    Consumed,
+    Released,
+    Expired,
+}
+
+impl fmt::Display for HoldStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HoldStatus::Active => write!(f, "active"),
+            HoldStatus::Consumed => write!(f, "consumed"),
+            HoldStatus::Released => write!(f, "released"),
+            HoldStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// Route groups that share a rate limit bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitGroup {
+    Auth,
+    Search,
+    Mutations,
+}
+
+impl fmt::Display for RateLimitGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitGroup::Auth => write!(f, "auth"),
+            RateLimitGroup::Search => write!(f, "search"),
+            RateLimitGroup::Mutations => write!(f, "mutations"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
+pub enum EmailStatus {
+    Pending,
+    Sent,
+    Failed,
+}
+
+impl fmt::Display for EmailStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmailStatus::Pending => write!(f, "pending"),
+            EmailStatus::Sent => write!(f, "sent"),
+            EmailStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteLineKind {
+    RoomNight,
+    CityTax,
+    Fee,
+    Vat,
+}
+
+/// Sort key for `GET /hotels`; ties are broken by hotel id.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HotelSort {
+    #[default]
+    Name,
+    /// Unrated hotels sort as 0.
+    Rating,
+    CreatedAt,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug)]
+pub enum BookingError {
+    NotFound,
+    RoomTypeNotFound,
+    RatePlanNotFound,
+    QuoteNotFound,
+    QuoteExpired,
+    QuoteMismatch,
+    HoldNotFound,
+    HoldExpired,
+    HoldMismatch,
+    InvalidDates,
+    TooManyGuests,
+    Unavailable,
+    StopSell,
+    ClosedToArrival,
+    InvalidTransition {
+        from: BookingStatus,
+        to: BookingStatus,
+    },
+    BeforeArrival(BookingStatus),
+    EmailNotVerified,
+    Database(sqlx::Error),
+    Redis(redis::RedisError),
+}
+
+impl From<sqlx::Error> for BookingError {
+    fn from(e: sqlx::Error) -> Self {
+        BookingError::Database(e)
+    }
+}
+
+impl From<redis::RedisError> for BookingError {
+    fn from(e: redis::RedisError) -> Self {
+        BookingError::Redis(e)
+    }
+}
+
+#[derive(Debug)]
+pub enum ReviewError {
+    NotFound,
+    BookingNotFound,
+    NotCheckedOut,
+    AlreadyReviewed,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ReviewError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => ReviewError::AlreadyReviewed,
+            e => ReviewError::Database(e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StaffError {
+    NotFound,
+    UserNotFound,
+    AlreadyMember,
+    LastOwner,
+    OwnersOnly,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for StaffError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => StaffError::AlreadyMember,
+            e => StaffError::Database(e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RefreshTokenError {
+    Invalid,
+    Reused,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for RefreshTokenError {
+    fn from(e: sqlx::Error) -> Self {
+        RefreshTokenError::Database(e)
+    }
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+    Database(sqlx::Error),
+    Redis(redis::RedisError),
+}
+
+impl From<sqlx::Error> for SessionError {
+    fn from(e: sqlx::Error) -> Self {
+        SessionError::Database(e)
+    }
+}
+
+impl From<redis::RedisError> for SessionError {
+    fn from(e: redis::RedisError) -> Self {
+        SessionError::Redis(e)
+    }
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionError::Database(e) => write!(f, "database error: {}", e),
+            SessionError::Redis(e) => write!(f, "redis error: {}", e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PasswordError {
+    InvalidToken,
+    Database(sqlx::Error),
+    Session(SessionError),
+}
+
+impl From<sqlx::Error> for PasswordError {
+    fn from(e: sqlx::Error) -> Self {
+        PasswordError::Database(e)
+    }
+}
+
+impl From<SessionError> for PasswordError {
+    fn from(e: SessionError) -> Self {
+        PasswordError::Session(e)
+    }
+}
+
+#[derive(Debug)]
+pub enum EmailVerificationError {
+    InvalidToken,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for EmailVerificationError {
+    fn from(e: sqlx::Error) -> Self {
+        EmailVerificationError::Database(e)
+    }
+}
+
+#[derive(Debug)]
+pub enum MailerError {
+    InvalidMessage(String),
+    Transport(String),
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for MailerError {
+    fn from(e: std::io::Error) -> Self {
+        MailerError::Io(e)
+    }
+}
+
+impl fmt::Display for MailerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MailerError::InvalidMessage(e) => write!(f, "invalid message: {}", e),
+            MailerError::Transport(e) => write!(f, "transport error: {}", e),
+            MailerError::Io(e) => write!(f, "io error: {}", e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum TwoFactorError {
+    AlreadyEnabled,
+    NotSetUp,
+    InvalidCode,
+    InvalidChallenge,
+    Database(sqlx::Error),
+    Redis(redis::RedisError),
+}
+
+impl From<sqlx::Error> for TwoFactorError {
+    fn from(e: sqlx::Error) -> Self {
+        TwoFactorError::Database(e)
+    }
+}
+
+impl From<redis::RedisError> for TwoFactorError {
+    fn from(e: redis::RedisError) -> Self {
+        TwoFactorError::Redis(e)
+    }
+}
+
+#[derive(Debug)]
+pub enum LoginThrottleError {
+    TooManyAttempts { retry_after_seconds: i64 },
+    AccountLocked { retry_after_seconds: i64 },
+    Database(sqlx::Error),
+    Redis(redis::RedisError),
+}
+
+impl From<sqlx::Error> for LoginThrottleError {
+    fn from(e: sqlx::Error) -> Self {
+        LoginThrottleError::Database(e)
+    }
+}
+
+impl From<redis::RedisError> for LoginThrottleError {
+    fn from(e: redis::RedisError) -> Self {
+        LoginThrottleError::Redis(e)
+    }
+}
@@ -0,0 +1,185 @@
+use axum::{
+    Json,
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// Postgres SQLSTATE for a unique constraint violation.
+const UNIQUE_VIOLATION: &str = "23505";
+
+/// Error returned by handlers, rendered as an RFC 7807 problem document.
+/// Each variant has a stable `code` clients can match on; the detail
+/// message is for humans and may change.
+#[derive(Debug)]
+pub enum AppError {
+    BadRequest(String),
+    /// Request body failed its declared rules; rendered with an `errors` list.
+    Validation(Vec<FieldError>),
+    Unauthorized(String),
+    Forbidden(String),
+    NotFound(String),
+    Conflict(String),
+    /// The resource existed but has expired or been used up.
+    Gone(String),
+    TooManyRequests {
+        detail: String,
+        retry_after_seconds: i64,
+    },
+    /// The message is shown to the client, so it should not include the
+    /// underlying error; log that where it is caught.
+    Internal(String),
+}
+
+/// `application/problem+json` body.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    /// Stable machine-readable error code, e.g. `not_found`.
+    pub code: String,
+    /// Per-field problems, present when `code` is `validation_failed`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    /// Rule that failed, e.g. `length` or `email`.
+    pub code: String,
+    pub message: String,
+}
+
+impl AppError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Gone(_) => StatusCode::GONE,
+            AppError::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::BadRequest(_) => "bad_request",
+            AppError::Validation(_) => "validation_failed",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::NotFound(_) => "not_found",
+            AppError::Conflict(_) => "conflict",
+            AppError::Gone(_) => "gone",
+            AppError::TooManyRequests { .. } => "rate_limited",
+            AppError::Internal(_) => "internal_error",
+        }
+    }
+
+    pub fn detail(&self) -> &str {
+        match self {
+            AppError::Validation(_) => "Request validation failed",
+            AppError::BadRequest(detail)
+            | AppError::Unauthorized(detail)
+            | AppError::Forbidden(detail)
+            | AppError::NotFound(detail)
+            | AppError::Conflict(detail)
+            | AppError::Gone(detail)
+            | AppError::TooManyRequests { detail, .. }
+            | AppError::Internal(detail) => detail,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status: StatusCode = self.status();
+        let problem: ProblemDetails = ProblemDetails {
+            problem_type: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail: self.detail().to_string(),
+            code: self.code().to_string(),
+            errors: match &self {
+                AppError::Validation(errors) => errors.clone(),
+                _ => Vec::new(),
+            },
+        };
+
+        let mut response: Response = (status, Json(problem)).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        if let AppError::TooManyRequests {
+            retry_after_seconds,
+            ..
+        } = self
+        {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_seconds));
+        }
+
+        response
+    }
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(e: sqlx::Error) -> Self {
+        match &e {
+            sqlx::Error::RowNotFound => AppError::NotFound("Resource not found".to_string()),
+            sqlx::Error::Database(db) if db.code().as_deref() == Some(UNIQUE_VIOLATION) => {
+                AppError::Conflict("Resource already exists".to_string())
+            }
+            _ => {
+                tracing::error!("Database error: {}", e);
+                AppError::Internal("Internal server error".to_string())
+            }
+        }
+    }
+}
+
+impl From<redis::RedisError> for AppError {
+    fn from(e: redis::RedisError) -> Self {
+        tracing::error!("Redis error: {}", e);
+        AppError::Internal("Internal server error".to_string())
+    }
+}
+
+impl From<bcrypt::BcryptError> for AppError {
+    fn from(e: bcrypt::BcryptError) -> Self {
+        tracing::error!("Password hashing error: {}", e);
+        AppError::Internal("Internal server error".to_string())
+    }
+}
+
+impl From<enums::AuthError> for AppError {
+    fn from(e: enums::AuthError) -> Self {
+        match e {
+            enums::AuthError::MissingToken => {
+                AppError::Unauthorized("Missing authorization token".to_string())
+            }
+            enums::AuthError::InvalidToken => AppError::Unauthorized("Invalid token".to_string()),
+            enums::AuthError::RevokedToken => {
+                AppError::Unauthorized("Token has been revoked".to_string())
+            }
+            enums::AuthError::Forbidden => {
+                AppError::Forbidden("Insufficient permissions".to_string())
+            }
+            enums::AuthError::Internal => {
+                AppError::Internal("Failed to validate token".to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::path;
+
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::enums::MailerError;
+use crate::models::emails::OutboxEmail;
+
+/// Delivers a single email drained from the outbox.
+#[async_trait::async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: &OutboxEmail) -> Result<(), MailerError>;
+}
+
+/// Parses the recipient address. Every transport checks it, so an email SMTP
+/// would refuse fails the same way under `file` and `log`.
+fn recipient(email: &OutboxEmail) -> Result<Mailbox, MailerError> {
+    email
+        .recipient
+        .parse()
+        .map_err(|e: lettre::address::AddressError| MailerError::InvalidMessage(e.to_string()))
+}
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(
+        host: &str,
+        port: u16,
+        username: Option<String>,
+        password: Option<String>,
+        starttls: bool,
+        from: &str,
+    ) -> Result<Self, MailerError> {
+        let builder = if starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .map_err(|e| MailerError::Transport(e.to_string()))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        };
+
+        let builder = match (username, password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username, password))
+            }
+            _ => builder,
+        };
+
+        Ok(Self {
+            transport: builder.port(port).build(),
+            from: from.parse().map_err(|e: lettre::address::AddressError| {
+                MailerError::InvalidMessage(e.to_string())
+            })?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: &OutboxEmail) -> Result<(), MailerError> {
+        let message: Message = Message::builder()
+            .from(self.from.clone())
+            .to(recipient(email)?)
+            .subject(&email.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.body.clone())
+            .map_err(|e| MailerError::InvalidMessage(e.to_string()))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| MailerError::Transport(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Writes each email to the log instead of sending it.
+pub struct LogMailer;
+
+#[async_trait::async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, email: &OutboxEmail) -> Result<(), MailerError> {
+        recipient(email)?;
+        tracing::info!(
+            "Email #{} to {}: {}\n{}",
+            email.id,
+            email.recipient,
+            email.subject,
+            email.body
+        );
+
+        Ok(())
+    }
+}
+
+/// Writes each email to `<dir>/<id>.eml` instead of sending it.
+pub struct FileMailer {
+    dir: path::PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl Into<path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, email: &OutboxEmail) -> Result<(), MailerError> {
+        recipient(email)?;
+        tokio::fs::create_dir_all(&self.dir).await?;
+        tokio::fs::write(
+            self.dir.join(format!("{}.eml", email.id)),
+            format!(
+                "To: {}\nSubject: {}\n\n{}",
+                email.recipient, email.subject, email.body
+            ),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
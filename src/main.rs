@@ -1,4 +1,6 @@
 mod enums;
+mod errors;
+mod mailer;
 mod middleware;
 mod models;
 mod repositories;
@@ -7,6 +9,7 @@ mod routes;
 mod services;
 mod settings;
 mod utils;
+mod validation;
 
 use tower_http::cors;
 
@@ -30,12 +33,21 @@ async fn init_state(settings: &settings::Settings) -> models::AppState {
         }
     };
 
+    let mailer: std::sync::Arc<dyn mailer::Mailer> = match resources::init_mailer(settings) {
+        Ok(mailer) => mailer,
+        Err(e) => {
+            tracing::error!("Failed to initialize mailer: {}", e);
+            std::process::exit(1);
+        }
+    };
+
     tracing::info!("Initialized state");
 
     models::AppState {
         jwt_secret: settings.jwt_secret.clone(),
-        jwt_expire_hours: settings.jwt_expire_hours,
-        services: services::Services::new(pool, redis_conn),
+        jwt_expire_minutes: settings.jwt_expire_minutes,
+        trust_forwarded_for: settings.trust_forwarded_for,
+        services: services::Services::new(pool, redis_conn, mailer, settings),
     }
 }
 
@@ -48,7 +60,21 @@ async fn main() {
         .init();
 
     let settings: settings::Settings = settings::Settings::new();
+    if let Err(e) = settings.validate() {
+        tracing::error!("Invalid settings: {}", e);
+        std::process::exit(1);
+    }
     let state: models::AppState = init_state(&settings).await;
+    state
+        .services
+        .hold_service
+        .clone()
+        .spawn_sweeper(settings.hold_sweep_interval_seconds);
+    state
+        .services
+        .email_service
+        .clone()
+        .spawn_worker(settings.email_worker_interval_seconds);
     let addr: String = format!("{}:{}", settings.app_host, settings.app_port);
 
     tracing::info!("Starting hotel booking API on {}/docs", addr);
@@ -62,5 +88,10 @@ async fn main() {
 
     let listener: tokio::net::TcpListener = tokio::net::TcpListener::bind(&addr).await.unwrap();
 
-    axum::serve(listener, app).await.unwrap();
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }
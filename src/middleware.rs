@@ -1,11 +1,13 @@
+use std::{marker::PhantomData, net::SocketAddr};
+
 use axum::{
-    extract::{Request, State},
-    http::{HeaderMap, StatusCode},
+    extract::{ConnectInfo, FromRequestParts, RawPathParams, Request, State},
+    http::{HeaderMap, HeaderValue, Method, request::Parts},
     middleware::Next,
     response::{IntoResponse, Response},
 };
 
-use crate::{enums, models, utils};
+use crate::{enums, errors::AppError, models, utils};
 
 pub async fn auth_middleware(
     State(app_state): State<models::AppState>,
@@ -19,22 +21,236 @@ pub async fn auth_middleware(
         .and_then(|h| h.strip_prefix("Bearer "))
         .ok_or(enums::AuthError::MissingToken)?;
 
-    request.extensions_mut().insert(
+    let claims: models::users::Claims =
         utils::extract_user_from_token(token, &app_state.jwt_secret)
-            .map_err(|_| enums::AuthError::InvalidToken)?,
+            .map_err(|_| enums::AuthError::InvalidToken)?;
+
+    let active: bool = app_state
+        .services
+        .session_service
+        .is_active(&claims)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check session for user {}: {}", claims.sub, e);
+            enums::AuthError::Internal
+        })?;
+
+    if !active {
+        return Err(enums::AuthError::RevokedToken);
+    }
+
+    request.extensions_mut().insert(claims);
+
+    Ok(next.run(request).await)
+}
+
+/// Applies the Redis token bucket for the request's route group: `/auth/*`,
+/// other reads (search) or other writes (mutations). Requests are counted per
+/// user when they carry a valid token and per client address otherwise. If
+/// Redis is unavailable the request is let through rather than failed.
+///
+/// There is no per-API-key bucket: the API does not issue API keys, and an
+/// unverified key header would let a client pick a fresh bucket per request.
+/// Keys should get their own `key:` client here once they exist.
+pub async fn rate_limit_middleware(
+    State(app_state): State<models::AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let group: enums::RateLimitGroup = if request.uri().path().starts_with("/auth/") {
+        enums::RateLimitGroup::Auth
+    } else if matches!(*request.method(), Method::GET | Method::HEAD) {
+        enums::RateLimitGroup::Search
+    } else {
+        enums::RateLimitGroup::Mutations
+    };
+
+    let client: String = request
+        .headers()
+        .get("authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .and_then(|token| utils::extract_user_from_token(token, &app_state.jwt_secret).ok())
+        .map(|claims| format!("user:{}", claims.sub))
+        .unwrap_or_else(|| {
+            format!(
+                "ip:{}",
+                utils::client_ip(request.headers(), peer, app_state.trust_forwarded_for)
+            )
+        });
+
+    let decision: models::rate_limits::RateLimitDecision = match app_state
+        .services
+        .rate_limit_service
+        .check(group, &client)
+        .await
+    {
+        Ok(Some(decision)) => decision,
+        Ok(None) => return next.run(request).await,
+        Err(e) => {
+            tracing::error!("Failed to check rate limit for {}: {}", client, e);
+            return next.run(request).await;
+        }
+    };
+
+    let mut response: Response = if decision.allowed {
+        next.run(request).await
+    } else {
+        AppError::TooManyRequests {
+            detail: "Rate limit exceeded".to_string(),
+            retry_after_seconds: decision.retry_after_seconds,
+        }
+        .into_response()
+    };
+
+    let headers: &mut HeaderMap = response.headers_mut();
+    headers.insert("x-ratelimit-limit", HeaderValue::from(decision.limit));
+    headers.insert(
+        "x-ratelimit-remaining",
+        HeaderValue::from(decision.remaining),
+    );
+    headers.insert(
+        "x-ratelimit-reset",
+        HeaderValue::from(decision.reset_seconds),
     );
 
+    response
+}
+
+/// Set of roles a route accepts, declared through `RequireRole<R>`.
+pub trait RoleSet {
+    const ROLES: &'static [enums::UserRole];
+}
+
+pub struct AdminOnly;
+
+impl RoleSet for AdminOnly {
+    const ROLES: &'static [enums::UserRole] = &[enums::UserRole::Admin];
+}
+
+pub struct HotelManagers;
+
+impl RoleSet for HotelManagers {
+    const ROLES: &'static [enums::UserRole] =
+        &[enums::UserRole::Admin, enums::UserRole::HotelManager];
+}
+
+/// Extracts the authenticated user's claims, rejecting with 403 unless their
+/// role is in `R`. Must run behind `auth_middleware`.
+pub struct RequireRole<R: RoleSet>(pub models::users::Claims, pub PhantomData<R>);
+
+impl<S, R> FromRequestParts<S> for RequireRole<R>
+where
+    S: Send + Sync,
+    R: RoleSet,
+{
+    type Rejection = enums::AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let claims: models::users::Claims = parts
+            .extensions
+            .get::<models::users::Claims>()
+            .cloned()
+            .ok_or(enums::AuthError::MissingToken)?;
+
+        if !R::ROLES.contains(&claims.role) {
+            return Err(enums::AuthError::Forbidden);
+        }
+
+        Ok(Self(claims, PhantomData))
+    }
+}
+
+/// Set of per-hotel staff roles a route group accepts, declared through
+/// `hotel_staff_middleware::<R>`.
+pub trait StaffRoleSet {
+    const ROLES: &'static [enums::StaffRole];
+}
+
+pub struct HotelOwners;
+
+impl StaffRoleSet for HotelOwners {
+    const ROLES: &'static [enums::StaffRole] = &[enums::StaffRole::Owner];
+}
+
+pub struct HotelManagement;
+
+impl StaffRoleSet for HotelManagement {
+    const ROLES: &'static [enums::StaffRole] =
+        &[enums::StaffRole::Owner, enums::StaffRole::Manager];
+}
+
+pub struct HotelOperations;
+
+impl StaffRoleSet for HotelOperations {
+    const ROLES: &'static [enums::StaffRole] = &[
+        enums::StaffRole::Owner,
+        enums::StaffRole::Manager,
+        enums::StaffRole::FrontDesk,
+        enums::StaffRole::Housekeeping,
+    ];
+}
+
+/// Restricts routes under `/hotels/{id}` to members of the hotel's staff whose
+/// role is in `R`, and inserts that role into the request extensions. Admins
+/// pass as owners. Must run behind `auth_middleware`.
+pub async fn hotel_staff_middleware<R: StaffRoleSet>(
+    State(app_state): State<models::AppState>,
+    path_params: RawPathParams,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let claims: &models::users::Claims = request
+        .extensions()
+        .get::<models::users::Claims>()
+        .ok_or(enums::AuthError::MissingToken.into_response())?;
+
+    let hotel_id: i32 = path_params
+        .iter()
+        .find(|(name, _)| *name == "id")
+        .and_then(|(_, value)| value.parse::<i32>().ok())
+        .ok_or_else(|| AppError::BadRequest("Invalid hotel id".to_string()).into_response())?;
+
+    let role: enums::StaffRole = if claims.role == enums::UserRole::Admin {
+        enums::StaffRole::Owner
+    } else {
+        let role: Option<enums::StaffRole> = app_state
+            .services
+            .staff_service
+            .role_for(hotel_id, claims.sub)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+
+        match role {
+            Some(role) => role,
+            None => {
+                app_state
+                    .services
+                    .hotel_service
+                    .get_by_id(hotel_id)
+                    .await
+                    .map_err(|e| AppError::from(e).into_response())?
+                    .ok_or_else(|| {
+                        AppError::NotFound("Hotel not found".to_string()).into_response()
+                    })?;
+
+                return Err(enums::AuthError::Forbidden.into_response());
+            }
+        }
+    };
+
+    if !R::ROLES.contains(&role) {
+        return Err(enums::AuthError::Forbidden.into_response());
+    }
+
+    request.extensions_mut().insert(role);
+
     Ok(next.run(request).await)
 }
 
 impl IntoResponse for enums::AuthError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            enums::AuthError::MissingToken => {
-                (StatusCode::UNAUTHORIZED, "Missing authorization token")
-            }
-            enums::AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, "Invalid token"),
-        };
-        (status, error_message).into_response()
+        AppError::from(self).into_response()
     }
 }
@@ -0,0 +1,131 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::enums::{BookingStatus, CancellationPenalty};
+use crate::models::money;
+use crate::models::rate_plans::{CancellationPolicy, NightlyRate};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Booking {
+    pub id: i32,
+    pub user_id: i32,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub rate_plan_id: Option<i32>,
+    pub quote_id: Option<Uuid>,
+    pub hold_id: Option<Uuid>,
+    pub room_id: Option<i32>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+    pub status: BookingStatus,
+    pub total_price: Decimal,
+    pub free_cancellation_hours: i32,
+    pub cancellation_penalty: CancellationPenalty,
+    pub penalty_percent: Option<Decimal>,
+    pub penalty_amount: Option<Decimal>,
+    pub refund_amount: Option<Decimal>,
+    pub cancelled_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl Booking {
+    pub fn cancellation_policy(&self) -> CancellationPolicy {
+        CancellationPolicy {
+            free_cancellation_hours: self.free_cancellation_hours,
+            penalty: self.cancellation_penalty,
+            penalty_percent: self.penalty_percent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BookingNight {
+    pub booking_id: i32,
+    pub night: chrono::NaiveDate,
+    pub price: Decimal,
+}
+
+impl From<BookingNight> for NightlyRate {
+    fn from(night: BookingNight) -> Self {
+        Self {
+            date: night.night,
+            price: night.price,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateBookingRequest {
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub rate_plan_id: Option<i32>,
+    pub quote_id: Option<Uuid>,
+    pub hold_id: Option<Uuid>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BookingResponse {
+    pub id: i32,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub rate_plan_id: Option<i32>,
+    pub quote_id: Option<Uuid>,
+    pub hold_id: Option<Uuid>,
+    pub room_id: Option<i32>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub nights: i64,
+    pub guests: i32,
+    pub status: BookingStatus,
+    pub total_price: Decimal,
+    pub cancellation_policy: CancellationPolicy,
+    pub penalty_amount: Option<Decimal>,
+    pub refund_amount: Option<Decimal>,
+}
+
+impl From<Booking> for BookingResponse {
+    fn from(booking: Booking) -> Self {
+        Self {
+            cancellation_policy: booking.cancellation_policy(),
+            id: booking.id,
+            hotel_id: booking.hotel_id,
+            room_type_id: booking.room_type_id,
+            rate_plan_id: booking.rate_plan_id,
+            quote_id: booking.quote_id,
+            hold_id: booking.hold_id,
+            room_id: booking.room_id,
+            check_in: booking.check_in,
+            check_out: booking.check_out,
+            nights: (booking.check_out - booking.check_in).num_days(),
+            guests: booking.guests,
+            status: booking.status,
+            total_price: booking.total_price,
+            penalty_amount: booking.penalty_amount.map(money),
+            refund_amount: booking.refund_amount.map(money),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BookingDetailResponse {
+    #[serde(flatten)]
+    pub booking: BookingResponse,
+    pub nightly_rates: Vec<NightlyRate>,
+}
+
+impl BookingDetailResponse {
+    pub fn new(booking: Booking, nightly_rates: Vec<NightlyRate>) -> Self {
+        Self {
+            booking: BookingResponse::from(booking),
+            nightly_rates,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::enums::EmailStatus;
+use crate::models::bookings::Booking;
+
+/// Delay before the first retry; doubles after every further failure.
+const RETRY_BASE_SECONDS: i64 = 30;
+const RETRY_MAX_SECONDS: i64 = 3600;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct OutboxEmail {
+    pub id: i64,
+    pub recipient: String,
+    pub subject: String,
+    pub body: String,
+    pub status: EmailStatus,
+    pub attempts: i32,
+    pub next_attempt_at: chrono::NaiveDateTime,
+    pub last_error: Option<String>,
+    pub sent_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewEmail {
+    pub recipient: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// A verification token ready to store, with the email that delivers it.
+#[derive(Debug, Clone)]
+pub struct NewEmailVerification {
+    pub token_hash: String,
+    pub expires_at: chrono::NaiveDateTime,
+    pub email: NewEmail,
+}
+
+impl NewEmail {
+    pub fn password_reset(recipient: &str, token: &str, ttl_minutes: i64) -> Self {
+        Self {
+            recipient: recipient.to_string(),
+            subject: "Reset your password".to_string(),
+            body: format!(
+                "We received a request to reset your password.\n\n\
+                 Reset token: {}\n\n\
+                 The token expires in {} minutes. If you did not ask for a reset, ignore this email.\n",
+                token, ttl_minutes
+            ),
+        }
+    }
+
+    pub fn email_verification(recipient: &str, token: &str, ttl_hours: i64) -> Self {
+        Self {
+            recipient: recipient.to_string(),
+            subject: "Verify your email address".to_string(),
+            body: format!(
+                "Welcome! Please confirm your email address.\n\n\
+                 Verification token: {}\n\n\
+                 The token expires in {} hours.\n",
+                token, ttl_hours
+            ),
+        }
+    }
+
+    pub fn booking_confirmation(recipient: &str, booking: &Booking) -> Self {
+        Self {
+            recipient: recipient.to_string(),
+            subject: format!("Booking #{} confirmed", booking.id),
+            body: format!(
+                "Your booking #{} is confirmed.\n\n\
+                 Check-in: {}\n\
+                 Check-out: {}\n\
+                 Guests: {}\n\
+                 Total: {}\n",
+                booking.id,
+                booking.check_in,
+                booking.check_out,
+                booking.guests,
+                booking.total_price
+            ),
+        }
+    }
+}
+
+/// How long to wait before retrying an email that has failed `attempts` times.
+pub fn retry_delay(attempts: i32) -> chrono::Duration {
+    let exponent: u32 = attempts.saturating_sub(1).clamp(0, 16) as u32;
+
+    chrono::Duration::seconds((RETRY_BASE_SECONDS << exponent).min(RETRY_MAX_SECONDS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_per_attempt() {
+        assert_eq!(retry_delay(1), chrono::Duration::seconds(30));
+        assert_eq!(retry_delay(2), chrono::Duration::seconds(60));
+        assert_eq!(retry_delay(4), chrono::Duration::seconds(240));
+    }
+
+    #[test]
+    fn retry_delay_is_capped() {
+        assert_eq!(retry_delay(20), chrono::Duration::seconds(3600));
+        assert_eq!(retry_delay(i32::MAX), chrono::Duration::seconds(3600));
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::enums::HoldStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BookingHold {
+    pub id: Uuid,
+    pub user_id: i32,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+    pub status: HoldStatus,
+    pub expires_at: chrono::NaiveDateTime,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateHoldRequest {
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HoldResponse {
+    pub id: Uuid,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+    pub status: HoldStatus,
+    pub expires_at: chrono::NaiveDateTime,
+}
+
+impl From<BookingHold> for HoldResponse {
+    fn from(hold: BookingHold) -> Self {
+        Self {
+            id: hold.id,
+            hotel_id: hold.hotel_id,
+            room_type_id: hold.room_type_id,
+            check_in: hold.check_in,
+            check_out: hold.check_out,
+            guests: hold.guests,
+            status: hold.status,
+            expires_at: hold.expires_at,
+        }
+    }
+}
@@ -1,6 +1,17 @@
+use chrono::TimeZone;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::enums::{HotelSort, SortOrder};
+use crate::models::Patch;
+use crate::models::quotes::QuoteBreakdown;
+use crate::validation::{
+    not_blank, not_blank_patch, not_null, validate_language, validate_language_patch,
+    validate_timezone, validate_timezone_patch,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Hotel {
@@ -10,28 +21,163 @@ pub struct Hotel {
     pub address: String,
     pub city: String,
     pub country: String,
-    pub rating: Option<f64>,
+    pub rating: Option<Decimal>,
     pub total_reviews: Option<i32>,
+    pub timezone: String,
+    pub check_in_time: chrono::NaiveTime,
+    pub language: String,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+impl Hotel {
+    pub fn check_in_at(&self, date: chrono::NaiveDate) -> chrono::DateTime<chrono::Utc> {
+        local_to_utc(&self.timezone, date.and_time(self.check_in_time))
+    }
+
+    /// Current calendar date in the hotel's timezone.
+    pub fn today(&self) -> chrono::NaiveDate {
+        let tz: chrono_tz::Tz = self.timezone.parse().unwrap_or(chrono_tz::UTC);
+        chrono::Utc::now().with_timezone(&tz).date_naive()
+    }
+}
+
+pub fn local_to_utc(timezone: &str, local: chrono::NaiveDateTime) -> chrono::DateTime<chrono::Utc> {
+    let tz: chrono_tz::Tz = timezone.parse().unwrap_or(chrono_tz::UTC);
+
+    // A wall-clock time skipped by a DST change is moved past the gap.
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + chrono::Duration::hours(1)))
+                .earliest()
+        })
+        .map(|datetime| datetime.with_timezone(&chrono::Utc))
+        .unwrap_or_else(|| local.and_utc())
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct CreateHotelRequest {
+    #[validate(length(min = 1, max = 255), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 255)]
     pub name: String,
+    #[validate(length(max = 5000))]
+    #[schema(max_length = 5000)]
     pub description: Option<String>,
+    #[validate(length(min = 1, max = 255), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 255)]
     pub address: String,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub city: String,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub country: String,
+    /// IANA timezone name, e.g. `Europe/Vienna`.
+    #[validate(custom(function = "validate_timezone"))]
+    #[schema(max_length = 64, example = "Europe/Vienna")]
+    pub timezone: Option<String>,
+    pub check_in_time: Option<chrono::NaiveTime>,
+    /// ISO 639-1 code of the language the hotel is described in, used to
+    /// index it for search. Defaults to `en`.
+    #[validate(custom(function = "validate_language"))]
+    #[schema(min_length = 2, max_length = 2, example = "en")]
+    pub language: Option<String>,
+    /// Only admins may assign an owner; a manager creating a hotel owns it.
+    pub owner_id: Option<i32>,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+/// Full replacement of a hotel; optional fields that are left out are reset
+/// to their defaults.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateHotelRequest {
-    pub name: Option<String>,
+    #[validate(length(min = 1, max = 255), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 255)]
+    pub name: String,
+    #[validate(length(max = 5000))]
+    #[schema(max_length = 5000)]
     pub description: Option<String>,
-    pub address: Option<String>,
-    pub city: Option<String>,
-    pub country: Option<String>,
+    #[validate(length(min = 1, max = 255), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 255)]
+    pub address: String,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub city: String,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub country: String,
+    /// IANA timezone name, e.g. `Europe/Vienna`. Defaults to `UTC`.
+    #[validate(custom(function = "validate_timezone"))]
+    #[schema(max_length = 64, example = "Europe/Vienna")]
+    pub timezone: Option<String>,
+    /// Defaults to 15:00.
+    pub check_in_time: Option<chrono::NaiveTime>,
+    /// ISO 639-1 code, e.g. `de`. Defaults to `en`.
+    #[validate(custom(function = "validate_language"))]
+    #[schema(min_length = 2, max_length = 2, example = "en")]
+    pub language: Option<String>,
+}
+
+/// JSON Merge Patch of a hotel: absent fields are unchanged and only
+/// `description` may be cleared with `null`.
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct PatchHotelRequest {
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        length(min = 1, max = 255),
+        custom(function = "not_blank_patch")
+    )]
+    #[schema(value_type = String, required = false, min_length = 1, max_length = 255)]
+    pub name: Patch<String>,
+    #[serde(default)]
+    #[validate(length(max = 5000))]
+    #[schema(value_type = Option<String>, required = false, max_length = 5000)]
+    pub description: Patch<String>,
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        length(min = 1, max = 255),
+        custom(function = "not_blank_patch")
+    )]
+    #[schema(value_type = String, required = false, min_length = 1, max_length = 255)]
+    pub address: Patch<String>,
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        length(min = 1, max = 100),
+        custom(function = "not_blank_patch")
+    )]
+    #[schema(value_type = String, required = false, min_length = 1, max_length = 100)]
+    pub city: Patch<String>,
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        length(min = 1, max = 100),
+        custom(function = "not_blank_patch")
+    )]
+    #[schema(value_type = String, required = false, min_length = 1, max_length = 100)]
+    pub country: Patch<String>,
+    /// IANA timezone name, e.g. `Europe/Vienna`.
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        custom(function = "validate_timezone_patch")
+    )]
+    #[schema(value_type = String, required = false, max_length = 64, example = "Europe/Vienna")]
+    pub timezone: Patch<String>,
+    #[serde(default)]
+    #[validate(custom(function = "not_null"))]
+    #[schema(value_type = chrono::NaiveTime, required = false)]
+    pub check_in_time: Patch<chrono::NaiveTime>,
+    /// ISO 639-1 code, e.g. `de`.
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        custom(function = "validate_language_patch")
+    )]
+    #[schema(value_type = String, required = false, min_length = 2, max_length = 2, example = "en")]
+    pub language: Patch<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -42,8 +188,11 @@ pub struct HotelResponse {
     pub address: String,
     pub city: String,
     pub country: String,
-    pub rating: Option<f64>,
+    pub rating: Option<Decimal>,
     pub total_reviews: Option<i32>,
+    pub timezone: String,
+    pub check_in_time: chrono::NaiveTime,
+    pub language: String,
 }
 
 impl From<Hotel> for HotelResponse {
@@ -57,6 +206,375 @@ impl From<Hotel> for HotelResponse {
             country: hotel.country,
             rating: hotel.rating,
             total_reviews: hotel.total_reviews,
+            timezone: hotel.timezone,
+            check_in_time: hotel.check_in_time,
+            language: hotel.language,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HotelListQuery {
+    /// Page size, 1 to 100; defaults to 20.
+    pub limit: Option<i64>,
+    /// `next_cursor` from the previous page.
+    pub cursor: Option<String>,
+    pub sort: Option<HotelSort>,
+    pub order: Option<SortOrder>,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub min_rating: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HotelPageResponse {
+    pub items: Vec<HotelResponse>,
+    pub limit: i64,
+    /// Pass as `cursor` to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+    /// Hotels matching the filters when this page was read; may drift while
+    /// paging.
+    pub total_estimate: i64,
+}
+
+/// Position after the last hotel of a page. It records the sort so a cursor
+/// can't be replayed against a different ordering.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HotelCursor {
+    pub sort: HotelSort,
+    pub order: SortOrder,
+    /// Sort key of the last hotel, as text.
+    pub value: String,
+    pub id: i32,
+}
+
+impl HotelCursor {
+    pub fn after(hotel: &Hotel, sort: HotelSort, order: SortOrder) -> Self {
+        let value: String = match sort {
+            HotelSort::Name => hotel.name.clone(),
+            HotelSort::Rating => hotel.rating.unwrap_or_default().to_string(),
+            HotelSort::CreatedAt => hotel.created_at.to_string(),
+        };
+
+        Self {
+            sort,
+            order,
+            value,
+            id: hotel.id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        hex::encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    /// Returns `None` for anything `encode` could not have produced.
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let cursor: Self = serde_json::from_slice(&hex::decode(cursor).ok()?).ok()?;
+        let valid: bool = match cursor.sort {
+            HotelSort::Name => true,
+            HotelSort::Rating => cursor.value.parse::<Decimal>().is_ok(),
+            HotelSort::CreatedAt => {
+                chrono::NaiveDateTime::parse_from_str(&cursor.value, "%Y-%m-%d %H:%M:%S%.f").is_ok()
+            }
+        };
+
+        valid.then_some(cursor)
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, Validate)]
+#[into_params(parameter_in = Query)]
+pub struct HotelSearchQuery {
+    /// Free text, e.g. `quiet hotel near old town Prague`.
+    #[validate(length(min = 1, max = 200), custom(function = "not_blank"))]
+    #[param(min_length = 1, max_length = 200)]
+    pub q: String,
+    /// ISO 639-1 code of the query's language, used for stemming. Defaults to `en`.
+    #[validate(custom(function = "validate_language"))]
+    pub lang: Option<String>,
+    /// 1 to 50; defaults to 20.
+    #[validate(range(min = 1, max = 50))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct HotelSearchHit {
+    #[sqlx(flatten)]
+    pub hotel: Hotel,
+    pub rank: f32,
+    pub snippet: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HotelSearchResult {
+    pub hotel: HotelResponse,
+    /// Higher is more relevant; only comparable within one response.
+    pub rank: f32,
+    /// HTML-escaped excerpt of the description with matched words wrapped in
+    /// `<mark>`...`</mark>`.
+    pub snippet: String,
+}
+
+impl From<HotelSearchHit> for HotelSearchResult {
+    fn from(hit: HotelSearchHit) -> Self {
+        Self {
+            hotel: HotelResponse::from(hit.hotel),
+            rank: hit.rank,
+            snippet: highlight_snippet(&hit.snippet),
+        }
+    }
+}
+
+/// Escapes a `ts_headline` snippet for HTML, then turns its match delimiters
+/// (`\u{2}` and `\u{3}`) into `<mark>` tags.
+fn highlight_snippet(snippet: &str) -> String {
+    let mut html: String = String::with_capacity(snippet.len());
+
+    for c in snippet.chars() {
+        match c {
+            '\u{2}' => html.push_str("<mark>"),
+            '\u{3}' => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+
+    html
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HotelSearchResponse {
+    pub items: Vec<HotelSearchResult>,
+    /// True when no hotel matched any word of the query and the results come
+    /// from the typo-tolerant fallback on name, city and country.
+    pub fuzzy: bool,
+}
+
+/// Hotel with the room types that can be booked for a whole stay.
+#[derive(Debug, Clone, FromRow)]
+pub struct HotelAvailability {
+    #[sqlx(flatten)]
+    pub hotel: Hotel,
+    pub room_type_ids: Vec<i32>,
+}
+
+/// Cheapest bookable offer at a hotel, priced like a quote.
+#[derive(Debug, Clone)]
+pub struct HotelOffer {
+    pub hotel: Hotel,
+    pub room_type_id: i32,
+    pub breakdown: QuoteBreakdown,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AvailabilityQuery {
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HotelAvailabilityResponse {
+    pub hotel: HotelResponse,
+    /// Room type of the cheapest offer.
+    pub room_type_id: i32,
+    /// Rate plan of the cheapest offer; absent when it is the room type's
+    /// base price.
+    pub rate_plan_id: Option<i32>,
+    /// Total for the whole stay including fees and taxes, as a quote for the
+    /// same stay would price it.
+    pub cheapest_total_price: Decimal,
+}
+
+impl From<HotelOffer> for HotelAvailabilityResponse {
+    fn from(offer: HotelOffer) -> Self {
+        Self {
+            hotel: HotelResponse::from(offer.hotel),
+            room_type_id: offer.room_type_id,
+            rate_plan_id: offer.breakdown.price.rate_plan_id,
+            cheapest_total_price: offer.breakdown.total_price,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(date: &str, time: &str) -> chrono::NaiveDateTime {
+        chrono::NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M")
+            .unwrap()
+    }
+
+    #[test]
+    fn local_to_utc_applies_offset() {
+        assert_eq!(
+            local_to_utc("Asia/Tokyo", local("2030-01-10", "15:00")),
+            local("2030-01-10", "06:00").and_utc()
+        );
+        assert_eq!(
+            local_to_utc("Europe/Berlin", local("2030-07-10", "15:00")),
+            local("2030-07-10", "13:00").and_utc()
+        );
+    }
+
+    #[test]
+    fn local_to_utc_handles_dst_gap() {
+        assert_eq!(
+            local_to_utc("Europe/Berlin", local("2030-03-31", "02:30")),
+            local("2030-03-31", "01:30").and_utc()
+        );
+    }
+
+    #[test]
+    fn local_to_utc_falls_back_to_utc() {
+        assert_eq!(
+            local_to_utc("Not/AZone", local("2030-01-10", "15:00")),
+            local("2030-01-10", "15:00").and_utc()
+        );
+    }
+
+    #[test]
+    fn highlight_snippet_escapes_text_around_marks() {
+        assert_eq!(
+            highlight_snippet("<script> & \u{2}Beach\u{3} \"view\" it's"),
+            "&lt;script&gt; &amp; <mark>Beach</mark> &quot;view&quot; it&#39;s"
+        );
+    }
+
+    fn create_request(name: &str, country: &str, timezone: Option<&str>) -> CreateHotelRequest {
+        CreateHotelRequest {
+            name: name.to_string(),
+            description: None,
+            address: "1 Main Street".to_string(),
+            city: "Vienna".to_string(),
+            country: country.to_string(),
+            timezone: timezone.map(str::to_string),
+            check_in_time: None,
+            language: None,
+            owner_id: None,
+        }
+    }
+
+    #[test]
+    fn create_hotel_request_accepts_valid_input() {
+        assert!(
+            create_request("Hotel", "Austria", Some("Europe/Vienna"))
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn create_hotel_request_reports_each_invalid_field() {
+        let errors = create_request("", &"x".repeat(10_000), Some("Mars/Olympus_Mons"))
+            .validate()
+            .unwrap_err();
+        let fields = errors.field_errors();
+
+        assert_eq!(fields["name"][0].code, "length");
+        assert_eq!(fields["country"][0].code, "length");
+        assert_eq!(fields["timezone"][0].code, "timezone");
+        assert!(!fields.contains_key("city"));
+    }
+
+    #[test]
+    fn create_hotel_request_rejects_blank_fields() {
+        let mut request = create_request("   ", "Austria", None);
+        request.city = "\t".to_string();
+        let errors = request.validate().unwrap_err();
+        let fields = errors.field_errors();
+
+        assert_eq!(fields["name"][0].code, "blank");
+        assert_eq!(fields["city"][0].code, "blank");
+        assert!(!fields.contains_key("address"));
+    }
+
+    fn patch_request(body: &str) -> PatchHotelRequest {
+        serde_json::from_str(body).unwrap()
+    }
+
+    #[test]
+    fn patch_hotel_request_distinguishes_absent_and_null() {
+        let request = patch_request(r#"{"name": "Hotel", "description": null}"#);
+
+        assert_eq!(request.name, Patch::Value("Hotel".to_string()));
+        assert_eq!(request.description, Patch::Null);
+        assert_eq!(request.city, Patch::Missing);
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn patch_hotel_request_rejects_null_for_required_fields() {
+        let errors = patch_request(r#"{"name": null, "timezone": "Mars/Olympus_Mons"}"#)
+            .validate()
+            .unwrap_err();
+        let fields = errors.field_errors();
+
+        assert_eq!(fields["name"][0].code, "not_null");
+        assert_eq!(fields["timezone"][0].code, "timezone");
+        assert!(!fields.contains_key("description"));
+    }
+
+    #[test]
+    fn patch_hotel_request_rejects_blank_fields() {
+        let errors = patch_request(r#"{"name": "  ", "country": "Austria"}"#)
+            .validate()
+            .unwrap_err();
+        let fields = errors.field_errors();
+
+        assert_eq!(fields["name"].len(), 1);
+        assert_eq!(fields["name"][0].code, "blank");
+        assert!(!fields.contains_key("country"));
+    }
+
+    fn hotel(id: i32, rating: Option<Decimal>) -> Hotel {
+        Hotel {
+            id,
+            name: "Hotel".to_string(),
+            description: None,
+            address: "1 Main Street".to_string(),
+            city: "Vienna".to_string(),
+            country: "Austria".to_string(),
+            rating,
+            total_reviews: None,
+            timezone: "UTC".to_string(),
+            check_in_time: chrono::NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+            language: "en".to_string(),
+            created_at: local("2030-01-10", "15:00"),
+            updated_at: local("2030-01-10", "15:00"),
+        }
+    }
+
+    #[test]
+    fn hotel_cursor_round_trips() {
+        for sort in [HotelSort::Name, HotelSort::Rating, HotelSort::CreatedAt] {
+            let cursor = HotelCursor::after(&hotel(7, None), sort, SortOrder::Desc);
+
+            assert_eq!(HotelCursor::decode(&cursor.encode()), Some(cursor));
+        }
+    }
+
+    #[test]
+    fn hotel_cursor_rejects_tampered_values() {
+        let mut cursor = HotelCursor::after(
+            &hotel(7, Some(Decimal::new(45, 1))),
+            HotelSort::Rating,
+            SortOrder::Asc,
+        );
+        assert_eq!(cursor.value, "4.5");
+        cursor.value = "4.5; DROP TABLE hotels".to_string();
+
+        assert_eq!(HotelCursor::decode(&cursor.encode()), None);
+        assert_eq!(HotelCursor::decode("not hex"), None);
+    }
+}
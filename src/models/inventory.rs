@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InventoryDay {
+    pub room_type_id: i32,
+    pub day: chrono::NaiveDate,
+    pub sold: i32,
+    pub held: i32,
+    pub stop_sell: bool,
+    pub closed_to_arrival: bool,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct CalendarDay {
+    pub room_type_id: i32,
+    pub room_type_name: String,
+    pub day: chrono::NaiveDate,
+    pub total: i64,
+    pub sold: i32,
+    pub held: i32,
+    pub stop_sell: bool,
+    pub closed_to_arrival: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarQuery {
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateRestrictionsRequest {
+    pub room_type_id: i32,
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub stop_sell: Option<bool>,
+    pub closed_to_arrival: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CalendarDayResponse {
+    pub date: chrono::NaiveDate,
+    pub total: i64,
+    pub sold: i32,
+    pub held: i32,
+    pub available: i64,
+    pub stop_sell: bool,
+    pub closed_to_arrival: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RoomTypeCalendarResponse {
+    pub room_type_id: i32,
+    pub room_type_name: String,
+    pub days: Vec<CalendarDayResponse>,
+}
+
+impl From<CalendarDay> for CalendarDayResponse {
+    fn from(day: CalendarDay) -> Self {
+        let available: i64 = if day.stop_sell {
+            0
+        } else {
+            (day.total - i64::from(day.sold + day.held)).max(0)
+        };
+
+        Self {
+            date: day.day,
+            total: day.total,
+            sold: day.sold,
+            held: day.held,
+            available,
+            stop_sell: day.stop_sell,
+            closed_to_arrival: day.closed_to_arrival,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AccountLockout {
+    pub id: i32,
+    pub user_id: i32,
+    pub locked_until: chrono::NaiveDateTime,
+    pub failed_attempts: i32,
+    pub ip_address: String,
+    pub unlocked_at: Option<chrono::NaiveDateTime>,
+    pub unlocked_by: Option<i32>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ActiveLockout {
+    pub id: i32,
+    pub user_id: i32,
+    pub email: String,
+    pub locked_until: chrono::NaiveDateTime,
+    pub failed_attempts: i32,
+    pub ip_address: String,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LockoutResponse {
+    pub id: i32,
+    pub user_id: i32,
+    pub email: String,
+    pub locked_until: chrono::NaiveDateTime,
+    pub failed_attempts: i32,
+    /// Address of the request that triggered the lock.
+    pub ip_address: String,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<ActiveLockout> for LockoutResponse {
+    fn from(lockout: ActiveLockout) -> Self {
+        Self {
+            id: lockout.id,
+            user_id: lockout.user_id,
+            email: lockout.email,
+            locked_until: lockout.locked_until,
+            failed_attempts: lockout.failed_attempts,
+            ip_address: lockout.ip_address,
+            created_at: lockout.created_at,
+        }
+    }
+}
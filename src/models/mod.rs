@@ -1,5 +1,18 @@
+pub mod bookings;
+pub mod emails;
 pub mod health;
+pub mod holds;
 pub mod hotels;
+pub mod inventory;
+pub mod lockouts;
+pub mod quotes;
+pub mod rate_limits;
+pub mod rate_plans;
+pub mod reviews;
+pub mod room_types;
+pub mod rooms;
+pub mod staff;
+pub mod taxes;
 pub mod users;
 
 use crate::services::Services;
@@ -7,6 +20,64 @@ use crate::services::Services;
 #[derive(Clone)]
 pub struct AppState {
     pub jwt_secret: String,
-    pub jwt_expire_hours: i64,
+    pub jwt_expire_minutes: i64,
+    pub trust_forwarded_for: bool,
     pub services: Services,
 }
+
+pub fn money(mut amount: rust_decimal::Decimal) -> rust_decimal::Decimal {
+    amount.rescale(2);
+    amount
+}
+
+/// Field of a JSON Merge Patch body. An absent field leaves the stored value
+/// unchanged, `null` clears it and any other value replaces it. Fields must be
+/// marked `#[serde(default)]` so that absent deserializes to `Missing`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Patch<T> {
+    #[default]
+    Missing,
+    Null,
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Patch::Missing)
+    }
+
+    /// The new value, or `None` when the field is absent or `null`.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Patch::Value(value) => Some(value),
+            Patch::Missing | Patch::Null => None,
+        }
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Patch<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Patch::Value(value),
+            None => Patch::Null,
+        })
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Patch<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Patch::Value(value) => value.serialize(serializer),
+            Patch::Missing | Patch::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<T: validator::ValidateLength<u64>> validator::ValidateLength<u64> for Patch<T> {
+    fn length(&self) -> Option<u64> {
+        match self {
+            Patch::Value(value) => value.length(),
+            Patch::Missing | Patch::Null => None,
+        }
+    }
+}
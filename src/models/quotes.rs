@@ -0,0 +1,125 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::enums::QuoteLineKind;
+use crate::models::money;
+use crate::models::rate_plans::{NightlyRate, StayPrice};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuoteLine {
+    pub kind: QuoteLineKind,
+    pub description: String,
+    pub date: Option<chrono::NaiveDate>,
+    pub quantity: i32,
+    pub unit_price: Decimal,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuoteBreakdown {
+    pub price: StayPrice,
+    pub lines: Vec<QuoteLine>,
+    pub room_total: Decimal,
+    pub fees_total: Decimal,
+    pub taxes_total: Decimal,
+    pub total_price: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Quote {
+    pub id: Uuid,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub rate_plan_id: Option<i32>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+    pub lines: Json<Vec<QuoteLine>>,
+    pub room_total: Decimal,
+    pub fees_total: Decimal,
+    pub taxes_total: Decimal,
+    pub total_price: Decimal,
+    pub expires_at: chrono::NaiveDateTime,
+    pub created_at: chrono::NaiveDateTime,
+    /// Requesting user; only they can book with the quote.
+    pub user_id: Option<i32>,
+    /// Set once a booking has been made with the quote.
+    pub used_at: Option<chrono::NaiveDateTime>,
+}
+
+impl Quote {
+    pub fn stay_price(&self) -> StayPrice {
+        let nights: Vec<NightlyRate> = self
+            .lines
+            .iter()
+            .filter(|line| line.kind == QuoteLineKind::RoomNight)
+            .filter_map(|line| {
+                line.date.map(|date| NightlyRate {
+                    date,
+                    price: line.amount,
+                })
+            })
+            .collect();
+
+        StayPrice {
+            rate_plan_id: self.rate_plan_id,
+            check_in: self.check_in,
+            check_out: self.check_out,
+            nights,
+            total_price: self.room_total,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateQuoteRequest {
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub rate_plan_id: Option<i32>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QuoteResponse {
+    pub id: Uuid,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub rate_plan_id: Option<i32>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub guests: i32,
+    pub lines: Vec<QuoteLine>,
+    pub room_total: Decimal,
+    pub fees_total: Decimal,
+    pub taxes_total: Decimal,
+    pub total_price: Decimal,
+    pub expires_at: chrono::NaiveDateTime,
+}
+
+impl From<Quote> for QuoteResponse {
+    fn from(quote: Quote) -> Self {
+        Self {
+            id: quote.id,
+            hotel_id: quote.hotel_id,
+            room_type_id: quote.room_type_id,
+            rate_plan_id: quote.rate_plan_id,
+            check_in: quote.check_in,
+            check_out: quote.check_out,
+            guests: quote.guests,
+            lines: quote.lines.0,
+            room_total: money(quote.room_total),
+            fees_total: money(quote.fees_total),
+            taxes_total: money(quote.taxes_total),
+            total_price: money(quote.total_price),
+            expires_at: quote.expires_at,
+        }
+    }
+}
+
+// Postgres returns zero NUMERIC values without their scale, so totals are rescaled to cents.
@@ -0,0 +1,19 @@
+/// Token bucket holding up to `capacity` requests, refilled continuously at
+/// `per_minute` tokens a minute.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    pub capacity: i64,
+    pub per_minute: i64,
+}
+
+/// Outcome of taking a token, used for the `X-RateLimit-*` headers.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: i64,
+    pub remaining: i64,
+    /// Seconds until the bucket is full again.
+    pub reset_seconds: i64,
+    /// Seconds until the next token is available; zero when allowed.
+    pub retry_after_seconds: i64,
+}
@@ -0,0 +1,321 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::enums::CancellationPenalty;
+use crate::validation::{not_blank, validate_amount};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RatePlan {
+    pub id: i32,
+    pub room_type_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub base_price: Decimal,
+    pub weekend_price: Option<Decimal>,
+    pub free_cancellation_hours: i32,
+    pub cancellation_penalty: CancellationPenalty,
+    pub penalty_percent: Option<Decimal>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl RatePlan {
+    pub fn cancellation_policy(&self) -> CancellationPolicy {
+        CancellationPolicy {
+            free_cancellation_hours: self.free_cancellation_hours,
+            penalty: self.cancellation_penalty,
+            penalty_percent: self.penalty_percent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CancellationPolicy {
+    pub free_cancellation_hours: i32,
+    pub penalty: CancellationPenalty,
+    pub penalty_percent: Option<Decimal>,
+}
+
+impl Default for CancellationPolicy {
+    fn default() -> Self {
+        Self {
+            free_cancellation_hours: 24,
+            penalty: CancellationPenalty::FirstNight,
+            penalty_percent: None,
+        }
+    }
+}
+
+impl CancellationPolicy {
+    pub fn is_valid(&self) -> bool {
+        self.free_cancellation_hours >= 0
+            && match self.penalty {
+                CancellationPenalty::Percentage => self.penalty_percent.is_some_and(|percent| {
+                    !percent.is_sign_negative() && percent <= Decimal::ONE_HUNDRED
+                }),
+                _ => true,
+            }
+    }
+
+    pub fn penalty(
+        &self,
+        total_price: Decimal,
+        first_night: Decimal,
+        check_in_at: chrono::DateTime<chrono::Utc>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Decimal {
+        if self.penalty == CancellationPenalty::NonRefundable {
+            return total_price;
+        }
+
+        let free_until =
+            check_in_at - chrono::Duration::hours(i64::from(self.free_cancellation_hours));
+        if now <= free_until {
+            return Decimal::ZERO;
+        }
+
+        match self.penalty {
+            CancellationPenalty::FirstNight => first_night.min(total_price),
+            CancellationPenalty::Percentage => (total_price
+                * self.penalty_percent.unwrap_or(Decimal::ONE_HUNDRED)
+                / Decimal::ONE_HUNDRED)
+                .round_dp_with_strategy(2, rust_decimal::RoundingStrategy::MidpointAwayFromZero),
+            CancellationPenalty::NonRefundable => total_price,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RatePlanSeason {
+    pub id: i32,
+    pub rate_plan_id: i32,
+    pub name: String,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    pub price: Decimal,
+    pub weekend_price: Option<Decimal>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct CreateRatePlanRequest {
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    #[validate(length(max = 5000))]
+    #[schema(max_length = 5000)]
+    pub description: Option<String>,
+    #[validate(custom(function = "validate_amount"))]
+    pub base_price: Decimal,
+    #[validate(custom(function = "validate_amount"))]
+    pub weekend_price: Option<Decimal>,
+    pub cancellation_policy: Option<CancellationPolicy>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct UpdateRatePlanRequest {
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: Option<String>,
+    #[validate(length(max = 5000))]
+    #[schema(max_length = 5000)]
+    pub description: Option<String>,
+    #[validate(custom(function = "validate_amount"))]
+    pub base_price: Option<Decimal>,
+    #[validate(custom(function = "validate_amount"))]
+    pub weekend_price: Option<Decimal>,
+    pub cancellation_policy: Option<CancellationPolicy>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct CreateSeasonRequest {
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    #[validate(custom(function = "validate_amount"))]
+    pub price: Decimal,
+    #[validate(custom(function = "validate_amount"))]
+    pub weekend_price: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PriceQuery {
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub rate_plan_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SeasonResponse {
+    pub id: i32,
+    pub name: String,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    pub price: Decimal,
+    pub weekend_price: Option<Decimal>,
+}
+
+impl From<RatePlanSeason> for SeasonResponse {
+    fn from(season: RatePlanSeason) -> Self {
+        Self {
+            id: season.id,
+            name: season.name,
+            start_date: season.start_date,
+            end_date: season.end_date,
+            price: season.price,
+            weekend_price: season.weekend_price,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RatePlanResponse {
+    pub id: i32,
+    pub room_type_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub base_price: Decimal,
+    pub weekend_price: Option<Decimal>,
+    pub cancellation_policy: CancellationPolicy,
+    pub seasons: Vec<SeasonResponse>,
+}
+
+impl RatePlanResponse {
+    pub fn new(rate_plan: RatePlan, seasons: Vec<RatePlanSeason>) -> Self {
+        Self {
+            cancellation_policy: rate_plan.cancellation_policy(),
+            id: rate_plan.id,
+            room_type_id: rate_plan.room_type_id,
+            name: rate_plan.name,
+            description: rate_plan.description,
+            base_price: rate_plan.base_price,
+            weekend_price: rate_plan.weekend_price,
+            seasons: seasons.into_iter().map(SeasonResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct NightlyRate {
+    pub date: chrono::NaiveDate,
+    pub price: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StayPrice {
+    pub rate_plan_id: Option<i32>,
+    pub check_in: chrono::NaiveDate,
+    pub check_out: chrono::NaiveDate,
+    pub nights: Vec<NightlyRate>,
+    pub total_price: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn check_in_at() -> chrono::DateTime<chrono::Utc> {
+        chrono::Utc.with_ymd_and_hms(2030, 6, 15, 13, 0, 0).unwrap()
+    }
+
+    fn policy(penalty: CancellationPenalty, percent: Option<Decimal>) -> CancellationPolicy {
+        CancellationPolicy {
+            free_cancellation_hours: 48,
+            penalty,
+            penalty_percent: percent,
+        }
+    }
+
+    #[test]
+    fn free_until_exact_deadline() {
+        let deadline = check_in_at() - chrono::Duration::hours(48);
+        let penalty = policy(CancellationPenalty::FirstNight, None).penalty(
+            Decimal::new(30000, 2),
+            Decimal::new(10000, 2),
+            check_in_at(),
+            deadline,
+        );
+
+        assert_eq!(penalty, Decimal::ZERO);
+    }
+
+    #[test]
+    fn first_night_charged_one_second_after_deadline() {
+        let now = check_in_at() - chrono::Duration::hours(48) + chrono::Duration::seconds(1);
+        let penalty = policy(CancellationPenalty::FirstNight, None).penalty(
+            Decimal::new(30000, 2),
+            Decimal::new(10000, 2),
+            check_in_at(),
+            now,
+        );
+
+        assert_eq!(penalty, Decimal::new(10000, 2));
+    }
+
+    #[test]
+    fn percentage_rounds_to_cents() {
+        let penalty = policy(CancellationPenalty::Percentage, Some(Decimal::new(3333, 2))).penalty(
+            Decimal::new(10001, 2),
+            Decimal::new(5000, 2),
+            check_in_at(),
+            check_in_at(),
+        );
+
+        assert_eq!(penalty, Decimal::new(3333, 2));
+    }
+
+    #[test]
+    fn zero_free_hours_charges_after_check_in_time() {
+        let policy = CancellationPolicy {
+            free_cancellation_hours: 0,
+            ..CancellationPolicy::default()
+        };
+
+        assert_eq!(
+            policy.penalty(
+                Decimal::new(20000, 2),
+                Decimal::new(10000, 2),
+                check_in_at(),
+                check_in_at(),
+            ),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            policy.penalty(
+                Decimal::new(20000, 2),
+                Decimal::new(10000, 2),
+                check_in_at(),
+                check_in_at() + chrono::Duration::seconds(1),
+            ),
+            Decimal::new(10000, 2)
+        );
+    }
+
+    #[test]
+    fn non_refundable_always_charges_total() {
+        let now = check_in_at() - chrono::Duration::days(90);
+        let penalty = policy(CancellationPenalty::NonRefundable, None).penalty(
+            Decimal::new(30000, 2),
+            Decimal::new(10000, 2),
+            check_in_at(),
+            now,
+        );
+
+        assert_eq!(penalty, Decimal::new(30000, 2));
+    }
+
+    #[test]
+    fn percentage_requires_valid_percent() {
+        assert!(!policy(CancellationPenalty::Percentage, None).is_valid());
+        assert!(!policy(CancellationPenalty::Percentage, Some(Decimal::new(101, 0))).is_valid());
+        assert!(policy(CancellationPenalty::Percentage, Some(Decimal::new(50, 0))).is_valid());
+        assert!(policy(CancellationPenalty::FirstNight, None).is_valid());
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Review {
+    pub id: i32,
+    pub booking_id: i32,
+    pub hotel_id: i32,
+    pub user_id: i32,
+    pub score: i32,
+    pub comment: String,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateReviewRequest {
+    pub booking_id: i32,
+    pub score: i32,
+    pub comment: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateReviewRequest {
+    pub score: Option<i32>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReviewsQuery {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReviewResponse {
+    pub id: i32,
+    pub booking_id: i32,
+    pub hotel_id: i32,
+    pub user_id: i32,
+    pub score: i32,
+    pub comment: String,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<Review> for ReviewResponse {
+    fn from(review: Review) -> Self {
+        Self {
+            id: review.id,
+            booking_id: review.booking_id,
+            hotel_id: review.hotel_id,
+            user_id: review.user_id,
+            score: review.score,
+            comment: review.comment,
+            created_at: review.created_at,
+            updated_at: review.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReviewPageResponse {
+    pub items: Vec<ReviewResponse>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+}
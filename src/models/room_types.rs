@@ -0,0 +1,77 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::validation::{not_blank, validate_amount};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RoomType {
+    pub id: i32,
+    pub hotel_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub capacity: i32,
+    pub bed_configuration: String,
+    pub base_price: Decimal,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct CreateRoomTypeRequest {
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    #[validate(length(max = 5000))]
+    #[schema(max_length = 5000)]
+    pub description: Option<String>,
+    pub capacity: i32,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub bed_configuration: String,
+    #[validate(custom(function = "validate_amount"))]
+    pub base_price: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct UpdateRoomTypeRequest {
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: Option<String>,
+    #[validate(length(max = 5000))]
+    #[schema(max_length = 5000)]
+    pub description: Option<String>,
+    pub capacity: Option<i32>,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub bed_configuration: Option<String>,
+    #[validate(custom(function = "validate_amount"))]
+    pub base_price: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RoomTypeResponse {
+    pub id: i32,
+    pub hotel_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub capacity: i32,
+    pub bed_configuration: String,
+    pub base_price: Decimal,
+}
+
+impl From<RoomType> for RoomTypeResponse {
+    fn from(room_type: RoomType) -> Self {
+        Self {
+            id: room_type.id,
+            hotel_id: room_type.hotel_id,
+            name: room_type.name,
+            description: room_type.description,
+            capacity: room_type.capacity,
+            bed_configuration: room_type.bed_configuration,
+            base_price: room_type.base_price,
+        }
+    }
+}
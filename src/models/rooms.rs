@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use crate::enums::RoomStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Room {
+    pub id: i32,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub room_number: String,
+    pub floor: Option<i32>,
+    pub status: RoomStatus,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateRoomRequest {
+    pub room_type_id: i32,
+    pub room_number: String,
+    pub floor: Option<i32>,
+    pub status: Option<RoomStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateRoomRequest {
+    pub room_type_id: Option<i32>,
+    pub room_number: Option<String>,
+    pub floor: Option<i32>,
+    pub status: Option<RoomStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RoomResponse {
+    pub id: i32,
+    pub hotel_id: i32,
+    pub room_type_id: i32,
+    pub room_number: String,
+    pub floor: Option<i32>,
+    pub status: RoomStatus,
+}
+
+impl From<Room> for RoomResponse {
+    fn from(room: Room) -> Self {
+        Self {
+            id: room.id,
+            hotel_id: room.hotel_id,
+            room_type_id: room.room_type_id,
+            room_number: room.room_number,
+            floor: room.floor,
+            status: room.status,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use crate::enums::StaffRole;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct HotelStaff {
+    pub hotel_id: i32,
+    pub user_id: i32,
+    pub role: StaffRole,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct StaffMember {
+    pub user_id: i32,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub role: StaffRole,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InviteStaffRequest {
+    pub email: String,
+    pub role: StaffRole,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StaffMemberResponse {
+    pub user_id: i32,
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub role: StaffRole,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<StaffMember> for StaffMemberResponse {
+    fn from(member: StaffMember) -> Self {
+        Self {
+            user_id: member.user_id,
+            email: member.email,
+            first_name: member.first_name,
+            last_name: member.last_name,
+            role: member.role,
+            created_at: member.created_at,
+        }
+    }
+}
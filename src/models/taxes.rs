@@ -0,0 +1,80 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::validation::{not_blank, validate_amount};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaxSettings {
+    pub city_tax_per_person_night: Decimal,
+    pub vat_percent: Decimal,
+}
+
+/// Tax settings together with the hotel they belong to.
+#[derive(Debug, Clone, FromRow)]
+pub struct HotelTaxSettings {
+    pub hotel_id: i32,
+    #[sqlx(flatten)]
+    pub settings: TaxSettings,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct HotelFee {
+    pub id: i32,
+    pub hotel_id: i32,
+    pub name: String,
+    pub amount: Decimal,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct UpdateTaxesRequest {
+    #[validate(custom(function = "validate_amount"))]
+    pub city_tax_per_person_night: Option<Decimal>,
+    pub vat_percent: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct CreateFeeRequest {
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    #[validate(custom(function = "validate_amount"))]
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FeeResponse {
+    pub id: i32,
+    pub name: String,
+    pub amount: Decimal,
+}
+
+impl From<HotelFee> for FeeResponse {
+    fn from(fee: HotelFee) -> Self {
+        Self {
+            id: fee.id,
+            name: fee.name,
+            amount: fee.amount,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TaxesResponse {
+    pub city_tax_per_person_night: Decimal,
+    pub vat_percent: Decimal,
+    pub fees: Vec<FeeResponse>,
+}
+
+impl TaxesResponse {
+    pub fn new(settings: TaxSettings, fees: Vec<HotelFee>) -> Self {
+        Self {
+            city_tax_per_person_night: settings.city_tax_per_person_night,
+            vat_percent: settings.vat_percent,
+            fees: fees.into_iter().map(FeeResponse::from).collect(),
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::enums::UserRole;
+use crate::models::Patch;
+use crate::validation::{not_blank, not_blank_patch, not_null};
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct User {
@@ -9,22 +14,61 @@ pub struct User {
     pub password_hash: String,
     pub first_name: String,
     pub last_name: String,
+    pub role: UserRole,
+    pub token_version: i32,
+    pub email_verified_at: Option<chrono::NaiveDateTime>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled_at: Option<chrono::NaiveDateTime>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct RegisterRequest {
+    #[validate(email, length(max = 255))]
+    #[schema(format = Email, max_length = 255)]
     pub email: String,
+    #[validate(length(min = 6, max = 72))]
+    #[schema(format = Password, min_length = 6, max_length = 72)]
     pub password: String,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub first_name: String,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
     pub last_name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct UpdateUserRequest {
-    pub first_name: Option<String>,
-    pub last_name: Option<String>,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub first_name: String,
+    #[validate(length(min = 1, max = 100), custom(function = "not_blank"))]
+    #[schema(min_length = 1, max_length = 100)]
+    pub last_name: String,
+}
+
+/// JSON Merge Patch of a profile; absent fields are unchanged and neither
+/// name can be cleared.
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct PatchUserRequest {
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        length(min = 1, max = 100),
+        custom(function = "not_blank_patch")
+    )]
+    #[schema(value_type = String, required = false, min_length = 1, max_length = 100)]
+    pub first_name: Patch<String>,
+    #[serde(default)]
+    #[validate(
+        custom(function = "not_null"),
+        length(min = 1, max = 100),
+        custom(function = "not_blank_patch")
+    )]
+    #[schema(value_type = String, required = false, min_length = 1, max_length = 100)]
+    pub last_name: Patch<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -39,18 +83,120 @@ pub struct UserResponse {
     pub email: String,
     pub first_name: String,
     pub last_name: String,
+    pub role: UserRole,
+    pub email_verified: bool,
+    pub two_factor_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateRoleRequest {
+    pub role: UserRole,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct AuthResponse {
     pub user: UserResponse,
     pub token: String,
+    pub refresh_token: String,
+    /// Lifetime of `token` in seconds.
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct LogoutRequest {
+    /// Also revokes this refresh token and the rest of its family.
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct ForgotPasswordRequest {
+    #[validate(email)]
+    #[schema(format = Email)]
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    #[validate(length(min = 6, max = 72))]
+    #[schema(format = Password, min_length = 6, max_length = 72)]
+    pub new_password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct ChangePasswordRequest {
+    #[schema(format = Password)]
+    pub current_password: String,
+    #[validate(length(min = 6, max = 72))]
+    #[schema(format = Password, min_length = 6, max_length = 72)]
+    pub new_password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerifyEmailRequest {
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorSetupResponse {
+    /// Base32 secret for authenticators that cannot scan the URI.
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorCodeRequest {
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RecoveryCodesResponse {
+    /// Shown once; each code can replace a TOTP code a single time.
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorChallengeResponse {
+    pub challenge_token: String,
+    /// Lifetime of `challenge_token` in seconds.
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorVerifyRequest {
+    pub challenge_token: String,
+    /// A current TOTP code or an unused recovery code.
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RefreshToken {
+    pub id: uuid::Uuid,
+    pub user_id: i32,
+    pub family_id: uuid::Uuid,
+    pub token_hash: String,
+    pub expires_at: chrono::NaiveDateTime,
+    pub rotated_at: Option<chrono::NaiveDateTime>,
+    pub revoked_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: i32,
     pub email: String,
+    #[serde(default)]
+    pub role: UserRole,
+    #[serde(default)]
+    pub jti: String,
+    /// The user's token version when this token was issued.
+    #[serde(default)]
+    pub ver: i32,
     pub exp: i64,
     pub iat: i64,
 }
@@ -62,6 +208,9 @@ impl From<User> for UserResponse {
             email: user.email,
             first_name: user.first_name,
             last_name: user.last_name,
+            role: user.role,
+            email_verified: user.email_verified_at.is_some(),
+            two_factor_enabled: user.totp_enabled_at.is_some(),
         }
     }
 }
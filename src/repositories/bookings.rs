@@ -0,0 +1,240 @@
+use crate::enums::{BookingError, BookingStatus, HoldStatus};
+use crate::models::bookings::{Booking, BookingNight};
+use crate::models::emails::NewEmail;
+use crate::models::rate_plans::{CancellationPolicy, StayPrice};
+use crate::repositories::email_outbox;
+use crate::repositories::inventory::lock_available_days;
+use rust_decimal::Decimal;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct BookingRepository {
+    pool: Pool<Postgres>,
+}
+
+impl BookingRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_user(&self, user_id: i32) -> Result<Vec<Booking>, sqlx::Error> {
+        sqlx::query_as::<_, Booking>(
+            "SELECT * FROM bookings WHERE user_id = $1 ORDER BY check_in DESC, id DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<Booking>, sqlx::Error> {
+        sqlx::query_as::<_, Booking>("SELECT * FROM bookings WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn list_nights(&self, booking_id: i32) -> Result<Vec<BookingNight>, sqlx::Error> {
+        sqlx::query_as::<_, BookingNight>(
+            "SELECT * FROM booking_nights WHERE booking_id = $1 ORDER BY night ASC",
+        )
+        .bind(booking_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_if_available(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        room_type_id: i32,
+        guests: i32,
+        price: &StayPrice,
+        total_price: Decimal,
+        quote_id: Option<Uuid>,
+        hold_id: Option<Uuid>,
+        cancellation_policy: &CancellationPolicy,
+        confirmation_recipient: &str,
+    ) -> Result<Booking, BookingError> {
+        let (check_in, check_out) = (price.check_in, price.check_out);
+        let mut tx = self.pool.begin().await?;
+
+        if let Some(quote_id) = quote_id {
+            sqlx::query_scalar::<_, Uuid>(
+                "UPDATE quotes SET used_at = CURRENT_TIMESTAMP
+                 WHERE id = $1 AND user_id = $2 AND used_at IS NULL AND expires_at > $3
+                 RETURNING id",
+            )
+            .bind(quote_id)
+            .bind(user_id)
+            .bind(chrono::Utc::now().naive_utc())
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(BookingError::QuoteExpired)?;
+        }
+
+        match hold_id {
+            Some(hold_id) => {
+                sqlx::query_scalar::<_, Uuid>(
+                    "UPDATE booking_holds SET status = $1, updated_at = CURRENT_TIMESTAMP
+                     WHERE id = $2 AND status = $3 AND expires_at > $4
+                     RETURNING id",
+                )
+                .bind(HoldStatus::Consumed)
+                .bind(hold_id)
+                .bind(HoldStatus::Active)
+                .bind(chrono::Utc::now().naive_utc())
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or(BookingError::HoldExpired)?;
+
+                sqlx::query(
+                    "UPDATE inventory_days SET held = held - 1, sold = sold + 1, updated_at = CURRENT_TIMESTAMP
+                     WHERE room_type_id = $1 AND day >= $2 AND day < $3",
+                )
+                .bind(room_type_id)
+                .bind(check_in)
+                .bind(check_out)
+                .execute(&mut *tx)
+                .await?;
+            }
+            None => {
+                lock_available_days(&mut tx, room_type_id, check_in, check_out).await?;
+
+                sqlx::query(
+                    "UPDATE inventory_days SET sold = sold + 1, updated_at = CURRENT_TIMESTAMP
+                     WHERE room_type_id = $1 AND day >= $2 AND day < $3",
+                )
+                .bind(room_type_id)
+                .bind(check_in)
+                .bind(check_out)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        let booking: Booking = sqlx::query_as::<_, Booking>(
+            "INSERT INTO bookings (user_id, hotel_id, room_type_id, rate_plan_id, quote_id, hold_id, check_in, check_out, guests,
+             total_price, free_cancellation_hours, cancellation_penalty, penalty_percent)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+             RETURNING *",
+        )
+        .bind(user_id)
+        .bind(hotel_id)
+        .bind(room_type_id)
+        .bind(price.rate_plan_id)
+        .bind(quote_id)
+        .bind(hold_id)
+        .bind(check_in)
+        .bind(check_out)
+        .bind(guests)
+        .bind(total_price)
+        .bind(cancellation_policy.free_cancellation_hours)
+        .bind(cancellation_policy.penalty)
+        .bind(cancellation_policy.penalty_percent)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let nights: Vec<chrono::NaiveDate> = price.nights.iter().map(|night| night.date).collect();
+        let prices: Vec<Decimal> = price.nights.iter().map(|night| night.price).collect();
+        sqlx::query(
+            "INSERT INTO booking_nights (booking_id, night, price)
+             SELECT $1, night, price FROM UNNEST($2::DATE[], $3::NUMERIC[]) AS n(night, price)",
+        )
+        .bind(booking.id)
+        .bind(nights)
+        .bind(prices)
+        .execute(&mut *tx)
+        .await?;
+
+        email_outbox::enqueue(
+            &mut tx,
+            &NewEmail::booking_confirmation(confirmation_recipient, &booking),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(booking)
+    }
+
+    pub async fn update_status(
+        &self,
+        id: i32,
+        from: BookingStatus,
+        to: BookingStatus,
+    ) -> Result<Option<Booking>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let booking: Option<Booking> = sqlx::query_as::<_, Booking>(
+            "UPDATE bookings SET status = $1, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $2 AND status = $3
+             RETURNING *",
+        )
+        .bind(to)
+        .bind(id)
+        .bind(from)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(booking) = &booking
+            && to.releases_inventory()
+        {
+            release_inventory(&mut tx, booking).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(booking)
+    }
+
+    pub async fn cancel(
+        &self,
+        id: i32,
+        from: BookingStatus,
+        penalty_amount: Decimal,
+        refund_amount: Decimal,
+    ) -> Result<Option<Booking>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let booking: Option<Booking> = sqlx::query_as::<_, Booking>(
+            "UPDATE bookings SET status = $1, penalty_amount = $2, refund_amount = $3,
+             cancelled_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $4 AND status = $5
+             RETURNING *",
+        )
+        .bind(BookingStatus::Cancelled)
+        .bind(penalty_amount)
+        .bind(refund_amount)
+        .bind(id)
+        .bind(from)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(booking) = &booking {
+            release_inventory(&mut tx, booking).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(booking)
+    }
+}
+
+async fn release_inventory(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    booking: &Booking,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE inventory_days SET sold = sold - 1, updated_at = CURRENT_TIMESTAMP
+         WHERE room_type_id = $1 AND day >= $2 AND day < $3",
+    )
+    .bind(booking.room_type_id)
+    .bind(booking.check_in)
+    .bind(booking.check_out)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::enums::EmailStatus;
+use crate::models::emails::{NewEmail, OutboxEmail};
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct EmailOutboxRepository {
+    pool: Pool<Postgres>,
+}
+
+impl EmailOutboxRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    /// Locks up to `limit` pending emails that are due and pushes their next
+    /// attempt to `lease_until`, so a concurrent worker skips them while they
+    /// are being sent.
+    pub async fn claim_due(
+        &self,
+        now: chrono::NaiveDateTime,
+        lease_until: chrono::NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<OutboxEmail>, sqlx::Error> {
+        sqlx::query_as::<_, OutboxEmail>(
+            "UPDATE email_outbox SET next_attempt_at = $1
+             WHERE id IN (
+                 SELECT id FROM email_outbox
+                 WHERE status = $2 AND next_attempt_at <= $3
+                 ORDER BY next_attempt_at ASC, id ASC
+                 LIMIT $4
+                 FOR UPDATE SKIP LOCKED
+             )
+             RETURNING *",
+        )
+        .bind(lease_until)
+        .bind(EmailStatus::Pending)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn mark_sent(&self, id: i64, now: chrono::NaiveDateTime) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE email_outbox SET status = $1, attempts = attempts + 1, sent_at = $2, last_error = NULL
+             WHERE id = $3",
+        )
+        .bind(EmailStatus::Sent)
+        .bind(now)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records a failed attempt, either scheduling a retry at `next_attempt_at`
+    /// or, with `None`, giving up on the email.
+    pub async fn mark_failed(
+        &self,
+        id: i64,
+        error: &str,
+        next_attempt_at: Option<chrono::NaiveDateTime>,
+    ) -> Result<(), sqlx::Error> {
+        let status: EmailStatus = match next_attempt_at {
+            Some(_) => EmailStatus::Pending,
+            None => EmailStatus::Failed,
+        };
+
+        sqlx::query(
+            "UPDATE email_outbox SET status = $1, attempts = attempts + 1, last_error = $2,
+             next_attempt_at = COALESCE($3, next_attempt_at)
+             WHERE id = $4",
+        )
+        .bind(status)
+        .bind(error)
+        .bind(next_attempt_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Queues an email as part of the caller's transaction, so it is only sent if
+/// the change that triggered it commits.
+pub async fn enqueue(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    email: &NewEmail,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO email_outbox (recipient, subject, body) VALUES ($1, $2, $3)")
+        .bind(&email.recipient)
+        .bind(&email.subject)
+        .bind(&email.body)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::models::emails::NewEmailVerification;
+use crate::repositories::email_outbox;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct EmailVerificationRepository {
+    pool: Pool<Postgres>,
+}
+
+impl EmailVerificationRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    /// Stores a verification token and queues the email that delivers it.
+    pub async fn create(
+        &self,
+        user_id: i32,
+        verification: &NewEmailVerification,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        insert(&mut tx, user_id, verification).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Spends a live verification token and marks its user's email verified.
+    /// Returns the user id, or `None` if the token is unknown, expired or
+    /// already used.
+    pub async fn consume(
+        &self,
+        token_hash: &str,
+        now: chrono::NaiveDateTime,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let user_id: Option<i32> = sqlx::query_scalar::<_, i32>(
+            "UPDATE email_verification_tokens SET used_at = $1
+             WHERE token_hash = $2 AND used_at IS NULL AND expires_at > $1
+             RETURNING user_id",
+        )
+        .bind(now)
+        .bind(token_hash)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(user_id) = user_id else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            "UPDATE users SET email_verified_at = COALESCE(email_verified_at, $1), updated_at = CURRENT_TIMESTAMP
+             WHERE id = $2",
+        )
+        .bind(now)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "UPDATE email_verification_tokens SET used_at = $1
+             WHERE user_id = $2 AND used_at IS NULL",
+        )
+        .bind(now)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(user_id))
+    }
+}
+
+/// Stores a verification token and queues its email as part of the caller's
+/// transaction.
+pub async fn insert(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    user_id: i32,
+    verification: &NewEmailVerification,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO email_verification_tokens (user_id, token_hash, expires_at)
+         VALUES ($1, $2, $3)",
+    )
+    .bind(user_id)
+    .bind(&verification.token_hash)
+    .bind(verification.expires_at)
+    .execute(&mut **tx)
+    .await?;
+
+    email_outbox::enqueue(tx, &verification.email).await
+}
@@ -0,0 +1,178 @@
+use crate::enums::{BookingError, HoldStatus};
+use crate::models::holds::BookingHold;
+use crate::repositories::inventory::lock_available_days;
+use redis::aio::MultiplexedConnection;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct HoldRepository {
+    pool: Pool<Postgres>,
+    redis_conn: MultiplexedConnection,
+}
+
+impl HoldRepository {
+    pub fn new(pool: Pool<Postgres>, redis_conn: MultiplexedConnection) -> Self {
+        Self { pool, redis_conn }
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<BookingHold>, sqlx::Error> {
+        sqlx::query_as::<_, BookingHold>("SELECT * FROM booking_holds WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        room_type_id: i32,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+        expires_at: chrono::NaiveDateTime,
+    ) -> Result<BookingHold, BookingError> {
+        let mut tx = self.pool.begin().await?;
+
+        lock_available_days(&mut tx, room_type_id, check_in, check_out).await?;
+
+        sqlx::query(
+            "UPDATE inventory_days SET held = held + 1, updated_at = CURRENT_TIMESTAMP
+             WHERE room_type_id = $1 AND day >= $2 AND day < $3",
+        )
+        .bind(room_type_id)
+        .bind(check_in)
+        .bind(check_out)
+        .execute(&mut *tx)
+        .await?;
+
+        let hold: BookingHold = sqlx::query_as::<_, BookingHold>(
+            "INSERT INTO booking_holds (user_id, hotel_id, room_type_id, check_in, check_out, guests, expires_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING *",
+        )
+        .bind(user_id)
+        .bind(hotel_id)
+        .bind(room_type_id)
+        .bind(check_in)
+        .bind(check_out)
+        .bind(guests)
+        .bind(expires_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(hold)
+    }
+
+    pub async fn release(
+        &self,
+        id: Uuid,
+        to: HoldStatus,
+    ) -> Result<Option<BookingHold>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let hold: Option<BookingHold> = sqlx::query_as::<_, BookingHold>(
+            "UPDATE booking_holds SET status = $1, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $2 AND status = $3
+             RETURNING *",
+        )
+        .bind(to)
+        .bind(id)
+        .bind(HoldStatus::Active)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(hold) = &hold {
+            release_held_days(&mut tx, hold).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(hold)
+    }
+
+    pub async fn release_expired(
+        &self,
+        now: chrono::NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<BookingHold>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // SKIP LOCKED lets several API instances sweep at once without releasing a hold twice.
+        let holds: Vec<BookingHold> = sqlx::query_as::<_, BookingHold>(
+            "WITH expired AS (
+                 SELECT id FROM booking_holds
+                 WHERE status = $1 AND expires_at <= $2
+                 ORDER BY expires_at ASC
+                 LIMIT $3
+                 FOR UPDATE SKIP LOCKED
+             )
+             UPDATE booking_holds SET status = $4, updated_at = CURRENT_TIMESTAMP
+             FROM expired WHERE booking_holds.id = expired.id
+             RETURNING booking_holds.*",
+        )
+        .bind(HoldStatus::Active)
+        .bind(now)
+        .bind(limit)
+        .bind(HoldStatus::Expired)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for hold in &holds {
+            release_held_days(&mut tx, hold).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(holds)
+    }
+
+    pub async fn set_marker(&self, id: Uuid, ttl_seconds: i64) -> Result<(), redis::RedisError> {
+        redis::cmd("SET")
+            .arg(marker_key(id))
+            .arg(1)
+            .arg("EX")
+            .arg(ttl_seconds)
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn marker_exists(&self, id: Uuid) -> Result<bool, redis::RedisError> {
+        redis::cmd("EXISTS")
+            .arg(marker_key(id))
+            .query_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn delete_marker(&self, id: Uuid) -> Result<(), redis::RedisError> {
+        redis::cmd("DEL")
+            .arg(marker_key(id))
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+}
+
+fn marker_key(id: Uuid) -> String {
+    format!("hold:{}", id)
+}
+
+async fn release_held_days(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    hold: &BookingHold,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE inventory_days SET held = held - 1, updated_at = CURRENT_TIMESTAMP
+         WHERE room_type_id = $1 AND day >= $2 AND day < $3",
+    )
+    .bind(hold.room_type_id)
+    .bind(hold.check_in)
+    .bind(hold.check_out)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
@@ -1,6 +1,18 @@
-use crate::models::hotels::Hotel;
+use crate::enums::{HotelSort, SortOrder, StaffRole};
+use crate::models::Patch;
+use crate::models::hotels::{Hotel, HotelAvailability, HotelCursor, HotelSearchHit};
+use rust_decimal::Decimal;
 use sqlx::{Pool, Postgres};
 
+/// Matches are delimited with control characters, stripped from the source
+/// text first, and only become `<mark>` tags once the snippet is HTML-escaped.
+const HEADLINE_OPTIONS: &str =
+    "StartSel=\u{2}, StopSel=\u{3}, MaxWords=30, MinWords=10, MaxFragments=2";
+const SIMILARITY_THRESHOLD: &str = "0.3";
+/// Weights for D, C, B and A (description, location, name) labels. Kept close
+/// together so matching more words beats a single match in the name.
+const RANK_WEIGHTS: [f32; 4] = [0.1, 0.2, 0.4, 0.6];
+
 #[derive(Clone)]
 pub struct HotelRepository {
     pool: Pool<Postgres>,
@@ -11,10 +23,162 @@ impl HotelRepository {
         Self { pool }
     }
 
-    pub async fn list_all(&self) -> Result<Vec<Hotel>, sqlx::Error> {
-        sqlx::query_as::<_, Hotel>("SELECT * FROM hotels ORDER BY id ASC")
-            .fetch_all(&self.pool)
-            .await
+    /// One keyset page: hotels that sort after `after`, in `sort` order with
+    /// id as tie-breaker.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list(
+        &self,
+        city: Option<String>,
+        country: Option<String>,
+        min_rating: Option<Decimal>,
+        sort: HotelSort,
+        order: SortOrder,
+        after: Option<HotelCursor>,
+        limit: i64,
+    ) -> Result<Vec<Hotel>, sqlx::Error> {
+        let (key, key_type): (&str, &str) = match sort {
+            HotelSort::Name => ("h.name", "VARCHAR"),
+            HotelSort::Rating => ("COALESCE(h.rating, 0)", "NUMERIC"),
+            HotelSort::CreatedAt => ("h.created_at", "TIMESTAMP"),
+        };
+        let (direction, comparison): (&str, &str) = match order {
+            SortOrder::Asc => ("ASC", ">"),
+            SortOrder::Desc => ("DESC", "<"),
+        };
+        let (after_value, after_id) = after.map(|cursor| (cursor.value, cursor.id)).unzip();
+
+        sqlx::query_as::<_, Hotel>(&format!(
+            "SELECT h.* FROM hotels h
+             WHERE ($1::VARCHAR IS NULL OR lower(h.city) = lower($1))
+             AND ($2::VARCHAR IS NULL OR lower(h.country) = lower($2))
+             AND ($3::NUMERIC IS NULL OR COALESCE(h.rating, 0) >= $3)
+             AND ($4::{key_type} IS NULL OR ({key}, h.id) {comparison} ($4::{key_type}, $5))
+             ORDER BY {key} {direction}, h.id {direction}
+             LIMIT $6"
+        ))
+        .bind(city)
+        .bind(country)
+        .bind(min_rating)
+        .bind(after_value)
+        .bind(after_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn count(
+        &self,
+        city: Option<String>,
+        country: Option<String>,
+        min_rating: Option<Decimal>,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM hotels h
+             WHERE ($1::VARCHAR IS NULL OR lower(h.city) = lower($1))
+             AND ($2::VARCHAR IS NULL OR lower(h.country) = lower($2))
+             AND ($3::NUMERIC IS NULL OR COALESCE(h.rating, 0) >= $3)",
+        )
+        .bind(city)
+        .bind(country)
+        .bind(min_rating)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn search_available(
+        &self,
+        city: Option<String>,
+        country: Option<String>,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<Vec<HotelAvailability>, sqlx::Error> {
+        sqlx::query_as::<_, HotelAvailability>(
+            "SELECT h.*, ARRAY_AGG(rt.id ORDER BY rt.id) AS room_type_ids
+             FROM hotels h
+             JOIN room_types rt ON rt.hotel_id = h.id
+             CROSS JOIN LATERAL (
+                 SELECT COUNT(*) AS total FROM rooms r
+                 WHERE r.room_type_id = rt.id AND r.status <> 'out_of_service'
+             ) inventory
+             WHERE ($1::VARCHAR IS NULL OR lower(h.city) = lower($1))
+             AND ($2::VARCHAR IS NULL OR lower(h.country) = lower($2))
+             AND rt.capacity >= $5
+             AND inventory.total > 0
+             AND NOT EXISTS (
+                 SELECT 1 FROM inventory_days d
+                 WHERE d.room_type_id = rt.id AND d.day >= $3 AND d.day < $4
+                 AND (d.stop_sell OR d.sold + d.held >= inventory.total
+                      OR (d.day = $3 AND d.closed_to_arrival))
+             )
+             GROUP BY h.id
+             ORDER BY h.id ASC",
+        )
+        .bind(city)
+        .bind(country)
+        .bind(check_in)
+        .bind(check_out)
+        .bind(guests)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Full-text search ranked by relevance; matches any word of `query`.
+    pub async fn search(
+        &self,
+        query: &str,
+        language: &str,
+        limit: i64,
+    ) -> Result<Vec<HotelSearchHit>, sqlx::Error> {
+        sqlx::query_as::<_, HotelSearchHit>(
+            "SELECT h.*, ts_rank_cd($3, h.search_vector, q.query) AS rank,
+             ts_headline(hotel_search_config(h.language), translate(COALESCE(h.description, h.name), chr(2) || chr(3), ''), q.query, $4) AS snippet
+             FROM hotels h, hotel_search_query($2, $1) AS q(query)
+             WHERE h.search_vector @@ q.query
+             ORDER BY rank DESC, h.id ASC
+             LIMIT $5",
+        )
+        .bind(query)
+        .bind(language)
+        .bind(RANK_WEIGHTS)
+        .bind(HEADLINE_OPTIONS)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Trigram match of `query` against name, city and country, for queries
+    /// with typos that full-text search can't match.
+    pub async fn search_similar(
+        &self,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<HotelSearchHit>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // `<%` uses the trigram index but reads its threshold from this setting.
+        sqlx::query("SELECT set_config('pg_trgm.word_similarity_threshold', $1, true)")
+            .bind(SIMILARITY_THRESHOLD)
+            .execute(&mut *tx)
+            .await?;
+
+        let hits: Vec<HotelSearchHit> = sqlx::query_as::<_, HotelSearchHit>(
+            "SELECT h.*, word_similarity($1, h.name || ' ' || h.city || ' ' || h.country) AS rank,
+             ts_headline(hotel_search_config(h.language), translate(COALESCE(h.description, h.name), chr(2) || chr(3), ''), plainto_tsquery('simple', $1), $2) AS snippet
+             FROM hotels h
+             WHERE $1 <% (h.name || ' ' || h.city || ' ' || h.country)
+             ORDER BY rank DESC, h.id ASC
+             LIMIT $3",
+        )
+        .bind(query)
+        .bind(HEADLINE_OPTIONS)
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(hits)
     }
 
     pub async fn find_by_id(&self, id: i32) -> Result<Option<Hotel>, sqlx::Error> {
@@ -24,6 +188,7 @@ impl HotelRepository {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
         &self,
         name: String,
@@ -31,40 +196,107 @@ impl HotelRepository {
         address: String,
         city: String,
         country: String,
+        timezone: Option<String>,
+        check_in_time: Option<chrono::NaiveTime>,
+        language: Option<String>,
+        owner_id: i32,
     ) -> Result<Hotel, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let hotel: Hotel = sqlx::query_as::<_, Hotel>(
+            "INSERT INTO hotels (name, description, address, city, country, timezone, check_in_time, language) 
+             VALUES ($1, $2, $3, $4, $5, COALESCE($6, 'UTC'), COALESCE($7, '15:00'::TIME), COALESCE($8, 'en')) 
+             RETURNING id, name, description, address, city, country, rating, total_reviews, timezone, check_in_time, language, created_at, updated_at"
+        )
+        .bind(name)
+        .bind(description)
+        .bind(address)
+        .bind(city)
+        .bind(country)
+        .bind(timezone)
+        .bind(check_in_time)
+        .bind(language)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query("INSERT INTO hotel_staff (hotel_id, user_id, role) VALUES ($1, $2, $3)")
+            .bind(hotel.id)
+            .bind(owner_id)
+            .bind(StaffRole::Owner)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(hotel)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        id: i32,
+        name: String,
+        description: Option<String>,
+        address: String,
+        city: String,
+        country: String,
+        timezone: Option<String>,
+        check_in_time: Option<chrono::NaiveTime>,
+        language: Option<String>,
+    ) -> Result<Option<Hotel>, sqlx::Error> {
         sqlx::query_as::<_, Hotel>(
-            "INSERT INTO hotels (name, description, address, city, country) 
-             VALUES ($1, $2, $3, $4, $5) 
-             RETURNING id, name, description, address, city, country, rating, total_reviews, created_at, updated_at"
+            "UPDATE hotels SET name = $1, description = $2, address = $3, city = $4, country = $5,
+             timezone = COALESCE($6, 'UTC'), check_in_time = COALESCE($7, '15:00'::TIME), language = COALESCE($8, 'en'),
+             updated_at = CURRENT_TIMESTAMP 
+             WHERE id = $9 
+             RETURNING id, name, description, address, city, country, rating, total_reviews, timezone, check_in_time, language, created_at, updated_at"
         )
         .bind(name)
         .bind(description)
         .bind(address)
         .bind(city)
         .bind(country)
-        .fetch_one(&self.pool)
+        .bind(timezone)
+        .bind(check_in_time)
+        .bind(language)
+        .bind(id)
+        .fetch_optional(&self.pool)
         .await
     }
 
-    pub async fn update(
+    /// Applies only the given fields; `description` is the one column that
+    /// can be cleared.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn patch(
         &self,
         id: i32,
         name: Option<String>,
-        description: Option<String>,
+        description: Patch<String>,
         address: Option<String>,
         city: Option<String>,
         country: Option<String>,
+        timezone: Option<String>,
+        check_in_time: Option<chrono::NaiveTime>,
+        language: Option<String>,
     ) -> Result<Option<Hotel>, sqlx::Error> {
         sqlx::query_as::<_, Hotel>(
-            "UPDATE hotels SET name = $1, description = $2, address = $3, city = $4, country = $5, updated_at = CURRENT_TIMESTAMP 
-             WHERE id = $6 
-             RETURNING id, name, description, address, city, country, rating, total_reviews, created_at, updated_at"
+            "UPDATE hotels SET name = COALESCE($1, name),
+             description = CASE WHEN $2 THEN description ELSE $3 END,
+             address = COALESCE($4, address), city = COALESCE($5, city), country = COALESCE($6, country),
+             timezone = COALESCE($7, timezone), check_in_time = COALESCE($8, check_in_time), language = COALESCE($9, language),
+             updated_at = CURRENT_TIMESTAMP 
+             WHERE id = $10 
+             RETURNING id, name, description, address, city, country, rating, total_reviews, timezone, check_in_time, language, created_at, updated_at"
         )
         .bind(name)
-        .bind(description)
+        .bind(description.is_missing())
+        .bind(description.into_option())
         .bind(address)
         .bind(city)
         .bind(country)
+        .bind(timezone)
+        .bind(check_in_time)
+        .bind(language)
         .bind(id)
         .fetch_optional(&self.pool)
         .await
@@ -0,0 +1,118 @@
+use crate::enums::BookingError;
+use crate::models::inventory::{CalendarDay, InventoryDay};
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct InventoryRepository {
+    pool: Pool<Postgres>,
+}
+
+impl InventoryRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn calendar(
+        &self,
+        hotel_id: i32,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<CalendarDay>, sqlx::Error> {
+        sqlx::query_as::<_, CalendarDay>(
+            "SELECT rt.id AS room_type_id, rt.name AS room_type_name, g.day::DATE AS day,
+             (SELECT COUNT(*) FROM rooms r WHERE r.room_type_id = rt.id AND r.status <> 'out_of_service') AS total,
+             COALESCE(d.sold, 0) AS sold, COALESCE(d.held, 0) AS held,
+             COALESCE(d.stop_sell, FALSE) AS stop_sell, COALESCE(d.closed_to_arrival, FALSE) AS closed_to_arrival
+             FROM room_types rt
+             CROSS JOIN generate_series($2::DATE, $3::DATE, INTERVAL '1 day') AS g(day)
+             LEFT JOIN inventory_days d ON d.room_type_id = rt.id AND d.day = g.day::DATE
+             WHERE rt.hotel_id = $1
+             ORDER BY rt.id ASC, g.day ASC",
+        )
+        .bind(hotel_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn update_restrictions(
+        &self,
+        room_type_id: i32,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        stop_sell: Option<bool>,
+        closed_to_arrival: Option<bool>,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO inventory_days (room_type_id, day, stop_sell, closed_to_arrival)
+             SELECT $1, g.day::DATE, COALESCE($4, FALSE), COALESCE($5, FALSE)
+             FROM generate_series($2::DATE, $3::DATE, INTERVAL '1 day') AS g(day)
+             ON CONFLICT (room_type_id, day) DO UPDATE SET
+             stop_sell = COALESCE($4, inventory_days.stop_sell),
+             closed_to_arrival = COALESCE($5, inventory_days.closed_to_arrival),
+             updated_at = CURRENT_TIMESTAMP",
+        )
+        .bind(room_type_id)
+        .bind(from)
+        .bind(to)
+        .bind(stop_sell)
+        .bind(closed_to_arrival)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+pub async fn lock_available_days(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    room_type_id: i32,
+    check_in: chrono::NaiveDate,
+    check_out: chrono::NaiveDate,
+) -> Result<(), BookingError> {
+    sqlx::query(
+        "INSERT INTO inventory_days (room_type_id, day)
+         SELECT $1, generate_series($2::DATE, $3::DATE - 1, INTERVAL '1 day')::DATE
+         ON CONFLICT (room_type_id, day) DO NOTHING",
+    )
+    .bind(room_type_id)
+    .bind(check_in)
+    .bind(check_out)
+    .execute(&mut **tx)
+    .await?;
+
+    // Locks the stay's days in date order so overlapping bookings serialize without deadlocks.
+    let days: Vec<InventoryDay> = sqlx::query_as::<_, InventoryDay>(
+        "SELECT * FROM inventory_days
+         WHERE room_type_id = $1 AND day >= $2 AND day < $3
+         ORDER BY day ASC
+         FOR UPDATE",
+    )
+    .bind(room_type_id)
+    .bind(check_in)
+    .bind(check_out)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let rooms: i64 = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM rooms WHERE room_type_id = $1 AND status <> 'out_of_service'",
+    )
+    .bind(room_type_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    for day in &days {
+        if day.day == check_in && day.closed_to_arrival {
+            return Err(BookingError::ClosedToArrival);
+        }
+        if day.stop_sell {
+            return Err(BookingError::StopSell);
+        }
+        if i64::from(day.sold + day.held) >= rooms {
+            return Err(BookingError::Unavailable);
+        }
+    }
+
+    Ok(())
+}
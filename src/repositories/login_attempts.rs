@@ -0,0 +1,153 @@
+use redis::aio::MultiplexedConnection;
+use sqlx::{Pool, Postgres};
+
+use crate::models::lockouts::{AccountLockout, ActiveLockout};
+
+#[derive(Clone)]
+pub struct LoginAttemptRepository {
+    pool: Pool<Postgres>,
+    redis_conn: MultiplexedConnection,
+}
+
+impl LoginAttemptRepository {
+    pub fn new(pool: Pool<Postgres>, redis_conn: MultiplexedConnection) -> Self {
+        Self { pool, redis_conn }
+    }
+
+    /// Adds a failure at `now_ms` to a sliding window, trims entries older than
+    /// the window and returns how many remain.
+    pub async fn record_failure(
+        &self,
+        key: &str,
+        member: &str,
+        now_ms: i64,
+        window_ms: i64,
+    ) -> Result<i64, redis::RedisError> {
+        let mut conn: MultiplexedConnection = self.redis_conn.clone();
+
+        redis::cmd("ZADD")
+            .arg(key)
+            .arg(now_ms)
+            .arg(member)
+            .exec_async(&mut conn)
+            .await?;
+        redis::cmd("PEXPIRE")
+            .arg(key)
+            .arg(window_ms)
+            .exec_async(&mut conn)
+            .await?;
+
+        self.count_failures(key, now_ms, window_ms).await
+    }
+
+    pub async fn count_failures(
+        &self,
+        key: &str,
+        now_ms: i64,
+        window_ms: i64,
+    ) -> Result<i64, redis::RedisError> {
+        let mut conn: MultiplexedConnection = self.redis_conn.clone();
+
+        redis::cmd("ZREMRANGEBYSCORE")
+            .arg(key)
+            .arg("-inf")
+            .arg(now_ms - window_ms)
+            .exec_async(&mut conn)
+            .await?;
+
+        redis::cmd("ZCARD").arg(key).query_async(&mut conn).await
+    }
+
+    /// Timestamp in milliseconds of the oldest failure still in the window.
+    pub async fn oldest_failure(&self, key: &str) -> Result<Option<i64>, redis::RedisError> {
+        let entries: Vec<(String, f64)> = redis::cmd("ZRANGE")
+            .arg(key)
+            .arg(0)
+            .arg(0)
+            .arg("WITHSCORES")
+            .query_async(&mut self.redis_conn.clone())
+            .await?;
+
+        Ok(entries.first().map(|(_, score)| *score as i64))
+    }
+
+    pub async fn clear_failures(&self, key: &str) -> Result<(), redis::RedisError> {
+        redis::cmd("DEL")
+            .arg(key)
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn find_active_lockout(
+        &self,
+        user_id: i32,
+        now: chrono::NaiveDateTime,
+    ) -> Result<Option<AccountLockout>, sqlx::Error> {
+        sqlx::query_as::<_, AccountLockout>(
+            "SELECT * FROM account_lockouts
+             WHERE user_id = $1 AND unlocked_at IS NULL AND locked_until > $2
+             ORDER BY locked_until DESC
+             LIMIT 1",
+        )
+        .bind(user_id)
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn list_active_lockouts(
+        &self,
+        now: chrono::NaiveDateTime,
+    ) -> Result<Vec<ActiveLockout>, sqlx::Error> {
+        sqlx::query_as::<_, ActiveLockout>(
+            "SELECT l.id, l.user_id, u.email, l.locked_until, l.failed_attempts, l.ip_address, l.created_at
+             FROM account_lockouts l
+             JOIN users u ON u.id = l.user_id
+             WHERE l.unlocked_at IS NULL AND l.locked_until > $1
+             ORDER BY l.created_at DESC",
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn create_lockout(
+        &self,
+        user_id: i32,
+        locked_until: chrono::NaiveDateTime,
+        failed_attempts: i32,
+        ip_address: &str,
+    ) -> Result<AccountLockout, sqlx::Error> {
+        sqlx::query_as::<_, AccountLockout>(
+            "INSERT INTO account_lockouts (user_id, locked_until, failed_attempts, ip_address)
+             VALUES ($1, $2, $3, $4)
+             RETURNING *",
+        )
+        .bind(user_id)
+        .bind(locked_until)
+        .bind(failed_attempts)
+        .bind(ip_address)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// Lifts every active lock on the user. Returns how many were lifted.
+    pub async fn unlock(
+        &self,
+        user_id: i32,
+        unlocked_by: i32,
+        now: chrono::NaiveDateTime,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE account_lockouts SET unlocked_at = $1, unlocked_by = $2
+             WHERE user_id = $3 AND unlocked_at IS NULL AND locked_until > $1",
+        )
+        .bind(now)
+        .bind(unlocked_by)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
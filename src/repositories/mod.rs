@@ -1,3 +1,21 @@
+pub mod bookings;
+pub mod email_outbox;
+pub mod email_verifications;
 pub mod health;
+pub mod holds;
 pub mod hotels;
+pub mod inventory;
+pub mod login_attempts;
+pub mod password_resets;
+pub mod quotes;
+pub mod rate_limits;
+pub mod rate_plans;
+pub mod refresh_tokens;
+pub mod reviews;
+pub mod room_types;
+pub mod rooms;
+pub mod sessions;
+pub mod staff;
+pub mod taxes;
+pub mod two_factor;
 pub mod users;
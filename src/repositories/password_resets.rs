@@ -0,0 +1,88 @@
+use crate::models::emails::NewEmail;
+use crate::repositories::email_outbox;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct PasswordResetRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PasswordResetRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    /// Stores a reset token and queues the email that delivers it.
+    pub async fn create(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        expires_at: chrono::NaiveDateTime,
+        email: &NewEmail,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO password_reset_tokens (user_id, token_hash, expires_at)
+             VALUES ($1, $2, $3)",
+        )
+        .bind(user_id)
+        .bind(token_hash)
+        .bind(expires_at)
+        .execute(&mut *tx)
+        .await?;
+
+        email_outbox::enqueue(&mut tx, email).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Spends a live reset token on a new password hash and voids the user's
+    /// other outstanding tokens. Returns the user id, or `None` if the token is
+    /// unknown, expired or already used.
+    pub async fn consume(
+        &self,
+        token_hash: &str,
+        password_hash: &str,
+        now: chrono::NaiveDateTime,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let user_id: Option<i32> = sqlx::query_scalar::<_, i32>(
+            "UPDATE password_reset_tokens SET used_at = $1
+             WHERE token_hash = $2 AND used_at IS NULL AND expires_at > $1
+             RETURNING user_id",
+        )
+        .bind(now)
+        .bind(token_hash)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(user_id) = user_id else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            "UPDATE users SET password_hash = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+        )
+        .bind(password_hash)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "UPDATE password_reset_tokens SET used_at = $1
+             WHERE user_id = $2 AND used_at IS NULL",
+        )
+        .bind(now)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(user_id))
+    }
+}
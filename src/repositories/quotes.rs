@@ -0,0 +1,54 @@
+use crate::models::quotes::{Quote, QuoteBreakdown};
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct QuoteRepository {
+    pool: Pool<Postgres>,
+}
+
+impl QuoteRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        room_type_id: i32,
+        guests: i32,
+        breakdown: &QuoteBreakdown,
+        expires_at: chrono::NaiveDateTime,
+    ) -> Result<Quote, sqlx::Error> {
+        sqlx::query_as::<_, Quote>(
+            "INSERT INTO quotes (user_id, hotel_id, room_type_id, rate_plan_id, check_in, check_out, guests, lines,
+             room_total, fees_total, taxes_total, total_price, expires_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+             RETURNING *",
+        )
+        .bind(user_id)
+        .bind(hotel_id)
+        .bind(room_type_id)
+        .bind(breakdown.price.rate_plan_id)
+        .bind(breakdown.price.check_in)
+        .bind(breakdown.price.check_out)
+        .bind(guests)
+        .bind(Json(&breakdown.lines))
+        .bind(breakdown.room_total)
+        .bind(breakdown.fees_total)
+        .bind(breakdown.taxes_total)
+        .bind(breakdown.total_price)
+        .bind(expires_at)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<Quote>, sqlx::Error> {
+        sqlx::query_as::<_, Quote>("SELECT * FROM quotes WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+}
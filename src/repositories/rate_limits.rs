@@ -0,0 +1,66 @@
+use redis::aio::MultiplexedConnection;
+
+/// Refills the bucket for the time since it was last touched, then takes one
+/// token if there is one. Runs as a script so concurrent requests on any
+/// replica see a consistent count, and uses the Redis clock so replicas don't
+/// need synchronised clocks.
+///
+/// Returns `{allowed, remaining, ms until full, ms until next token}`.
+const TAKE_TOKEN_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local per_ms = tonumber(ARGV[2]) / 60000
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'updated_at')
+local tokens = tonumber(bucket[1]) or capacity
+local updated_at = tonumber(bucket[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - updated_at) * per_ms)
+
+local allowed = 0
+local retry_ms = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+else
+    retry_ms = math.ceil((1 - tokens) / per_ms)
+end
+
+local full_ms = math.ceil((capacity - tokens) / per_ms)
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'updated_at', now)
+redis.call('PEXPIRE', KEYS[1], full_ms + 1000)
+
+return {allowed, math.floor(tokens), full_ms, retry_ms}
+"#;
+
+#[derive(Clone)]
+pub struct RateLimitRepository {
+    redis_conn: MultiplexedConnection,
+    script: redis::Script,
+}
+
+impl RateLimitRepository {
+    pub fn new(redis_conn: MultiplexedConnection) -> Self {
+        Self {
+            redis_conn,
+            script: redis::Script::new(TAKE_TOKEN_SCRIPT),
+        }
+    }
+
+    pub async fn take_token(
+        &self,
+        key: &str,
+        capacity: i64,
+        per_minute: i64,
+    ) -> Result<(bool, i64, i64, i64), redis::RedisError> {
+        let (allowed, remaining, full_ms, retry_ms): (i64, i64, i64, i64) = self
+            .script
+            .key(key)
+            .arg(capacity)
+            .arg(per_minute)
+            .invoke_async(&mut self.redis_conn.clone())
+            .await?;
+
+        Ok((allowed == 1, remaining, full_ms, retry_ms))
+    }
+}
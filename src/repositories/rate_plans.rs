@@ -0,0 +1,168 @@
+use crate::models::rate_plans::{CancellationPolicy, RatePlan, RatePlanSeason};
+use rust_decimal::Decimal;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct RatePlanRepository {
+    pool: Pool<Postgres>,
+}
+
+impl RatePlanRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_room_type(&self, room_type_id: i32) -> Result<Vec<RatePlan>, sqlx::Error> {
+        sqlx::query_as::<_, RatePlan>(
+            "SELECT * FROM rate_plans WHERE room_type_id = $1 ORDER BY id ASC",
+        )
+        .bind(room_type_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn list_by_room_types(
+        &self,
+        room_type_ids: &[i32],
+    ) -> Result<Vec<RatePlan>, sqlx::Error> {
+        sqlx::query_as::<_, RatePlan>(
+            "SELECT * FROM rate_plans WHERE room_type_id = ANY($1) ORDER BY id ASC",
+        )
+        .bind(room_type_ids)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        room_type_id: i32,
+        id: i32,
+    ) -> Result<Option<RatePlan>, sqlx::Error> {
+        sqlx::query_as::<_, RatePlan>(
+            "SELECT * FROM rate_plans WHERE room_type_id = $1 AND id = $2",
+        )
+        .bind(room_type_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn create(
+        &self,
+        room_type_id: i32,
+        name: String,
+        description: Option<String>,
+        base_price: Decimal,
+        weekend_price: Option<Decimal>,
+        cancellation_policy: CancellationPolicy,
+    ) -> Result<RatePlan, sqlx::Error> {
+        sqlx::query_as::<_, RatePlan>(
+            "INSERT INTO rate_plans (room_type_id, name, description, base_price, weekend_price,
+             free_cancellation_hours, cancellation_penalty, penalty_percent)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+             RETURNING *",
+        )
+        .bind(room_type_id)
+        .bind(name)
+        .bind(description)
+        .bind(base_price)
+        .bind(weekend_price)
+        .bind(cancellation_policy.free_cancellation_hours)
+        .bind(cancellation_policy.penalty)
+        .bind(cancellation_policy.penalty_percent)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        room_type_id: i32,
+        id: i32,
+        name: Option<String>,
+        description: Option<String>,
+        base_price: Option<Decimal>,
+        weekend_price: Option<Decimal>,
+        cancellation_policy: Option<CancellationPolicy>,
+    ) -> Result<Option<RatePlan>, sqlx::Error> {
+        sqlx::query_as::<_, RatePlan>(
+            "UPDATE rate_plans SET name = COALESCE($1, name), description = COALESCE($2, description),
+             base_price = COALESCE($3, base_price), weekend_price = COALESCE($4, weekend_price),
+             free_cancellation_hours = COALESCE($5, free_cancellation_hours),
+             cancellation_penalty = COALESCE($6, cancellation_penalty),
+             penalty_percent = CASE WHEN $6 IS NULL THEN penalty_percent ELSE $7 END,
+             updated_at = CURRENT_TIMESTAMP
+             WHERE room_type_id = $8 AND id = $9
+             RETURNING *",
+        )
+        .bind(name)
+        .bind(description)
+        .bind(base_price)
+        .bind(weekend_price)
+        .bind(cancellation_policy.map(|policy| policy.free_cancellation_hours))
+        .bind(cancellation_policy.map(|policy| policy.penalty))
+        .bind(cancellation_policy.and_then(|policy| policy.penalty_percent))
+        .bind(room_type_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn delete(&self, room_type_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM rate_plans WHERE room_type_id = $1 AND id = $2")
+            .bind(room_type_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn list_seasons(
+        &self,
+        rate_plan_ids: &[i32],
+    ) -> Result<Vec<RatePlanSeason>, sqlx::Error> {
+        sqlx::query_as::<_, RatePlanSeason>(
+            "SELECT * FROM rate_plan_seasons WHERE rate_plan_id = ANY($1)
+             ORDER BY start_date ASC, id ASC",
+        )
+        .bind(rate_plan_ids)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn create_season(
+        &self,
+        rate_plan_id: i32,
+        name: String,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        price: Decimal,
+        weekend_price: Option<Decimal>,
+    ) -> Result<RatePlanSeason, sqlx::Error> {
+        sqlx::query_as::<_, RatePlanSeason>(
+            "INSERT INTO rate_plan_seasons (rate_plan_id, name, start_date, end_date, price, weekend_price)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             RETURNING *",
+        )
+        .bind(rate_plan_id)
+        .bind(name)
+        .bind(start_date)
+        .bind(end_date)
+        .bind(price)
+        .bind(weekend_price)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn delete_season(&self, rate_plan_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        let result =
+            sqlx::query("DELETE FROM rate_plan_seasons WHERE rate_plan_id = $1 AND id = $2")
+                .bind(rate_plan_id)
+                .bind(id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected())
+    }
+}
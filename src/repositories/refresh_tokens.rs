@@ -0,0 +1,131 @@
+use crate::enums::RefreshTokenError;
+use crate::models::users::RefreshToken;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct RefreshTokenRepository {
+    pool: Pool<Postgres>,
+}
+
+impl RefreshTokenRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    /// Stores the first token of a new family.
+    pub async fn create(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        expires_at: chrono::NaiveDateTime,
+    ) -> Result<RefreshToken, sqlx::Error> {
+        sqlx::query_as::<_, RefreshToken>(
+            "INSERT INTO refresh_tokens (user_id, family_id, token_hash, expires_at)
+             VALUES ($1, gen_random_uuid(), $2, $3)
+             RETURNING *",
+        )
+        .bind(user_id)
+        .bind(token_hash)
+        .bind(expires_at)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// Exchanges a live refresh token for a new one in the same family. A token
+    /// that was already rotated or revoked revokes its whole family.
+    pub async fn rotate(
+        &self,
+        token_hash: &str,
+        new_token_hash: &str,
+        now: chrono::NaiveDateTime,
+        expires_at: chrono::NaiveDateTime,
+    ) -> Result<RefreshToken, RefreshTokenError> {
+        let mut tx = self.pool.begin().await?;
+
+        let current: RefreshToken = sqlx::query_as::<_, RefreshToken>(
+            "SELECT * FROM refresh_tokens WHERE token_hash = $1 FOR UPDATE",
+        )
+        .bind(token_hash)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(RefreshTokenError::Invalid)?;
+
+        if current.rotated_at.is_some() || current.revoked_at.is_some() {
+            sqlx::query(
+                "UPDATE refresh_tokens SET revoked_at = $1
+                 WHERE family_id = $2 AND revoked_at IS NULL",
+            )
+            .bind(now)
+            .bind(current.family_id)
+            .execute(&mut *tx)
+            .await?;
+            tx.commit().await?;
+
+            return Err(RefreshTokenError::Reused);
+        }
+
+        if current.expires_at <= now {
+            return Err(RefreshTokenError::Invalid);
+        }
+
+        sqlx::query("UPDATE refresh_tokens SET rotated_at = $1 WHERE id = $2")
+            .bind(now)
+            .bind(current.id)
+            .execute(&mut *tx)
+            .await?;
+
+        let next: RefreshToken = sqlx::query_as::<_, RefreshToken>(
+            "INSERT INTO refresh_tokens (user_id, family_id, token_hash, expires_at)
+             VALUES ($1, $2, $3, $4)
+             RETURNING *",
+        )
+        .bind(current.user_id)
+        .bind(current.family_id)
+        .bind(new_token_hash)
+        .bind(expires_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(next)
+    }
+
+    pub async fn revoke_family(
+        &self,
+        token_hash: &str,
+        user_id: i32,
+        now: chrono::NaiveDateTime,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = $1
+             WHERE revoked_at IS NULL AND family_id = (
+                 SELECT family_id FROM refresh_tokens WHERE token_hash = $2 AND user_id = $3
+             )",
+        )
+        .bind(now)
+        .bind(token_hash)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn revoke_all(
+        &self,
+        user_id: i32,
+        now: chrono::NaiveDateTime,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = $1
+             WHERE user_id = $2 AND revoked_at IS NULL",
+        )
+        .bind(now)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,157 @@
+use crate::models::reviews::Review;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct ReviewRepository {
+    pool: Pool<Postgres>,
+}
+
+impl ReviewRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_hotel(
+        &self,
+        hotel_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<Review>, i64), sqlx::Error> {
+        let reviews: Vec<Review> = sqlx::query_as::<_, Review>(
+            "SELECT * FROM reviews WHERE hotel_id = $1
+             ORDER BY created_at DESC, id DESC
+             LIMIT $2 OFFSET $3",
+        )
+        .bind(hotel_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: i64 =
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM reviews WHERE hotel_id = $1")
+                .bind(hotel_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok((reviews, total))
+    }
+
+    pub async fn find_by_id(&self, hotel_id: i32, id: i32) -> Result<Option<Review>, sqlx::Error> {
+        sqlx::query_as::<_, Review>("SELECT * FROM reviews WHERE hotel_id = $1 AND id = $2")
+            .bind(hotel_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        booking_id: i32,
+        hotel_id: i32,
+        user_id: i32,
+        score: i32,
+        comment: String,
+    ) -> Result<Review, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        lock_hotel(&mut tx, hotel_id).await?;
+
+        let review: Review = sqlx::query_as::<_, Review>(
+            "INSERT INTO reviews (booking_id, hotel_id, user_id, score, comment)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *",
+        )
+        .bind(booking_id)
+        .bind(hotel_id)
+        .bind(user_id)
+        .bind(score)
+        .bind(comment)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        refresh_rating(&mut tx, hotel_id).await?;
+        tx.commit().await?;
+
+        Ok(review)
+    }
+
+    pub async fn update(
+        &self,
+        hotel_id: i32,
+        id: i32,
+        score: Option<i32>,
+        comment: Option<String>,
+    ) -> Result<Option<Review>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        lock_hotel(&mut tx, hotel_id).await?;
+
+        let review: Option<Review> = sqlx::query_as::<_, Review>(
+            "UPDATE reviews SET score = COALESCE($1, score), comment = COALESCE($2, comment),
+             updated_at = CURRENT_TIMESTAMP
+             WHERE hotel_id = $3 AND id = $4
+             RETURNING *",
+        )
+        .bind(score)
+        .bind(comment)
+        .bind(hotel_id)
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        refresh_rating(&mut tx, hotel_id).await?;
+        tx.commit().await?;
+
+        Ok(review)
+    }
+
+    pub async fn delete(&self, hotel_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        lock_hotel(&mut tx, hotel_id).await?;
+
+        let result = sqlx::query("DELETE FROM reviews WHERE hotel_id = $1 AND id = $2")
+            .bind(hotel_id)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        refresh_rating(&mut tx, hotel_id).await?;
+        tx.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+// Serializes concurrent review writes per hotel so the aggregate always sees every review.
+async fn lock_hotel(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    hotel_id: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT id FROM hotels WHERE id = $1 FOR UPDATE")
+        .bind(hotel_id)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn refresh_rating(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    hotel_id: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE hotels SET rating = stats.rating, total_reviews = stats.total
+         FROM (
+             SELECT ROUND(AVG(score), 2) AS rating, COUNT(*)::INT AS total
+             FROM reviews WHERE hotel_id = $1
+         ) stats
+         WHERE hotels.id = $1",
+    )
+    .bind(hotel_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::models::room_types::RoomType;
+use rust_decimal::Decimal;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct RoomTypeRepository {
+    pool: Pool<Postgres>,
+}
+
+impl RoomTypeRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_hotel(&self, hotel_id: i32) -> Result<Vec<RoomType>, sqlx::Error> {
+        sqlx::query_as::<_, RoomType>(
+            "SELECT * FROM room_types WHERE hotel_id = $1 ORDER BY id ASC",
+        )
+        .bind(hotel_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn list_by_ids(&self, ids: &[i32]) -> Result<Vec<RoomType>, sqlx::Error> {
+        sqlx::query_as::<_, RoomType>("SELECT * FROM room_types WHERE id = ANY($1) ORDER BY id ASC")
+            .bind(ids)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        hotel_id: i32,
+        id: i32,
+    ) -> Result<Option<RoomType>, sqlx::Error> {
+        sqlx::query_as::<_, RoomType>("SELECT * FROM room_types WHERE hotel_id = $1 AND id = $2")
+            .bind(hotel_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        hotel_id: i32,
+        name: String,
+        description: Option<String>,
+        capacity: i32,
+        bed_configuration: String,
+        base_price: Decimal,
+    ) -> Result<RoomType, sqlx::Error> {
+        sqlx::query_as::<_, RoomType>(
+            "INSERT INTO room_types (hotel_id, name, description, capacity, bed_configuration, base_price)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             RETURNING id, hotel_id, name, description, capacity, bed_configuration, base_price, created_at, updated_at"
+        )
+        .bind(hotel_id)
+        .bind(name)
+        .bind(description)
+        .bind(capacity)
+        .bind(bed_configuration)
+        .bind(base_price)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        hotel_id: i32,
+        id: i32,
+        name: Option<String>,
+        description: Option<String>,
+        capacity: Option<i32>,
+        bed_configuration: Option<String>,
+        base_price: Option<Decimal>,
+    ) -> Result<Option<RoomType>, sqlx::Error> {
+        sqlx::query_as::<_, RoomType>(
+            "UPDATE room_types SET name = COALESCE($1, name), description = COALESCE($2, description),
+             capacity = COALESCE($3, capacity), bed_configuration = COALESCE($4, bed_configuration),
+             base_price = COALESCE($5, base_price), updated_at = CURRENT_TIMESTAMP
+             WHERE hotel_id = $6 AND id = $7
+             RETURNING id, hotel_id, name, description, capacity, bed_configuration, base_price, created_at, updated_at"
+        )
+        .bind(name)
+        .bind(description)
+        .bind(capacity)
+        .bind(bed_configuration)
+        .bind(base_price)
+        .bind(hotel_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn delete(&self, hotel_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM room_types WHERE hotel_id = $1 AND id = $2")
+            .bind(hotel_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
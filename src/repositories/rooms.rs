@@ -0,0 +1,86 @@
+use crate::enums::RoomStatus;
+use crate::models::rooms::Room;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct RoomRepository {
+    pool: Pool<Postgres>,
+}
+
+impl RoomRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_hotel(&self, hotel_id: i32) -> Result<Vec<Room>, sqlx::Error> {
+        sqlx::query_as::<_, Room>("SELECT * FROM rooms WHERE hotel_id = $1 ORDER BY id ASC")
+            .bind(hotel_id)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn find_by_id(&self, hotel_id: i32, id: i32) -> Result<Option<Room>, sqlx::Error> {
+        sqlx::query_as::<_, Room>("SELECT * FROM rooms WHERE hotel_id = $1 AND id = $2")
+            .bind(hotel_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        hotel_id: i32,
+        room_type_id: i32,
+        room_number: String,
+        floor: Option<i32>,
+        status: RoomStatus,
+    ) -> Result<Room, sqlx::Error> {
+        sqlx::query_as::<_, Room>(
+            "INSERT INTO rooms (hotel_id, room_type_id, room_number, floor, status)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING id, hotel_id, room_type_id, room_number, floor, status, created_at, updated_at",
+        )
+        .bind(hotel_id)
+        .bind(room_type_id)
+        .bind(room_number)
+        .bind(floor)
+        .bind(status)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn update(
+        &self,
+        hotel_id: i32,
+        id: i32,
+        room_type_id: Option<i32>,
+        room_number: Option<String>,
+        floor: Option<i32>,
+        status: Option<RoomStatus>,
+    ) -> Result<Option<Room>, sqlx::Error> {
+        sqlx::query_as::<_, Room>(
+            "UPDATE rooms SET room_type_id = COALESCE($1, room_type_id), room_number = COALESCE($2, room_number),
+             floor = COALESCE($3, floor), status = COALESCE($4, status), updated_at = CURRENT_TIMESTAMP
+             WHERE hotel_id = $5 AND id = $6
+             RETURNING id, hotel_id, room_type_id, room_number, floor, status, created_at, updated_at",
+        )
+        .bind(room_type_id)
+        .bind(room_number)
+        .bind(floor)
+        .bind(status)
+        .bind(hotel_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn delete(&self, hotel_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM rooms WHERE hotel_id = $1 AND id = $2")
+            .bind(hotel_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,84 @@
+use redis::aio::MultiplexedConnection;
+use sqlx::{Pool, Postgres};
+
+/// How long a user's token version stays cached before it is reloaded from Postgres.
+const TOKEN_VERSION_CACHE_SECONDS: i64 = 3600;
+
+#[derive(Clone)]
+pub struct SessionRepository {
+    pool: Pool<Postgres>,
+    redis_conn: MultiplexedConnection,
+}
+
+impl SessionRepository {
+    pub fn new(pool: Pool<Postgres>, redis_conn: MultiplexedConnection) -> Self {
+        Self { pool, redis_conn }
+    }
+
+    pub async fn deny(&self, jti: &str, ttl_seconds: i64) -> Result<(), redis::RedisError> {
+        redis::cmd("SET")
+            .arg(denylist_key(jti))
+            .arg(1)
+            .arg("EX")
+            .arg(ttl_seconds)
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn is_denied(&self, jti: &str) -> Result<bool, redis::RedisError> {
+        redis::cmd("EXISTS")
+            .arg(denylist_key(jti))
+            .query_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn cached_token_version(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<i32>, redis::RedisError> {
+        redis::cmd("GET")
+            .arg(token_version_key(user_id))
+            .query_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn cache_token_version(
+        &self,
+        user_id: i32,
+        version: i32,
+    ) -> Result<(), redis::RedisError> {
+        redis::cmd("SET")
+            .arg(token_version_key(user_id))
+            .arg(version)
+            .arg("EX")
+            .arg(TOKEN_VERSION_CACHE_SECONDS)
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn token_version(&self, user_id: i32) -> Result<Option<i32>, sqlx::Error> {
+        sqlx::query_scalar::<_, i32>("SELECT token_version FROM users WHERE id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn bump_token_version(&self, user_id: i32) -> Result<i32, sqlx::Error> {
+        sqlx::query_scalar::<_, i32>(
+            "UPDATE users SET token_version = token_version + 1, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $1
+             RETURNING token_version",
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+}
+
+fn denylist_key(jti: &str) -> String {
+    format!("revoked_token:{}", jti)
+}
+
+fn token_version_key(user_id: i32) -> String {
+    format!("token_version:{}", user_id)
+}
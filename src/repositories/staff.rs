@@ -0,0 +1,123 @@
+use crate::enums::{StaffError, StaffRole};
+use crate::models::staff::{HotelStaff, StaffMember};
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct StaffRepository {
+    pool: Pool<Postgres>,
+}
+
+impl StaffRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn find(
+        &self,
+        hotel_id: i32,
+        user_id: i32,
+    ) -> Result<Option<HotelStaff>, sqlx::Error> {
+        sqlx::query_as::<_, HotelStaff>(
+            "SELECT * FROM hotel_staff WHERE hotel_id = $1 AND user_id = $2",
+        )
+        .bind(hotel_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn list_by_hotel(&self, hotel_id: i32) -> Result<Vec<StaffMember>, sqlx::Error> {
+        sqlx::query_as::<_, StaffMember>(
+            "SELECT s.user_id, u.email, u.first_name, u.last_name, s.role, s.created_at
+             FROM hotel_staff s
+             JOIN users u ON u.id = s.user_id
+             WHERE s.hotel_id = $1
+             ORDER BY s.created_at ASC, s.user_id ASC",
+        )
+        .bind(hotel_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn find_member(
+        &self,
+        hotel_id: i32,
+        user_id: i32,
+    ) -> Result<Option<StaffMember>, sqlx::Error> {
+        sqlx::query_as::<_, StaffMember>(
+            "SELECT s.user_id, u.email, u.first_name, u.last_name, s.role, s.created_at
+             FROM hotel_staff s
+             JOIN users u ON u.id = s.user_id
+             WHERE s.hotel_id = $1 AND s.user_id = $2",
+        )
+        .bind(hotel_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn add(
+        &self,
+        hotel_id: i32,
+        user_id: i32,
+        role: StaffRole,
+    ) -> Result<HotelStaff, sqlx::Error> {
+        sqlx::query_as::<_, HotelStaff>(
+            "INSERT INTO hotel_staff (hotel_id, user_id, role)
+             VALUES ($1, $2, $3)
+             RETURNING *",
+        )
+        .bind(hotel_id)
+        .bind(user_id)
+        .bind(role)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn remove(
+        &self,
+        hotel_id: i32,
+        user_id: i32,
+        actor_role: StaffRole,
+    ) -> Result<(), StaffError> {
+        let mut tx = self.pool.begin().await?;
+
+        // Locking the whole staff list keeps concurrent removals from dropping the last owner.
+        let staff: Vec<HotelStaff> = sqlx::query_as::<_, HotelStaff>(
+            "SELECT * FROM hotel_staff WHERE hotel_id = $1 FOR UPDATE",
+        )
+        .bind(hotel_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let member: &HotelStaff = staff
+            .iter()
+            .find(|member| member.user_id == user_id)
+            .ok_or(StaffError::NotFound)?;
+
+        if member.role == StaffRole::Owner {
+            if actor_role != StaffRole::Owner {
+                return Err(StaffError::OwnersOnly);
+            }
+
+            let owners: usize = staff
+                .iter()
+                .filter(|member| member.role == StaffRole::Owner)
+                .count();
+
+            if owners == 1 {
+                return Err(StaffError::LastOwner);
+            }
+        }
+
+        sqlx::query("DELETE FROM hotel_staff WHERE hotel_id = $1 AND user_id = $2")
+            .bind(hotel_id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
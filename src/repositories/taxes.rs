@@ -0,0 +1,102 @@
+use crate::models::taxes::{HotelFee, HotelTaxSettings, TaxSettings};
+use rust_decimal::Decimal;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct TaxRepository {
+    pool: Pool<Postgres>,
+}
+
+impl TaxRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn find_settings(&self, hotel_id: i32) -> Result<Option<TaxSettings>, sqlx::Error> {
+        sqlx::query_as::<_, TaxSettings>(
+            "SELECT city_tax_per_person_night, vat_percent FROM hotels WHERE id = $1",
+        )
+        .bind(hotel_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn list_settings(
+        &self,
+        hotel_ids: &[i32],
+    ) -> Result<Vec<HotelTaxSettings>, sqlx::Error> {
+        sqlx::query_as::<_, HotelTaxSettings>(
+            "SELECT id AS hotel_id, city_tax_per_person_night, vat_percent
+             FROM hotels WHERE id = ANY($1)",
+        )
+        .bind(hotel_ids)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn update_settings(
+        &self,
+        hotel_id: i32,
+        city_tax_per_person_night: Option<Decimal>,
+        vat_percent: Option<Decimal>,
+    ) -> Result<Option<TaxSettings>, sqlx::Error> {
+        sqlx::query_as::<_, TaxSettings>(
+            "UPDATE hotels SET city_tax_per_person_night = COALESCE($1, city_tax_per_person_night),
+             vat_percent = COALESCE($2, vat_percent), updated_at = CURRENT_TIMESTAMP
+             WHERE id = $3
+             RETURNING city_tax_per_person_night, vat_percent",
+        )
+        .bind(city_tax_per_person_night)
+        .bind(vat_percent)
+        .bind(hotel_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn list_fees(&self, hotel_id: i32) -> Result<Vec<HotelFee>, sqlx::Error> {
+        sqlx::query_as::<_, HotelFee>(
+            "SELECT * FROM hotel_fees WHERE hotel_id = $1 ORDER BY id ASC",
+        )
+        .bind(hotel_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn list_fees_for_hotels(
+        &self,
+        hotel_ids: &[i32],
+    ) -> Result<Vec<HotelFee>, sqlx::Error> {
+        sqlx::query_as::<_, HotelFee>(
+            "SELECT * FROM hotel_fees WHERE hotel_id = ANY($1) ORDER BY id ASC",
+        )
+        .bind(hotel_ids)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn create_fee(
+        &self,
+        hotel_id: i32,
+        name: String,
+        amount: Decimal,
+    ) -> Result<HotelFee, sqlx::Error> {
+        sqlx::query_as::<_, HotelFee>(
+            "INSERT INTO hotel_fees (hotel_id, name, amount) VALUES ($1, $2, $3) RETURNING *",
+        )
+        .bind(hotel_id)
+        .bind(name)
+        .bind(amount)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn delete_fee(&self, hotel_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM hotel_fees WHERE hotel_id = $1 AND id = $2")
+            .bind(hotel_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
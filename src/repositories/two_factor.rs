@@ -0,0 +1,198 @@
+use redis::aio::MultiplexedConnection;
+use sqlx::{Pool, Postgres};
+
+#[derive(Clone)]
+pub struct TwoFactorRepository {
+    pool: Pool<Postgres>,
+    redis_conn: MultiplexedConnection,
+}
+
+impl TwoFactorRepository {
+    pub fn new(pool: Pool<Postgres>, redis_conn: MultiplexedConnection) -> Self {
+        Self { pool, redis_conn }
+    }
+
+    /// Replaces the pending secret of a user who has not enabled 2FA yet.
+    pub async fn set_pending_secret(&self, user_id: i32, secret: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE users SET totp_secret = $1, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $2 AND totp_enabled_at IS NULL",
+        )
+        .bind(secret)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Turns on 2FA and replaces the user's recovery codes. Returns `false` if
+    /// it was already on.
+    pub async fn enable(
+        &self,
+        user_id: i32,
+        code_hashes: &[String],
+        now: chrono::NaiveDateTime,
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let enabled: u64 = sqlx::query(
+            "UPDATE users SET totp_enabled_at = $1, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $2 AND totp_enabled_at IS NULL AND totp_secret IS NOT NULL",
+        )
+        .bind(now)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if enabled == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query("DELETE FROM recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            "INSERT INTO recovery_codes (user_id, code_hash)
+             SELECT $1, code_hash FROM UNNEST($2::VARCHAR[]) AS c(code_hash)",
+        )
+        .bind(user_id)
+        .bind(code_hashes)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Spends an unused recovery code. Returns `false` if there was none.
+    pub async fn use_recovery_code(
+        &self,
+        user_id: i32,
+        code_hash: &str,
+        now: chrono::NaiveDateTime,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE recovery_codes SET used_at = $1
+             WHERE user_id = $2 AND code_hash = $3 AND used_at IS NULL",
+        )
+        .bind(now)
+        .bind(user_id)
+        .bind(code_hash)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Records `step` as the user's last accepted TOTP step. Returns `false`
+    /// if a code from this step or a later one was already used.
+    pub async fn use_totp_step(&self, user_id: i32, step: i64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE users SET totp_last_step = $1
+             WHERE id = $2 AND (totp_last_step IS NULL OR totp_last_step < $1)",
+        )
+        .bind(step)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn create_challenge(
+        &self,
+        token_hash: &str,
+        user_id: i32,
+        ttl_seconds: i64,
+    ) -> Result<(), redis::RedisError> {
+        redis::cmd("SET")
+            .arg(challenge_key(token_hash))
+            .arg(user_id)
+            .arg("EX")
+            .arg(ttl_seconds)
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn challenge_user(&self, token_hash: &str) -> Result<Option<i32>, redis::RedisError> {
+        redis::cmd("GET")
+            .arg(challenge_key(token_hash))
+            .query_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    /// Counts a wrong code against the challenge and returns the total so far.
+    pub async fn record_failed_attempt(
+        &self,
+        token_hash: &str,
+        ttl_seconds: i64,
+    ) -> Result<i64, redis::RedisError> {
+        let mut conn: MultiplexedConnection = self.redis_conn.clone();
+        let attempts: i64 = redis::cmd("INCR")
+            .arg(attempts_key(token_hash))
+            .query_async(&mut conn)
+            .await?;
+
+        redis::cmd("EXPIRE")
+            .arg(attempts_key(token_hash))
+            .arg(ttl_seconds)
+            .exec_async(&mut conn)
+            .await?;
+
+        Ok(attempts)
+    }
+
+    /// Takes the challenge so no other request can answer it, returning the
+    /// milliseconds it had left, or `None` if another request already took it.
+    pub async fn claim_challenge(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<i64>, redis::RedisError> {
+        let (ttl_ms, deleted): (i64, i64) = redis::pipe()
+            .atomic()
+            .cmd("PTTL")
+            .arg(challenge_key(token_hash))
+            .cmd("DEL")
+            .arg(challenge_key(token_hash))
+            .query_async(&mut self.redis_conn.clone())
+            .await?;
+
+        Ok((deleted > 0).then_some(ttl_ms))
+    }
+
+    /// Puts a claimed challenge back for another try with the time it had left.
+    pub async fn restore_challenge(
+        &self,
+        token_hash: &str,
+        user_id: i32,
+        ttl_ms: i64,
+    ) -> Result<(), redis::RedisError> {
+        redis::cmd("SET")
+            .arg(challenge_key(token_hash))
+            .arg(user_id)
+            .arg("PX")
+            .arg(ttl_ms.max(1))
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+
+    pub async fn clear_attempts(&self, token_hash: &str) -> Result<(), redis::RedisError> {
+        redis::cmd("DEL")
+            .arg(attempts_key(token_hash))
+            .exec_async(&mut self.redis_conn.clone())
+            .await
+    }
+}
+
+fn challenge_key(token_hash: &str) -> String {
+    format!("2fa_challenge:{}", token_hash)
+}
+
+fn attempts_key(token_hash: &str) -> String {
+    format!("2fa_challenge_attempts:{}", token_hash)
+}
@@ -1,4 +1,7 @@
+use crate::enums::UserRole;
+use crate::models::emails::NewEmailVerification;
 use crate::models::users::User;
+use crate::repositories::email_verifications;
 use sqlx::{Pool, Postgres};
 
 #[derive(Clone)]
@@ -11,29 +14,42 @@ impl UserRepository {
         Self { pool }
     }
 
+    /// Inserts the user together with their first email verification token,
+    /// so a user is never created without one.
     pub async fn create(
         &self,
         email: String,
         password_hash: String,
         first_name: String,
         last_name: String,
+        role: UserRole,
+        verification: &NewEmailVerification,
     ) -> Result<User, sqlx::Error> {
-        sqlx::query_as::<_, User>(
-            "INSERT INTO users (email, password_hash, first_name, last_name) 
-             VALUES ($1, $2, $3, $4) 
-             RETURNING id, email, password_hash, first_name, last_name, created_at, updated_at",
+        let mut tx = self.pool.begin().await?;
+
+        let user: User = sqlx::query_as::<_, User>(
+            "INSERT INTO users (email, password_hash, first_name, last_name, role) 
+             VALUES ($1, $2, $3, $4, $5) 
+             RETURNING id, email, password_hash, first_name, last_name, role, token_version, email_verified_at, totp_secret, totp_enabled_at, created_at, updated_at",
         )
         .bind(email)
         .bind(password_hash)
         .bind(first_name)
         .bind(last_name)
-        .fetch_one(&self.pool)
-        .await
+        .bind(role)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        email_verifications::insert(&mut tx, user.id, verification).await?;
+
+        tx.commit().await?;
+
+        Ok(user)
     }
 
     pub async fn get_by_email(&self, email: &str) -> Result<Option<User>, sqlx::Error> {
         sqlx::query_as::<_, User>(
-            "SELECT id, email, password_hash, first_name, last_name, created_at, updated_at FROM users WHERE email = $1"
+            "SELECT id, email, password_hash, first_name, last_name, role, token_version, email_verified_at, totp_secret, totp_enabled_at, created_at, updated_at FROM users WHERE email = $1"
         )
         .bind(email)
         .fetch_optional(&self.pool)
@@ -42,7 +58,7 @@ impl UserRepository {
 
     pub async fn get_by_id(&self, id: i32) -> Result<Option<User>, sqlx::Error> {
         sqlx::query_as::<_, User>(
-            "SELECT id, email, password_hash, first_name, last_name, created_at, updated_at FROM users WHERE id = $1"
+            "SELECT id, email, password_hash, first_name, last_name, role, token_version, email_verified_at, totp_secret, totp_enabled_at, created_at, updated_at FROM users WHERE id = $1"
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -50,15 +66,34 @@ impl UserRepository {
     }
 
     pub async fn update(
+        &self,
+        id: i32,
+        first_name: String,
+        last_name: String,
+    ) -> Result<Option<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "UPDATE users SET first_name = $1, last_name = $2, updated_at = CURRENT_TIMESTAMP 
+             WHERE id = $3 
+             RETURNING id, email, password_hash, first_name, last_name, role, token_version, email_verified_at, totp_secret, totp_enabled_at, created_at, updated_at",
+        )
+        .bind(first_name)
+        .bind(last_name)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    /// Like `update`, but `None` leaves the column unchanged.
+    pub async fn patch(
         &self,
         id: i32,
         first_name: Option<String>,
         last_name: Option<String>,
     ) -> Result<Option<User>, sqlx::Error> {
         sqlx::query_as::<_, User>(
-            "UPDATE users SET first_name = $1, last_name = $2, updated_at = CURRENT_TIMESTAMP 
+            "UPDATE users SET first_name = COALESCE($1, first_name), last_name = COALESCE($2, last_name), updated_at = CURRENT_TIMESTAMP 
              WHERE id = $3 
-             RETURNING id, email, password_hash, first_name, last_name, created_at, updated_at",
+             RETURNING id, email, password_hash, first_name, last_name, role, token_version, email_verified_at, totp_secret, totp_enabled_at, created_at, updated_at",
         )
         .bind(first_name)
         .bind(last_name)
@@ -66,4 +101,28 @@ impl UserRepository {
         .fetch_optional(&self.pool)
         .await
     }
+
+    pub async fn update_role(&self, id: i32, role: UserRole) -> Result<Option<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            "UPDATE users SET role = $1, updated_at = CURRENT_TIMESTAMP
+             WHERE id = $2
+             RETURNING id, email, password_hash, first_name, last_name, role, token_version, email_verified_at, totp_secret, totp_enabled_at, created_at, updated_at",
+        )
+        .bind(role)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn update_password(&self, id: i32, password_hash: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE users SET password_hash = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2",
+        )
+        .bind(password_hash)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
 }
@@ -1,6 +1,6 @@
-use std::{env, path};
+use std::{env, path, sync::Arc};
 
-use crate::settings;
+use crate::{enums, mailer, settings};
 
 pub async fn init_redis(
     settings: &settings::Settings,
@@ -30,3 +30,24 @@ pub async fn init_postgres(
 
     Ok(pool)
 }
+
+pub fn init_mailer(
+    settings: &settings::Settings,
+) -> Result<Arc<dyn mailer::Mailer>, enums::MailerError> {
+    match settings.mail_transport.as_str() {
+        "smtp" => Ok(Arc::new(mailer::SmtpMailer::new(
+            &settings.smtp_host,
+            settings.smtp_port,
+            settings.smtp_username.clone(),
+            settings.smtp_password.clone(),
+            settings.smtp_starttls,
+            &settings.mail_from,
+        )?)),
+        "file" => Ok(Arc::new(mailer::FileMailer::new(&settings.mail_file_dir))),
+        "log" => Ok(Arc::new(mailer::LogMailer)),
+        other => Err(enums::MailerError::Transport(format!(
+            "unknown mail transport '{}'",
+            other
+        ))),
+    }
+}
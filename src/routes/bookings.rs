@@ -0,0 +1,328 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{enums, errors::AppError, models};
+
+impl From<enums::BookingError> for AppError {
+    fn from(e: enums::BookingError) -> Self {
+        match e {
+            enums::BookingError::NotFound => AppError::NotFound("Booking not found".to_string()),
+            enums::BookingError::RoomTypeNotFound => {
+                AppError::NotFound("Room type not found".to_string())
+            }
+            enums::BookingError::RatePlanNotFound => {
+                AppError::NotFound("Rate plan not found".to_string())
+            }
+            enums::BookingError::QuoteNotFound => AppError::NotFound("Quote not found".to_string()),
+            enums::BookingError::QuoteExpired => {
+                AppError::Gone("Quote has expired or was already used".to_string())
+            }
+            enums::BookingError::QuoteMismatch => {
+                AppError::BadRequest("Quote does not match the requested stay".to_string())
+            }
+            enums::BookingError::HoldNotFound => AppError::NotFound("Hold not found".to_string()),
+            enums::BookingError::HoldExpired => {
+                AppError::Gone("Hold has expired or was already used".to_string())
+            }
+            enums::BookingError::HoldMismatch => {
+                AppError::BadRequest("Hold does not match the requested stay".to_string())
+            }
+            enums::BookingError::InvalidDates => AppError::BadRequest(
+                "Check-out must be after check-in and check-in cannot be in the past".to_string(),
+            ),
+            enums::BookingError::TooManyGuests => {
+                AppError::BadRequest("Guest count exceeds room type capacity".to_string())
+            }
+            enums::BookingError::Unavailable => {
+                AppError::Conflict("No rooms available for the selected dates".to_string())
+            }
+            enums::BookingError::StopSell => AppError::Conflict(
+                "Sales are stopped for at least one night of the stay".to_string(),
+            ),
+            enums::BookingError::ClosedToArrival => {
+                AppError::Conflict("Arrivals are closed on the check-in date".to_string())
+            }
+            enums::BookingError::InvalidTransition { from, to } => AppError::Conflict(format!(
+                "Cannot change booking status from {} to {}",
+                from, to
+            )),
+            enums::BookingError::BeforeArrival(to) => AppError::Conflict(format!(
+                "Cannot change booking status to {} before the arrival date",
+                to
+            )),
+            enums::BookingError::EmailNotVerified => {
+                AppError::Forbidden("Verify your email address before booking".to_string())
+            }
+            enums::BookingError::Database(e) => {
+                tracing::error!("Booking database error: {}", e);
+                AppError::Internal("Failed to process booking".to_string())
+            }
+            enums::BookingError::Redis(e) => {
+                tracing::error!("Booking Redis error: {}", e);
+                AppError::Internal("Failed to process booking".to_string())
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/bookings",
+    tag = "bookings",
+    request_body = models::bookings::CreateBookingRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Booking created", body = models::bookings::BookingDetailResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid dates, guest count or mismatched quote or hold"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Email not verified"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type, rate plan, quote or hold not found"),
+        (status = http::StatusCode::CONFLICT, description = "No rooms available"),
+        (status = http::StatusCode::GONE, description = "Quote or hold has expired or was already used"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_booking(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Json(payload): Json<models::bookings::CreateBookingRequest>,
+) -> Result<(StatusCode, Json<models::bookings::BookingDetailResponse>), AppError> {
+    let (booking, nightly_rates) = state
+        .services
+        .booking_service
+        .create(
+            claims.sub,
+            payload.hotel_id,
+            payload.room_type_id,
+            payload.rate_plan_id,
+            payload.quote_id,
+            payload.hold_id,
+            payload.check_in,
+            payload.check_out,
+            payload.guests,
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::bookings::BookingDetailResponse::new(
+            booking,
+            nightly_rates,
+        )),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/bookings",
+    tag = "bookings",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Bookings of the current user", body = Vec<models::bookings::BookingResponse>),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn list_bookings(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+) -> Result<Json<Vec<models::bookings::BookingResponse>>, AppError> {
+    let bookings: Vec<models::bookings::Booking> = state
+        .services
+        .booking_service
+        .list_by_user(claims.sub)
+        .await?;
+
+    Ok(Json(
+        bookings
+            .into_iter()
+            .map(models::bookings::BookingResponse::from)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/bookings/{id}",
+    tag = "bookings",
+    params(
+        ("id" = i32, Path, description = "Booking ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Booking details with the priced nights", body = models::bookings::BookingDetailResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Booking not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_booking(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path(id): Path<i32>,
+) -> Result<Json<models::bookings::BookingDetailResponse>, AppError> {
+    let booking: models::bookings::Booking = state
+        .services
+        .booking_service
+        .get_for_user(claims.sub, id)
+        .await?;
+
+    let nightly_rates: Vec<models::rate_plans::NightlyRate> = state
+        .services
+        .booking_service
+        .list_nights(booking.id)
+        .await?;
+
+    Ok(Json(models::bookings::BookingDetailResponse::new(
+        booking,
+        nightly_rates,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/bookings/{booking_id}/confirm",
+    tag = "bookings",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("booking_id" = i32, Path, description = "Booking ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Booking confirmed", body = models::bookings::BookingResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not a member of the hotel's staff"),
+        (status = http::StatusCode::NOT_FOUND, description = "Booking not found"),
+        (status = http::StatusCode::CONFLICT, description = "Transition not allowed from current status"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn confirm_booking(
+    State(state): State<models::AppState>,
+    Path((id, booking_id)): Path<(i32, i32)>,
+) -> Result<Json<models::bookings::BookingResponse>, AppError> {
+    transition(&state, id, booking_id, enums::BookingStatus::Confirmed).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/bookings/{booking_id}/check-in",
+    tag = "bookings",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("booking_id" = i32, Path, description = "Booking ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Guest checked in", body = models::bookings::BookingResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not a member of the hotel's staff"),
+        (status = http::StatusCode::NOT_FOUND, description = "Booking not found"),
+        (status = http::StatusCode::CONFLICT, description = "Transition not allowed from current status, or before the arrival date"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn check_in_booking(
+    State(state): State<models::AppState>,
+    Path((id, booking_id)): Path<(i32, i32)>,
+) -> Result<Json<models::bookings::BookingResponse>, AppError> {
+    transition(&state, id, booking_id, enums::BookingStatus::CheckedIn).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/bookings/{booking_id}/check-out",
+    tag = "bookings",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("booking_id" = i32, Path, description = "Booking ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Guest checked out", body = models::bookings::BookingResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not a member of the hotel's staff"),
+        (status = http::StatusCode::NOT_FOUND, description = "Booking not found"),
+        (status = http::StatusCode::CONFLICT, description = "Transition not allowed from current status"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn check_out_booking(
+    State(state): State<models::AppState>,
+    Path((id, booking_id)): Path<(i32, i32)>,
+) -> Result<Json<models::bookings::BookingResponse>, AppError> {
+    transition(&state, id, booking_id, enums::BookingStatus::CheckedOut).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/bookings/{id}/cancel",
+    tag = "bookings",
+    params(
+        ("id" = i32, Path, description = "Booking ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Booking cancelled with the penalty from its cancellation policy and the refund amount", body = models::bookings::BookingResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Booking not found"),
+        (status = http::StatusCode::CONFLICT, description = "Transition not allowed from current status"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn cancel_booking(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path(id): Path<i32>,
+) -> Result<Json<models::bookings::BookingResponse>, AppError> {
+    let booking: models::bookings::Booking = state
+        .services
+        .booking_service
+        .cancel(claims.sub, id)
+        .await?;
+
+    Ok(Json(models::bookings::BookingResponse::from(booking)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/bookings/{booking_id}/no-show",
+    tag = "bookings",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("booking_id" = i32, Path, description = "Booking ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Booking marked as no-show", body = models::bookings::BookingResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not a member of the hotel's staff"),
+        (status = http::StatusCode::NOT_FOUND, description = "Booking not found"),
+        (status = http::StatusCode::CONFLICT, description = "Transition not allowed from current status, or before the arrival date"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn mark_no_show(
+    State(state): State<models::AppState>,
+    Path((id, booking_id)): Path<(i32, i32)>,
+) -> Result<Json<models::bookings::BookingResponse>, AppError> {
+    transition(&state, id, booking_id, enums::BookingStatus::NoShow).await
+}
+
+async fn transition(
+    state: &models::AppState,
+    hotel_id: i32,
+    id: i32,
+    to: enums::BookingStatus,
+) -> Result<Json<models::bookings::BookingResponse>, AppError> {
+    let booking: models::bookings::Booking = state
+        .services
+        .booking_service
+        .transition(hotel_id, id, to)
+        .await?;
+
+    Ok(Json(models::bookings::BookingResponse::from(booking)))
+}
@@ -0,0 +1,102 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::{errors::AppError, models};
+
+#[utoipa::path(
+    post,
+    path = "/holds",
+    tag = "bookings",
+    request_body = models::holds::CreateHoldRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Inventory held until the hold expires", body = models::holds::HoldResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid dates or guest count"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type not found"),
+        (status = http::StatusCode::CONFLICT, description = "No rooms available"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_hold(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Json(payload): Json<models::holds::CreateHoldRequest>,
+) -> Result<(StatusCode, Json<models::holds::HoldResponse>), AppError> {
+    let hold: models::holds::BookingHold = state
+        .services
+        .hold_service
+        .create(
+            claims.sub,
+            payload.hotel_id,
+            payload.room_type_id,
+            payload.check_in,
+            payload.check_out,
+            payload.guests,
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::holds::HoldResponse::from(hold)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/holds/{id}",
+    tag = "bookings",
+    params(
+        ("id" = Uuid, Path, description = "Hold ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Hold details", body = models::holds::HoldResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hold not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_hold(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<models::holds::HoldResponse>, AppError> {
+    let hold: models::holds::BookingHold = state
+        .services
+        .hold_service
+        .get_for_user(claims.sub, id)
+        .await?;
+
+    Ok(Json(models::holds::HoldResponse::from(hold)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/holds/{id}",
+    tag = "bookings",
+    params(
+        ("id" = Uuid, Path, description = "Hold ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Hold released"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hold not found"),
+        (status = http::StatusCode::GONE, description = "Hold has expired or was already used"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn release_hold(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    state.services.hold_service.release(claims.sub, id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,38 +1,180 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
 
-use crate::models;
+use validator::Validate;
+
+use crate::{
+    enums,
+    errors::{self, AppError},
+    middleware, models,
+    validation::ValidatedJson,
+};
+
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+const DEFAULT_SEARCH_LIMIT: i64 = 20;
 
 #[utoipa::path(
     get,
     path = "/hotels",
     tag = "hotels",
+    params(models::hotels::HotelListQuery),
     responses(
-        (status = http::StatusCode::OK, description = "List of hotels", body = Vec<models::hotels::HotelResponse>),
-        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::OK, description = "One page of hotels", body = models::hotels::HotelPageResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid page size, or a cursor that is malformed or from another sort", body = errors::ProblemDetails, content_type = "application/problem+json"),
         (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
     )
 )]
 pub async fn list_hotels(
     State(state): State<models::AppState>,
-) -> Result<Json<Vec<models::hotels::HotelResponse>>, (StatusCode, String)> {
-    let hotels: Vec<models::hotels::Hotel> =
-        state.services.hotel_service.list_all().await.map_err(|e| {
-            tracing::error!("Failed to fetch hotels: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to fetch hotels".to_string(),
-            )
-        })?;
+    Query(query): Query<models::hotels::HotelListQuery>,
+) -> Result<Json<models::hotels::HotelPageResponse>, AppError> {
+    let limit: i64 = query.limit.unwrap_or(DEFAULT_LIMIT);
+    let sort: enums::HotelSort = query.sort.unwrap_or_default();
+    let order: enums::SortOrder = query.order.unwrap_or_default();
 
-    Ok(Json(
+    if !(1..=MAX_LIMIT).contains(&limit) {
+        return Err(AppError::BadRequest(format!(
+            "Limit must be between 1 and {}",
+            MAX_LIMIT
+        )));
+    }
+
+    let after: Option<models::hotels::HotelCursor> = match query.cursor.as_deref() {
+        Some(cursor) => {
+            let cursor: models::hotels::HotelCursor =
+                models::hotels::HotelCursor::decode(cursor)
+                    .ok_or_else(|| AppError::BadRequest("Invalid cursor".to_string()))?;
+            if cursor.sort != sort || cursor.order != order {
+                return Err(AppError::BadRequest(
+                    "Cursor was issued for a different sort".to_string(),
+                ));
+            }
+            Some(cursor)
+        }
+        None => None,
+    };
+
+    // One extra row tells us whether there is a next page.
+    let (mut hotels, total_estimate) = state
+        .services
+        .hotel_service
+        .list(
+            query.city,
+            query.country,
+            query.min_rating,
+            sort,
+            order,
+            after,
+            limit + 1,
+        )
+        .await?;
+
+    let next_cursor: Option<String> = if hotels.len() as i64 > limit {
+        hotels.truncate(limit as usize);
         hotels
+            .last()
+            .map(|hotel| models::hotels::HotelCursor::after(hotel, sort, order).encode())
+    } else {
+        None
+    };
+
+    Ok(Json(models::hotels::HotelPageResponse {
+        items: hotels
             .into_iter()
             .map(models::hotels::HotelResponse::from)
             .collect(),
+        limit,
+        next_cursor,
+        total_estimate,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/search",
+    tag = "hotels",
+    params(models::hotels::HotelSearchQuery),
+    responses(
+        (status = http::StatusCode::OK, description = "Hotels matching the query, most relevant first", body = models::hotels::HotelSearchResponse),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn search_hotels(
+    State(state): State<models::AppState>,
+    Query(query): Query<models::hotels::HotelSearchQuery>,
+) -> Result<Json<models::hotels::HotelSearchResponse>, AppError> {
+    query.validate()?;
+
+    let (hits, fuzzy) = state
+        .services
+        .hotel_service
+        .search(
+            query.q.trim(),
+            query.lang.as_deref().unwrap_or("en"),
+            query.limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+        )
+        .await?;
+
+    Ok(Json(models::hotels::HotelSearchResponse {
+        items: hits
+            .into_iter()
+            .map(models::hotels::HotelSearchResult::from)
+            .collect(),
+        fuzzy,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/availability",
+    tag = "hotels",
+    params(models::hotels::AvailabilityQuery),
+    responses(
+        (status = http::StatusCode::OK, description = "Hotels with at least one bookable room type for the whole stay, each with its cheapest offer including fees and taxes, cheapest first", body = Vec<models::hotels::HotelAvailabilityResponse>),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid dates or guest count"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn search_availability(
+    State(state): State<models::AppState>,
+    Query(query): Query<models::hotels::AvailabilityQuery>,
+) -> Result<Json<Vec<models::hotels::HotelAvailabilityResponse>>, AppError> {
+    if query.check_out <= query.check_in {
+        return Err(AppError::BadRequest(
+            "Check-out must be after check-in".to_string(),
+        ));
+    }
+
+    let guests: i32 = query.guests.unwrap_or(1);
+
+    if guests < 1 {
+        return Err(AppError::BadRequest(
+            "Guests must be at least 1".to_string(),
+        ));
+    }
+
+    let offers: Vec<models::hotels::HotelOffer> = state
+        .services
+        .hotel_service
+        .search_available(
+            query.city,
+            query.country,
+            query.check_in,
+            query.check_out,
+            guests,
+        )
+        .await?;
+
+    Ok(Json(
+        offers
+            .into_iter()
+            .map(models::hotels::HotelAvailabilityResponse::from)
+            .collect(),
     ))
 }
 
@@ -52,20 +194,13 @@ pub async fn list_hotels(
 pub async fn get_hotel(
     State(state): State<models::AppState>,
     Path(id): Path<i32>,
-) -> Result<Json<models::hotels::HotelResponse>, (StatusCode, String)> {
+) -> Result<Json<models::hotels::HotelResponse>, AppError> {
     let hotel: models::hotels::Hotel = state
         .services
         .hotel_service
         .get_by_id(id)
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to fetch hotel: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to fetch hotel".to_string(),
-            )
-        })?
-        .ok_or((StatusCode::NOT_FOUND, "Hotel not found".to_string()))?;
+        .await?
+        .ok_or_else(|| AppError::NotFound("Hotel not found".to_string()))?;
 
     Ok(Json(models::hotels::HotelResponse::from(hotel)))
 }
@@ -78,15 +213,31 @@ pub async fn get_hotel(
     security(("bearer_auth" = [])),
     responses(
         (status = http::StatusCode::CREATED, description = "Hotel created", body = models::hotels::HotelResponse),
-        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::BAD_REQUEST, description = "Owner is not a hotel manager"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
         (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Only admins and hotel managers can create hotels"),
         (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
     )
 )]
 pub async fn create_hotel(
     State(state): State<models::AppState>,
-    Json(payload): Json<models::hotels::CreateHotelRequest>,
-) -> Result<(StatusCode, Json<models::hotels::HotelResponse>), (StatusCode, String)> {
+    middleware::RequireRole(claims, _): middleware::RequireRole<middleware::HotelManagers>,
+    ValidatedJson(payload): ValidatedJson<models::hotels::CreateHotelRequest>,
+) -> Result<(StatusCode, Json<models::hotels::HotelResponse>), AppError> {
+    let owner_id: i32 = match (claims.role, payload.owner_id) {
+        (enums::UserRole::Admin, Some(owner_id)) => {
+            ensure_manager(&state, owner_id).await?;
+            owner_id
+        }
+        (enums::UserRole::HotelManager, Some(owner_id)) if owner_id != claims.sub => {
+            return Err(AppError::Forbidden(
+                "Only admins can assign another owner".to_string(),
+            ));
+        }
+        _ => claims.sub,
+    };
+
     let hotel: models::hotels::Hotel = state
         .services
         .hotel_service
@@ -96,15 +247,12 @@ pub async fn create_hotel(
             payload.address,
             payload.city,
             payload.country,
+            payload.timezone,
+            payload.check_in_time,
+            payload.language,
+            owner_id,
         )
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to create hotel: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to create hotel".to_string(),
-            )
-        })?;
+        .await?;
 
     Ok((
         StatusCode::CREATED,
@@ -124,15 +272,17 @@ pub async fn create_hotel(
     responses(
         (status = http::StatusCode::OK, description = "Hotel updated", body = models::hotels::HotelResponse),
         (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not an admin or one of the hotel's owners or managers"),
         (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
         (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
     )
 )]
 pub async fn update_hotel(
     State(state): State<models::AppState>,
     Path(id): Path<i32>,
-    Json(payload): Json<models::hotels::UpdateHotelRequest>,
-) -> Result<Json<models::hotels::HotelResponse>, (StatusCode, String)> {
+    ValidatedJson(payload): ValidatedJson<models::hotels::UpdateHotelRequest>,
+) -> Result<Json<models::hotels::HotelResponse>, AppError> {
     let hotel: models::hotels::Hotel = state
         .services
         .hotel_service
@@ -143,16 +293,55 @@ pub async fn update_hotel(
             payload.address,
             payload.city,
             payload.country,
+            payload.timezone,
+            payload.check_in_time,
+            payload.language,
         )
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to update hotel: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to update hotel".to_string(),
-            )
-        })?
-        .ok_or((StatusCode::NOT_FOUND, "Hotel not found".to_string()))?;
+        .await?
+        .ok_or_else(|| AppError::NotFound("Hotel not found".to_string()))?;
+
+    Ok(Json(models::hotels::HotelResponse::from(hotel)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/hotels/{id}",
+    tag = "hotels",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body(content = models::hotels::PatchHotelRequest, content_type = "application/merge-patch+json"),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Hotel updated", body = models::hotels::HotelResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not an admin or one of the hotel's owners or managers"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed or a required field set to null", body = errors::ProblemDetails, content_type = "application/problem+json"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn patch_hotel(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    ValidatedJson(payload): ValidatedJson<models::hotels::PatchHotelRequest>,
+) -> Result<Json<models::hotels::HotelResponse>, AppError> {
+    let hotel: models::hotels::Hotel = state
+        .services
+        .hotel_service
+        .patch(
+            id,
+            payload.name.into_option(),
+            payload.description,
+            payload.address.into_option(),
+            payload.city.into_option(),
+            payload.country.into_option(),
+            payload.timezone.into_option(),
+            payload.check_in_time.into_option(),
+            payload.language.into_option(),
+        )
+        .await?
+        .ok_or_else(|| AppError::NotFound("Hotel not found".to_string()))?;
 
     Ok(Json(models::hotels::HotelResponse::from(hotel)))
 }
@@ -168,6 +357,7 @@ pub async fn update_hotel(
     responses(
         (status = http::StatusCode::NO_CONTENT, description = "Hotel deleted"),
         (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not an admin or one of the hotel's owners"),
         (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
         (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
     )
@@ -175,18 +365,36 @@ pub async fn update_hotel(
 pub async fn delete_hotel(
     State(state): State<models::AppState>,
     Path(id): Path<i32>,
-) -> Result<StatusCode, (StatusCode, String)> {
-    let rows_affected: u64 = state.services.hotel_service.delete(id).await.map_err(|e| {
-        tracing::error!("Failed to delete hotel: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Failed to delete hotel".to_string(),
-        )
-    })?;
+) -> Result<StatusCode, AppError> {
+    let rows_affected: u64 = state.services.hotel_service.delete(id).await?;
 
     if rows_affected == 0 {
-        return Err((StatusCode::NOT_FOUND, "Hotel not found".to_string()));
+        return Err(AppError::NotFound("Hotel not found".to_string()));
     }
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+pub async fn ensure_hotel_exists(
+    state: &models::AppState,
+    id: i32,
+) -> Result<models::hotels::Hotel, AppError> {
+    state
+        .services
+        .hotel_service
+        .get_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Hotel not found".to_string()))
+}
+
+async fn ensure_manager(state: &models::AppState, user_id: i32) -> Result<(), AppError> {
+    let user: Option<models::users::User> = state.services.user_service.get_by_id(user_id).await?;
+
+    if !user.is_some_and(|user| user.role == enums::UserRole::HotelManager) {
+        return Err(AppError::BadRequest(
+            "Owner must be an existing hotel manager".to_string(),
+        ));
+    }
+
+    Ok(())
+}
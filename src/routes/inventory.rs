@@ -0,0 +1,103 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+
+use crate::routes::hotels::ensure_hotel_exists;
+use crate::routes::room_types::ensure_room_type_exists;
+use crate::{errors::AppError, models};
+
+const MAX_CALENDAR_DAYS: i64 = 366;
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/calendar",
+    tag = "inventory",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        models::inventory::CalendarQuery
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Per-day inventory for each room type", body = Vec<models::inventory::RoomTypeCalendarResponse>),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid date range"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_calendar(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    Query(query): Query<models::inventory::CalendarQuery>,
+) -> Result<Json<Vec<models::inventory::RoomTypeCalendarResponse>>, AppError> {
+    validate_range(query.from, query.to)?;
+    ensure_hotel_exists(&state, id).await?;
+
+    let calendar: Vec<models::inventory::RoomTypeCalendarResponse> = state
+        .services
+        .inventory_service
+        .calendar(id, query.from, query.to)
+        .await?;
+
+    Ok(Json(calendar))
+}
+
+#[utoipa::path(
+    put,
+    path = "/hotels/{id}/calendar",
+    tag = "inventory",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body = models::inventory::UpdateRestrictionsRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Restrictions updated"),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid date range"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn update_restrictions(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<models::inventory::UpdateRestrictionsRequest>,
+) -> Result<StatusCode, AppError> {
+    validate_range(payload.from, payload.to)?;
+
+    ensure_room_type_exists(&state, id, payload.room_type_id).await?;
+
+    state
+        .services
+        .inventory_service
+        .update_restrictions(
+            payload.room_type_id,
+            payload.from,
+            payload.to,
+            payload.stop_sell,
+            payload.closed_to_arrival,
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn validate_range(from: chrono::NaiveDate, to: chrono::NaiveDate) -> Result<(), AppError> {
+    if to < from {
+        return Err(AppError::BadRequest(
+            "The end date must not be before the start date".to_string(),
+        ));
+    }
+
+    if (to - from).num_days() >= MAX_CALENDAR_DAYS {
+        return Err(AppError::BadRequest(format!(
+            "Date range cannot exceed {} days",
+            MAX_CALENDAR_DAYS
+        )));
+    }
+
+    Ok(())
+}
@@ -1,8 +1,18 @@
+pub mod bookings;
 pub mod health;
+pub mod holds;
 pub mod hotels;
+pub mod inventory;
+pub mod quotes;
+pub mod rate_plans;
+pub mod reviews;
+pub mod room_types;
+pub mod rooms;
+pub mod staff;
+pub mod taxes;
 pub mod users;
 
-use crate::{middleware, models};
+use crate::{enums, errors, middleware, models};
 use axum::{Router, routing};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
@@ -33,19 +43,88 @@ impl utoipa::Modify for SecurityAddon {
         health::ready,
         users::register,
         users::login,
+        users::verify_two_factor,
+        users::setup_two_factor,
+        users::confirm_two_factor,
+        users::refresh,
+        users::logout,
+        users::logout_all,
+        users::forgot_password,
+        users::reset_password,
+        users::change_password,
+        users::verify_email,
+        users::resend_verification,
         users::profile,
         users::update_profile,
+        users::patch_profile,
+        users::update_role,
+        users::list_lockouts,
+        users::unlock_user,
         hotels::list_hotels,
+        hotels::search_hotels,
+        hotels::search_availability,
         hotels::get_hotel,
         hotels::create_hotel,
         hotels::update_hotel,
+        hotels::patch_hotel,
         hotels::delete_hotel,
+        room_types::list_room_types,
+        room_types::get_room_type,
+        room_types::create_room_type,
+        room_types::update_room_type,
+        room_types::delete_room_type,
+        rooms::list_rooms,
+        rooms::get_room,
+        rooms::create_room,
+        rooms::update_room,
+        rooms::delete_room,
+        rate_plans::list_rate_plans,
+        rate_plans::get_rate_plan,
+        rate_plans::create_rate_plan,
+        rate_plans::update_rate_plan,
+        rate_plans::delete_rate_plan,
+        rate_plans::create_season,
+        rate_plans::delete_season,
+        rate_plans::get_price,
+        taxes::get_taxes,
+        taxes::update_taxes,
+        taxes::create_fee,
+        taxes::delete_fee,
+        reviews::list_reviews,
+        reviews::create_review,
+        reviews::update_review,
+        reviews::delete_review,
+        staff::list_staff,
+        staff::invite_staff,
+        staff::remove_staff,
+        quotes::create_quote,
+        quotes::get_quote,
+        inventory::get_calendar,
+        inventory::update_restrictions,
+        bookings::create_booking,
+        bookings::list_bookings,
+        bookings::get_booking,
+        bookings::confirm_booking,
+        bookings::check_in_booking,
+        bookings::check_out_booking,
+        bookings::cancel_booking,
+        bookings::mark_no_show,
+        holds::create_hold,
+        holds::get_hold,
+        holds::release_hold,
     ),
+    components(schemas(errors::ProblemDetails, enums::HotelSort, enums::SortOrder)),
     modifiers(&SecurityAddon),
     tags(
         (name = "health", description = "Health check endpoints"),
         (name = "auth", description = "Authentication endpoints"),
         (name = "hotels", description = "Hotel management"),
+        (name = "rooms", description = "Room type and room inventory management"),
+        (name = "pricing", description = "Rate plans, seasonal prices and stay pricing"),
+        (name = "reviews", description = "Guest reviews and hotel ratings"),
+        (name = "staff", description = "Hotel staff membership"),
+        (name = "inventory", description = "Per-day inventory calendar and sell restrictions"),
+        (name = "bookings", description = "Reservations, checkout holds and booking lifecycle"),
     )
 )]
 pub struct ApiDoc;
@@ -56,18 +135,159 @@ pub fn create_routers(state: models::AppState) -> Router {
     let auth_routes = Router::new()
         .route(
             "/profile",
-            routing::get(users::profile).put(users::update_profile),
+            routing::get(users::profile)
+                .put(users::update_profile)
+                .patch(users::patch_profile),
+        )
+        .route("/logout", routing::post(users::logout))
+        .route("/logout-all", routing::post(users::logout_all))
+        .route("/password/change", routing::post(users::change_password))
+        .route("/2fa/setup", routing::post(users::setup_two_factor))
+        .route("/2fa/confirm", routing::post(users::confirm_two_factor))
+        .route(
+            "/verify-email/resend",
+            routing::post(users::resend_verification),
         )
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             middleware::auth_middleware,
         ));
 
+    let hotel_owner_routes = Router::new()
+        .route("/{id}", routing::delete(hotels::delete_hotel))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::hotel_staff_middleware::<middleware::HotelOwners>,
+        ));
+
+    let hotel_management_routes = Router::new()
+        .route(
+            "/{id}",
+            routing::put(hotels::update_hotel).patch(hotels::patch_hotel),
+        )
+        .route(
+            "/{id}/room-types",
+            routing::post(room_types::create_room_type),
+        )
+        .route(
+            "/{id}/room-types/{room_type_id}",
+            routing::put(room_types::update_room_type).delete(room_types::delete_room_type),
+        )
+        .route(
+            "/{id}/room-types/{room_type_id}/rate-plans",
+            routing::post(rate_plans::create_rate_plan),
+        )
+        .route(
+            "/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}",
+            routing::put(rate_plans::update_rate_plan).delete(rate_plans::delete_rate_plan),
+        )
+        .route(
+            "/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}/seasons",
+            routing::post(rate_plans::create_season),
+        )
+        .route(
+            "/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}/seasons/{season_id}",
+            routing::delete(rate_plans::delete_season),
+        )
+        .route("/{id}/taxes", routing::put(taxes::update_taxes))
+        .route("/{id}/fees", routing::post(taxes::create_fee))
+        .route("/{id}/fees/{fee_id}", routing::delete(taxes::delete_fee))
+        .route("/{id}/rooms", routing::post(rooms::create_room))
+        .route("/{id}/rooms/{room_id}", routing::delete(rooms::delete_room))
+        .route(
+            "/{id}/calendar",
+            routing::put(inventory::update_restrictions),
+        )
+        .route("/{id}/staff", routing::post(staff::invite_staff))
+        .route(
+            "/{id}/staff/{user_id}",
+            routing::delete(staff::remove_staff),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::hotel_staff_middleware::<middleware::HotelManagement>,
+        ));
+
+    let hotel_operations_routes = Router::new()
+        .route("/{id}/rooms/{room_id}", routing::put(rooms::update_room))
+        .route("/{id}/calendar", routing::get(inventory::get_calendar))
+        .route("/{id}/staff", routing::get(staff::list_staff))
+        .route(
+            "/{id}/bookings/{booking_id}/confirm",
+            routing::post(bookings::confirm_booking),
+        )
+        .route(
+            "/{id}/bookings/{booking_id}/check-in",
+            routing::post(bookings::check_in_booking),
+        )
+        .route(
+            "/{id}/bookings/{booking_id}/check-out",
+            routing::post(bookings::check_out_booking),
+        )
+        .route(
+            "/{id}/bookings/{booking_id}/no-show",
+            routing::post(bookings::mark_no_show),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::hotel_staff_middleware::<middleware::HotelOperations>,
+        ));
+
     let protected_hotel_routes = Router::new()
         .route("/", routing::post(hotels::create_hotel))
+        .merge(hotel_owner_routes)
+        .merge(hotel_management_routes)
+        .merge(hotel_operations_routes)
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ));
+
+    let review_routes = Router::new()
+        .route("/{id}/reviews", routing::post(reviews::create_review))
+        .route(
+            "/{id}/reviews/{review_id}",
+            routing::put(reviews::update_review).delete(reviews::delete_review),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ));
+
+    let admin_routes = Router::new()
+        .route("/users/{id}/role", routing::put(users::update_role))
+        .route("/users/{id}/lockout", routing::delete(users::unlock_user))
+        .route("/lockouts", routing::get(users::list_lockouts))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ));
+
+    let booking_routes = Router::new()
+        .route(
+            "/",
+            routing::get(bookings::list_bookings).post(bookings::create_booking),
+        )
+        .route("/{id}", routing::get(bookings::get_booking))
+        .route("/{id}/cancel", routing::post(bookings::cancel_booking))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ));
+
+    let quote_routes = Router::new()
+        .route("/", routing::post(quotes::create_quote))
+        .route("/{id}", routing::get(quotes::get_quote))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::auth_middleware,
+        ));
+
+    let hold_routes = Router::new()
+        .route("/", routing::post(holds::create_hold))
         .route(
             "/{id}",
-            routing::put(hotels::update_hotel).delete(hotels::delete_hotel),
+            routing::get(holds::get_hold).delete(holds::release_hold),
         )
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
@@ -75,14 +295,63 @@ pub fn create_routers(state: models::AppState) -> Router {
         ));
 
     Router::new()
-        .route("/health/live", routing::get(health::live))
-        .route("/health/ready", routing::get(health::ready))
         .route("/auth/register", routing::post(users::register))
         .route("/auth/login", routing::post(users::login))
+        .route("/auth/2fa/verify", routing::post(users::verify_two_factor))
+        .route("/auth/refresh", routing::post(users::refresh))
+        .route(
+            "/auth/password/forgot",
+            routing::post(users::forgot_password),
+        )
+        .route("/auth/password/reset", routing::post(users::reset_password))
+        .route("/auth/verify-email", routing::post(users::verify_email))
         .nest("/auth", auth_routes)
         .route("/hotels", routing::get(hotels::list_hotels))
+        .route("/hotels/search", routing::get(hotels::search_hotels))
+        .route(
+            "/hotels/availability",
+            routing::get(hotels::search_availability),
+        )
         .route("/hotels/{id}", routing::get(hotels::get_hotel))
+        .route(
+            "/hotels/{id}/room-types",
+            routing::get(room_types::list_room_types),
+        )
+        .route(
+            "/hotels/{id}/room-types/{room_type_id}",
+            routing::get(room_types::get_room_type),
+        )
+        .route(
+            "/hotels/{id}/room-types/{room_type_id}/rate-plans",
+            routing::get(rate_plans::list_rate_plans),
+        )
+        .route(
+            "/hotels/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}",
+            routing::get(rate_plans::get_rate_plan),
+        )
+        .route(
+            "/hotels/{id}/room-types/{room_type_id}/price",
+            routing::get(rate_plans::get_price),
+        )
+        .route("/hotels/{id}/taxes", routing::get(taxes::get_taxes))
+        .route("/hotels/{id}/reviews", routing::get(reviews::list_reviews))
+        .route("/hotels/{id}/rooms", routing::get(rooms::list_rooms))
+        .route(
+            "/hotels/{id}/rooms/{room_id}",
+            routing::get(rooms::get_room),
+        )
         .nest("/hotels", protected_hotel_routes)
+        .nest("/hotels", review_routes)
+        .nest("/admin", admin_routes)
+        .nest("/quotes", quote_routes)
+        .nest("/bookings", booking_routes)
+        .nest("/holds", hold_routes)
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            middleware::rate_limit_middleware,
+        ))
+        .route("/health/live", routing::get(health::live))
+        .route("/health/ready", routing::get(health::ready))
         .merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", openapi))
         .with_state(state)
 }
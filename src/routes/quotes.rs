@@ -0,0 +1,76 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::{errors::AppError, models};
+
+#[utoipa::path(
+    post,
+    path = "/quotes",
+    tag = "pricing",
+    request_body = models::quotes::CreateQuoteRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Quote with a line-item breakdown, bookable once by the requesting user", body = models::quotes::QuoteResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid dates or guest count"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type or rate plan not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_quote(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Json(payload): Json<models::quotes::CreateQuoteRequest>,
+) -> Result<(StatusCode, Json<models::quotes::QuoteResponse>), AppError> {
+    let quote: models::quotes::Quote = state
+        .services
+        .quote_service
+        .create(
+            claims.sub,
+            payload.hotel_id,
+            payload.room_type_id,
+            payload.rate_plan_id,
+            payload.check_in,
+            payload.check_out,
+            payload.guests,
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::quotes::QuoteResponse::from(quote)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/quotes/{id}",
+    tag = "pricing",
+    params(
+        ("id" = Uuid, Path, description = "Quote ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Quote details", body = models::quotes::QuoteResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Quote not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_quote(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<models::quotes::QuoteResponse>, AppError> {
+    let quote: models::quotes::Quote = state
+        .services
+        .quote_service
+        .get_for_user(claims.sub, id)
+        .await?;
+
+    Ok(Json(models::quotes::QuoteResponse::from(quote)))
+}
@@ -0,0 +1,348 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use rust_decimal::Decimal;
+
+use crate::routes::room_types::ensure_room_type_exists;
+use crate::{errors::AppError, models, validation::ValidatedJson};
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/room-types/{room_type_id}/rate-plans",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID")
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "Rate plans with their seasons", body = Vec<models::rate_plans::RatePlanResponse>),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn list_rate_plans(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id)): Path<(i32, i32)>,
+) -> Result<Json<Vec<models::rate_plans::RatePlanResponse>>, AppError> {
+    ensure_room_type_exists(&state, id, room_type_id).await?;
+
+    let rate_plans: Vec<models::rate_plans::RatePlanResponse> = state
+        .services
+        .rate_plan_service
+        .list_by_room_type(room_type_id)
+        .await?;
+
+    Ok(Json(rate_plans))
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID"),
+        ("rate_plan_id" = i32, Path, description = "Rate plan ID")
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "Rate plan details", body = models::rate_plans::RatePlanResponse),
+        (status = http::StatusCode::NOT_FOUND, description = "Rate plan not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_rate_plan(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id, rate_plan_id)): Path<(i32, i32, i32)>,
+) -> Result<Json<models::rate_plans::RatePlanResponse>, AppError> {
+    ensure_room_type_exists(&state, id, room_type_id).await?;
+
+    let rate_plan: models::rate_plans::RatePlanResponse = state
+        .services
+        .rate_plan_service
+        .get_by_id(room_type_id, rate_plan_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Rate plan not found".to_string()))?;
+
+    Ok(Json(rate_plan))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/room-types/{room_type_id}/rate-plans",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID")
+    ),
+    request_body = models::rate_plans::CreateRatePlanRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Rate plan created", body = models::rate_plans::RatePlanResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid price or cancellation policy"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_rate_plan(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id)): Path<(i32, i32)>,
+    ValidatedJson(payload): ValidatedJson<models::rate_plans::CreateRatePlanRequest>,
+) -> Result<(StatusCode, Json<models::rate_plans::RatePlanResponse>), AppError> {
+    validate_prices(&[Some(payload.base_price), payload.weekend_price])?;
+    validate_policy(payload.cancellation_policy.as_ref())?;
+    ensure_room_type_exists(&state, id, room_type_id).await?;
+
+    let rate_plan: models::rate_plans::RatePlanResponse = state
+        .services
+        .rate_plan_service
+        .create(
+            room_type_id,
+            payload.name,
+            payload.description,
+            payload.base_price,
+            payload.weekend_price,
+            payload.cancellation_policy.unwrap_or_default(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(rate_plan)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/hotels/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID"),
+        ("rate_plan_id" = i32, Path, description = "Rate plan ID")
+    ),
+    request_body = models::rate_plans::UpdateRatePlanRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Rate plan updated", body = models::rate_plans::RatePlanResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid price or cancellation policy"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Rate plan not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn update_rate_plan(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id, rate_plan_id)): Path<(i32, i32, i32)>,
+    ValidatedJson(payload): ValidatedJson<models::rate_plans::UpdateRatePlanRequest>,
+) -> Result<Json<models::rate_plans::RatePlanResponse>, AppError> {
+    validate_prices(&[payload.base_price, payload.weekend_price])?;
+    validate_policy(payload.cancellation_policy.as_ref())?;
+    ensure_room_type_exists(&state, id, room_type_id).await?;
+
+    let rate_plan: models::rate_plans::RatePlanResponse = state
+        .services
+        .rate_plan_service
+        .update(
+            room_type_id,
+            rate_plan_id,
+            payload.name,
+            payload.description,
+            payload.base_price,
+            payload.weekend_price,
+            payload.cancellation_policy,
+        )
+        .await?
+        .ok_or_else(|| AppError::NotFound("Rate plan not found".to_string()))?;
+
+    Ok(Json(rate_plan))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/hotels/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID"),
+        ("rate_plan_id" = i32, Path, description = "Rate plan ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Rate plan deleted"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Rate plan not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn delete_rate_plan(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id, rate_plan_id)): Path<(i32, i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    ensure_room_type_exists(&state, id, room_type_id).await?;
+
+    let rows_affected: u64 = state
+        .services
+        .rate_plan_service
+        .delete(room_type_id, rate_plan_id)
+        .await?;
+
+    if rows_affected == 0 {
+        return Err(AppError::NotFound("Rate plan not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}/seasons",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID"),
+        ("rate_plan_id" = i32, Path, description = "Rate plan ID")
+    ),
+    request_body = models::rate_plans::CreateSeasonRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Seasonal price added", body = models::rate_plans::SeasonResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid dates or price"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Rate plan not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_season(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id, rate_plan_id)): Path<(i32, i32, i32)>,
+    ValidatedJson(payload): ValidatedJson<models::rate_plans::CreateSeasonRequest>,
+) -> Result<(StatusCode, Json<models::rate_plans::SeasonResponse>), AppError> {
+    if payload.end_date < payload.start_date {
+        return Err(AppError::BadRequest(
+            "The end date must not be before the start date".to_string(),
+        ));
+    }
+
+    validate_prices(&[Some(payload.price), payload.weekend_price])?;
+    ensure_room_type_exists(&state, id, room_type_id).await?;
+
+    let season: models::rate_plans::RatePlanSeason = state
+        .services
+        .rate_plan_service
+        .create_season(
+            room_type_id,
+            rate_plan_id,
+            payload.name,
+            payload.start_date,
+            payload.end_date,
+            payload.price,
+            payload.weekend_price,
+        )
+        .await?
+        .ok_or_else(|| AppError::NotFound("Rate plan not found".to_string()))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::rate_plans::SeasonResponse::from(season)),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/hotels/{id}/room-types/{room_type_id}/rate-plans/{rate_plan_id}/seasons/{season_id}",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID"),
+        ("rate_plan_id" = i32, Path, description = "Rate plan ID"),
+        ("season_id" = i32, Path, description = "Season ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Seasonal price removed"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Season not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn delete_season(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id, rate_plan_id, season_id)): Path<(i32, i32, i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    ensure_room_type_exists(&state, id, room_type_id).await?;
+
+    let rows_affected: u64 = state
+        .services
+        .rate_plan_service
+        .delete_season(room_type_id, rate_plan_id, season_id)
+        .await?;
+
+    if rows_affected == 0 {
+        return Err(AppError::NotFound("Season not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/room-types/{room_type_id}/price",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID"),
+        models::rate_plans::PriceQuery
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "Nightly breakdown and total for the stay", body = models::rate_plans::StayPrice),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid dates"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type or rate plan not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_price(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id)): Path<(i32, i32)>,
+    Query(query): Query<models::rate_plans::PriceQuery>,
+) -> Result<Json<models::rate_plans::StayPrice>, AppError> {
+    let price: models::rate_plans::StayPrice = state
+        .services
+        .pricing_service
+        .quote(
+            id,
+            room_type_id,
+            query.rate_plan_id,
+            query.check_in,
+            query.check_out,
+        )
+        .await?;
+
+    Ok(Json(price))
+}
+
+fn validate_prices(prices: &[Option<Decimal>]) -> Result<(), AppError> {
+    if prices
+        .iter()
+        .flatten()
+        .any(|price| price.is_sign_negative())
+    {
+        return Err(AppError::BadRequest(
+            "Prices must not be negative".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_policy(
+    policy: Option<&models::rate_plans::CancellationPolicy>,
+) -> Result<(), AppError> {
+    if policy.is_some_and(|policy| !policy.is_valid()) {
+        return Err(AppError::BadRequest("Free cancellation hours must not be negative and percentage penalties need a percent between 0 and 100".to_string()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,219 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+
+use crate::routes::hotels::ensure_hotel_exists;
+use crate::{enums, errors::AppError, models};
+
+const DEFAULT_PER_PAGE: i64 = 20;
+const MAX_PER_PAGE: i64 = 100;
+/// Keeps the row offset well inside `i64`; deep pages of reviews are not
+/// useful anyway.
+const MAX_PAGE: i64 = 10_000;
+const MAX_COMMENT_LENGTH: usize = 5000;
+
+impl From<enums::ReviewError> for AppError {
+    fn from(e: enums::ReviewError) -> Self {
+        match e {
+            enums::ReviewError::NotFound => AppError::NotFound("Review not found".to_string()),
+            enums::ReviewError::BookingNotFound => {
+                AppError::NotFound("Booking not found".to_string())
+            }
+            enums::ReviewError::NotCheckedOut => {
+                AppError::Conflict("Only checked-out stays can be reviewed".to_string())
+            }
+            enums::ReviewError::AlreadyReviewed => {
+                AppError::Conflict("This booking has already been reviewed".to_string())
+            }
+            enums::ReviewError::Database(e) => {
+                tracing::error!("Review database error: {}", e);
+                AppError::Internal("Failed to process review".to_string())
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/reviews",
+    tag = "reviews",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        models::reviews::ReviewsQuery
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "Newest reviews first", body = models::reviews::ReviewPageResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid page or page size"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn list_reviews(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    Query(query): Query<models::reviews::ReviewsQuery>,
+) -> Result<Json<models::reviews::ReviewPageResponse>, AppError> {
+    let page: i64 = query.page.unwrap_or(1);
+    let per_page: i64 = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
+
+    if !(1..=MAX_PAGE).contains(&page) || !(1..=MAX_PER_PAGE).contains(&per_page) {
+        return Err(AppError::BadRequest(format!(
+            "Page must be between 1 and {} and page size between 1 and {}",
+            MAX_PAGE, MAX_PER_PAGE
+        )));
+    }
+
+    ensure_hotel_exists(&state, id).await?;
+
+    let (reviews, total) = state
+        .services
+        .review_service
+        .list_by_hotel(id, page, per_page)
+        .await?;
+
+    Ok(Json(models::reviews::ReviewPageResponse {
+        items: reviews
+            .into_iter()
+            .map(models::reviews::ReviewResponse::from)
+            .collect(),
+        page,
+        per_page,
+        total,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/reviews",
+    tag = "reviews",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body = models::reviews::CreateReviewRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Review posted", body = models::reviews::ReviewResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid score or comment"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Booking not found"),
+        (status = http::StatusCode::CONFLICT, description = "Stay not checked out or already reviewed"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_review(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path(id): Path<i32>,
+    Json(payload): Json<models::reviews::CreateReviewRequest>,
+) -> Result<(StatusCode, Json<models::reviews::ReviewResponse>), AppError> {
+    validate_review(Some(payload.score), Some(&payload.comment))?;
+
+    let review: models::reviews::Review = state
+        .services
+        .review_service
+        .create(
+            claims.sub,
+            id,
+            payload.booking_id,
+            payload.score,
+            payload.comment.trim().to_string(),
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::reviews::ReviewResponse::from(review)),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/hotels/{id}/reviews/{review_id}",
+    tag = "reviews",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("review_id" = i32, Path, description = "Review ID")
+    ),
+    request_body = models::reviews::UpdateReviewRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Review updated", body = models::reviews::ReviewResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid score or comment"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Review not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn update_review(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path((id, review_id)): Path<(i32, i32)>,
+    Json(payload): Json<models::reviews::UpdateReviewRequest>,
+) -> Result<Json<models::reviews::ReviewResponse>, AppError> {
+    validate_review(payload.score, payload.comment.as_deref())?;
+
+    let review: models::reviews::Review = state
+        .services
+        .review_service
+        .update(
+            claims.sub,
+            id,
+            review_id,
+            payload.score,
+            payload.comment.map(|comment| comment.trim().to_string()),
+        )
+        .await?;
+
+    Ok(Json(models::reviews::ReviewResponse::from(review)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/hotels/{id}/reviews/{review_id}",
+    tag = "reviews",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("review_id" = i32, Path, description = "Review ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Review deleted"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Review not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn delete_review(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Path((id, review_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    state
+        .services
+        .review_service
+        .delete(claims.sub, id, review_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn validate_review(score: Option<i32>, comment: Option<&str>) -> Result<(), AppError> {
+    if score.is_some_and(|score| !(1..=5).contains(&score)) {
+        return Err(AppError::BadRequest(
+            "Score must be between 1 and 5".to_string(),
+        ));
+    }
+
+    if comment.is_some_and(|comment| {
+        comment.trim().is_empty() || comment.chars().count() > MAX_COMMENT_LENGTH
+    }) {
+        return Err(AppError::BadRequest(format!(
+            "Comment must not be empty or longer than {} characters",
+            MAX_COMMENT_LENGTH
+        )));
+    }
+
+    Ok(())
+}
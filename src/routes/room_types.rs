@@ -0,0 +1,225 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::routes::hotels::ensure_hotel_exists;
+use crate::{errors::AppError, models, validation::ValidatedJson};
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/room-types",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "List of room types", body = Vec<models::room_types::RoomTypeResponse>),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn list_room_types(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<models::room_types::RoomTypeResponse>>, AppError> {
+    ensure_hotel_exists(&state, id).await?;
+
+    let room_types: Vec<models::room_types::RoomType> =
+        state.services.room_type_service.list_by_hotel(id).await?;
+
+    Ok(Json(
+        room_types
+            .into_iter()
+            .map(models::room_types::RoomTypeResponse::from)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/room-types/{room_type_id}",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID")
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "Room type details", body = models::room_types::RoomTypeResponse),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_room_type(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id)): Path<(i32, i32)>,
+) -> Result<Json<models::room_types::RoomTypeResponse>, AppError> {
+    let room_type: models::room_types::RoomType = state
+        .services
+        .room_type_service
+        .get_by_id(id, room_type_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Room type not found".to_string()))?;
+
+    Ok(Json(models::room_types::RoomTypeResponse::from(room_type)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/room-types",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body = models::room_types::CreateRoomTypeRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Room type created", body = models::room_types::RoomTypeResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid capacity or price"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_room_type(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    ValidatedJson(payload): ValidatedJson<models::room_types::CreateRoomTypeRequest>,
+) -> Result<(StatusCode, Json<models::room_types::RoomTypeResponse>), AppError> {
+    if payload.capacity < 1 {
+        return Err(AppError::BadRequest(
+            "Capacity must be at least 1".to_string(),
+        ));
+    }
+
+    if payload.base_price.is_sign_negative() {
+        return Err(AppError::BadRequest(
+            "Base price must not be negative".to_string(),
+        ));
+    }
+
+    ensure_hotel_exists(&state, id).await?;
+
+    let room_type: models::room_types::RoomType = state
+        .services
+        .room_type_service
+        .create(
+            id,
+            payload.name,
+            payload.description,
+            payload.capacity,
+            payload.bed_configuration,
+            payload.base_price,
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::room_types::RoomTypeResponse::from(room_type)),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/hotels/{id}/room-types/{room_type_id}",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID")
+    ),
+    request_body = models::room_types::UpdateRoomTypeRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Room type updated", body = models::room_types::RoomTypeResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid capacity or price"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn update_room_type(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id)): Path<(i32, i32)>,
+    ValidatedJson(payload): ValidatedJson<models::room_types::UpdateRoomTypeRequest>,
+) -> Result<Json<models::room_types::RoomTypeResponse>, AppError> {
+    if payload.capacity.is_some_and(|capacity| capacity < 1) {
+        return Err(AppError::BadRequest(
+            "Capacity must be at least 1".to_string(),
+        ));
+    }
+
+    if payload
+        .base_price
+        .is_some_and(|price| price.is_sign_negative())
+    {
+        return Err(AppError::BadRequest(
+            "Base price must not be negative".to_string(),
+        ));
+    }
+
+    let room_type: models::room_types::RoomType = state
+        .services
+        .room_type_service
+        .update(
+            id,
+            room_type_id,
+            payload.name,
+            payload.description,
+            payload.capacity,
+            payload.bed_configuration,
+            payload.base_price,
+        )
+        .await?
+        .ok_or_else(|| AppError::NotFound("Room type not found".to_string()))?;
+
+    Ok(Json(models::room_types::RoomTypeResponse::from(room_type)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/hotels/{id}/room-types/{room_type_id}",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_type_id" = i32, Path, description = "Room type ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Room type deleted"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room type not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn delete_room_type(
+    State(state): State<models::AppState>,
+    Path((id, room_type_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    let rows_affected: u64 = state
+        .services
+        .room_type_service
+        .delete(id, room_type_id)
+        .await?;
+
+    if rows_affected == 0 {
+        return Err(AppError::NotFound("Room type not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn ensure_room_type_exists(
+    state: &models::AppState,
+    hotel_id: i32,
+    room_type_id: i32,
+) -> Result<models::room_types::RoomType, AppError> {
+    state
+        .services
+        .room_type_service
+        .get_by_id(hotel_id, room_type_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Room type not found".to_string()))
+}
@@ -0,0 +1,192 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::routes::hotels::ensure_hotel_exists;
+use crate::routes::room_types::ensure_room_type_exists;
+use crate::{enums, errors::AppError, models};
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/rooms",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "List of rooms", body = Vec<models::rooms::RoomResponse>),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn list_rooms(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<models::rooms::RoomResponse>>, AppError> {
+    ensure_hotel_exists(&state, id).await?;
+
+    let rooms: Vec<models::rooms::Room> = state.services.room_service.list_by_hotel(id).await?;
+
+    Ok(Json(
+        rooms
+            .into_iter()
+            .map(models::rooms::RoomResponse::from)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/rooms/{room_id}",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_id" = i32, Path, description = "Room ID")
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "Room details", body = models::rooms::RoomResponse),
+        (status = http::StatusCode::NOT_FOUND, description = "Room not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_room(
+    State(state): State<models::AppState>,
+    Path((id, room_id)): Path<(i32, i32)>,
+) -> Result<Json<models::rooms::RoomResponse>, AppError> {
+    let room: models::rooms::Room = state
+        .services
+        .room_service
+        .get_by_id(id, room_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Room not found".to_string()))?;
+
+    Ok(Json(models::rooms::RoomResponse::from(room)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/rooms",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body = models::rooms::CreateRoomRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Room created", body = models::rooms::RoomResponse),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel or room type not found"),
+        (status = http::StatusCode::CONFLICT, description = "Room number already exists"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_room(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    Json(payload): Json<models::rooms::CreateRoomRequest>,
+) -> Result<(StatusCode, Json<models::rooms::RoomResponse>), AppError> {
+    ensure_hotel_exists(&state, id).await?;
+    ensure_room_type_exists(&state, id, payload.room_type_id).await?;
+
+    let room: models::rooms::Room = state
+        .services
+        .room_service
+        .create(
+            id,
+            payload.room_type_id,
+            payload.room_number,
+            payload.floor,
+            payload.status.unwrap_or(enums::RoomStatus::Available),
+        )
+        .await
+        .map_err(map_room_write_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::rooms::RoomResponse::from(room)),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/hotels/{id}/rooms/{room_id}",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_id" = i32, Path, description = "Room ID")
+    ),
+    request_body = models::rooms::UpdateRoomRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Room updated", body = models::rooms::RoomResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room or room type not found"),
+        (status = http::StatusCode::CONFLICT, description = "Room number already exists"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn update_room(
+    State(state): State<models::AppState>,
+    Path((id, room_id)): Path<(i32, i32)>,
+    Json(payload): Json<models::rooms::UpdateRoomRequest>,
+) -> Result<Json<models::rooms::RoomResponse>, AppError> {
+    if let Some(room_type_id) = payload.room_type_id {
+        ensure_room_type_exists(&state, id, room_type_id).await?;
+    }
+
+    let room: models::rooms::Room = state
+        .services
+        .room_service
+        .update(
+            id,
+            room_id,
+            payload.room_type_id,
+            payload.room_number,
+            payload.floor,
+            payload.status,
+        )
+        .await
+        .map_err(map_room_write_error)?
+        .ok_or_else(|| AppError::NotFound("Room not found".to_string()))?;
+
+    Ok(Json(models::rooms::RoomResponse::from(room)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/hotels/{id}/rooms/{room_id}",
+    tag = "rooms",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("room_id" = i32, Path, description = "Room ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Room deleted"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Room not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn delete_room(
+    State(state): State<models::AppState>,
+    Path((id, room_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    let rows_affected: u64 = state.services.room_service.delete(id, room_id).await?;
+
+    if rows_affected == 0 {
+        return Err(AppError::NotFound("Room not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn map_room_write_error(e: sqlx::Error) -> AppError {
+    match AppError::from(e) {
+        AppError::Conflict(_) => AppError::Conflict("Room number already exists".to_string()),
+        e => e,
+    }
+}
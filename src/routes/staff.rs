@@ -0,0 +1,134 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::routes::hotels::ensure_hotel_exists;
+use crate::{enums, errors::AppError, models};
+
+impl From<enums::StaffError> for AppError {
+    fn from(e: enums::StaffError) -> Self {
+        match e {
+            enums::StaffError::NotFound => AppError::NotFound("Staff member not found".to_string()),
+            enums::StaffError::UserNotFound => {
+                AppError::NotFound("No user registered with that email".to_string())
+            }
+            enums::StaffError::AlreadyMember => {
+                AppError::Conflict("User is already a member of this hotel's staff".to_string())
+            }
+            enums::StaffError::LastOwner => {
+                AppError::Conflict("A hotel must keep at least one owner".to_string())
+            }
+            enums::StaffError::OwnersOnly => {
+                AppError::Forbidden("Only owners can add or remove owners".to_string())
+            }
+            enums::StaffError::Database(e) => {
+                tracing::error!("Staff database error: {}", e);
+                AppError::Internal("Failed to process staff change".to_string())
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/staff",
+    tag = "staff",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Hotel staff", body = Vec<models::staff::StaffMemberResponse>),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not a member of the hotel's staff"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn list_staff(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<Vec<models::staff::StaffMemberResponse>>, AppError> {
+    ensure_hotel_exists(&state, id).await?;
+
+    let staff: Vec<models::staff::StaffMember> = state.services.staff_service.list(id).await?;
+
+    Ok(Json(
+        staff
+            .into_iter()
+            .map(models::staff::StaffMemberResponse::from)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/staff",
+    tag = "staff",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body = models::staff::InviteStaffRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Staff member added", body = models::staff::StaffMemberResponse),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not an owner or manager, or a manager adding an owner"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel or user not found"),
+        (status = http::StatusCode::CONFLICT, description = "User is already on the staff"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn invite_staff(
+    State(state): State<models::AppState>,
+    Extension(actor_role): Extension<enums::StaffRole>,
+    Path(id): Path<i32>,
+    Json(payload): Json<models::staff::InviteStaffRequest>,
+) -> Result<(StatusCode, Json<models::staff::StaffMemberResponse>), AppError> {
+    ensure_hotel_exists(&state, id).await?;
+
+    let member: models::staff::StaffMember = state
+        .services
+        .staff_service
+        .invite(id, actor_role, payload.email.trim(), payload.role)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::staff::StaffMemberResponse::from(member)),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/hotels/{id}/staff/{user_id}",
+    tag = "staff",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("user_id" = i32, Path, description = "User ID of the staff member")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Staff member removed"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::FORBIDDEN, description = "Not an owner or manager, or a manager removing an owner"),
+        (status = http::StatusCode::NOT_FOUND, description = "Staff member not found"),
+        (status = http::StatusCode::CONFLICT, description = "Removing the last owner"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn remove_staff(
+    State(state): State<models::AppState>,
+    Extension(actor_role): Extension<enums::StaffRole>,
+    Path((id, user_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    state
+        .services
+        .staff_service
+        .remove(id, actor_role, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
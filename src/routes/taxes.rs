@@ -0,0 +1,158 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::routes::hotels::ensure_hotel_exists;
+use crate::{errors::AppError, models, validation::ValidatedJson};
+
+#[utoipa::path(
+    get,
+    path = "/hotels/{id}/taxes",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    responses(
+        (status = http::StatusCode::OK, description = "Tax rates and fixed fees of the hotel", body = models::taxes::TaxesResponse),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn get_taxes(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<models::taxes::TaxesResponse>, AppError> {
+    let taxes: models::taxes::TaxesResponse = state
+        .services
+        .tax_service
+        .get_by_hotel(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Hotel not found".to_string()))?;
+
+    Ok(Json(taxes))
+}
+
+#[utoipa::path(
+    put,
+    path = "/hotels/{id}/taxes",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body = models::taxes::UpdateTaxesRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::OK, description = "Tax rates updated", body = models::taxes::TaxesResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid tax rate"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn update_taxes(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    ValidatedJson(payload): ValidatedJson<models::taxes::UpdateTaxesRequest>,
+) -> Result<Json<models::taxes::TaxesResponse>, AppError> {
+    if payload
+        .city_tax_per_person_night
+        .is_some_and(|tax| tax.is_sign_negative())
+    {
+        return Err(AppError::BadRequest(
+            "City tax must not be negative".to_string(),
+        ));
+    }
+
+    if payload
+        .vat_percent
+        .is_some_and(|vat| vat.is_sign_negative() || vat > rust_decimal::Decimal::ONE_HUNDRED)
+    {
+        return Err(AppError::BadRequest(
+            "VAT must be between 0 and 100 percent".to_string(),
+        ));
+    }
+
+    let taxes: models::taxes::TaxesResponse = state
+        .services
+        .tax_service
+        .update(id, payload.city_tax_per_person_night, payload.vat_percent)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Hotel not found".to_string()))?;
+
+    Ok(Json(taxes))
+}
+
+#[utoipa::path(
+    post,
+    path = "/hotels/{id}/fees",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID")
+    ),
+    request_body = models::taxes::CreateFeeRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::CREATED, description = "Fee added", body = models::taxes::FeeResponse),
+        (status = http::StatusCode::BAD_REQUEST, description = "Invalid amount"),
+        (status = http::StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Hotel not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn create_fee(
+    State(state): State<models::AppState>,
+    Path(id): Path<i32>,
+    ValidatedJson(payload): ValidatedJson<models::taxes::CreateFeeRequest>,
+) -> Result<(StatusCode, Json<models::taxes::FeeResponse>), AppError> {
+    if payload.amount.is_sign_negative() {
+        return Err(AppError::BadRequest(
+            "Fee amount must not be negative".to_string(),
+        ));
+    }
+
+    ensure_hotel_exists(&state, id).await?;
+
+    let fee: models::taxes::HotelFee = state
+        .services
+        .tax_service
+        .create_fee(id, payload.name, payload.amount)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(models::taxes::FeeResponse::from(fee)),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/hotels/{id}/fees/{fee_id}",
+    tag = "pricing",
+    params(
+        ("id" = i32, Path, description = "Hotel ID"),
+        ("fee_id" = i32, Path, description = "Fee ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = http::StatusCode::NO_CONTENT, description = "Fee removed"),
+        (status = http::StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = http::StatusCode::NOT_FOUND, description = "Fee not found"),
+        (status = http::StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn delete_fee(
+    State(state): State<models::AppState>,
+    Path((id, fee_id)): Path<(i32, i32)>,
+) -> Result<StatusCode, AppError> {
+    let rows_affected: u64 = state.services.tax_service.delete_fee(id, fee_id).await?;
+
+    if rows_affected == 0 {
+        return Err(AppError::NotFound("Fee not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
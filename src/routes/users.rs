@@ -1,10 +1,126 @@
 use axum::{
     Json,
-    extract::State,
+    extract::{ConnectInfo, Extension, Path, State},
     http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
 };
 
-use crate::{models, utils};
+use crate::{
+    enums,
+    errors::{self, AppError},
+    middleware, models, utils,
+    validation::ValidatedJson,
+};
+
+impl From<enums::SessionError> for AppError {
+    fn from(e: enums::SessionError) -> Self {
+        tracing::error!("Failed to revoke session: {}", e);
+        AppError::Internal("Failed to log out".to_string())
+    }
+}
+
+impl From<enums::PasswordError> for AppError {
+    fn from(e: enums::PasswordError) -> Self {
+        match e {
+            enums::PasswordError::InvalidToken => {
+                AppError::BadRequest("Invalid or expired reset token".to_string())
+            }
+            enums::PasswordError::Database(e) => {
+                tracing::error!("Password update database error: {}", e);
+                AppError::Internal("Failed to update password".to_string())
+            }
+            enums::PasswordError::Session(e) => {
+                tracing::error!("Failed to revoke sessions after password update: {}", e);
+                AppError::Internal("Failed to update password".to_string())
+            }
+        }
+    }
+}
+
+impl From<enums::EmailVerificationError> for AppError {
+    fn from(e: enums::EmailVerificationError) -> Self {
+        match e {
+            enums::EmailVerificationError::InvalidToken => {
+                AppError::BadRequest("Invalid or expired verification token".to_string())
+            }
+            enums::EmailVerificationError::Database(e) => {
+                tracing::error!("Email verification database error: {}", e);
+                AppError::Internal("Failed to verify email".to_string())
+            }
+        }
+    }
+}
+
+impl From<enums::TwoFactorError> for AppError {
+    fn from(e: enums::TwoFactorError) -> Self {
+        match e {
+            enums::TwoFactorError::AlreadyEnabled => {
+                AppError::Conflict("Two-factor authentication is already enabled".to_string())
+            }
+            enums::TwoFactorError::NotSetUp => {
+                AppError::Conflict("Two-factor setup has not been started".to_string())
+            }
+            enums::TwoFactorError::InvalidCode => {
+                AppError::BadRequest("Invalid two-factor code".to_string())
+            }
+            enums::TwoFactorError::InvalidChallenge => {
+                AppError::Unauthorized("Invalid or expired challenge token".to_string())
+            }
+            enums::TwoFactorError::Database(e) => {
+                tracing::error!("Two-factor database error: {}", e);
+                AppError::Internal("Failed to process two-factor authentication".to_string())
+            }
+            enums::TwoFactorError::Redis(e) => {
+                tracing::error!("Two-factor Redis error: {}", e);
+                AppError::Internal("Failed to process two-factor authentication".to_string())
+            }
+        }
+    }
+}
+
+impl From<enums::LoginThrottleError> for AppError {
+    fn from(e: enums::LoginThrottleError) -> Self {
+        match e {
+            enums::LoginThrottleError::TooManyAttempts {
+                retry_after_seconds,
+            } => AppError::TooManyRequests {
+                detail: "Too many failed login attempts".to_string(),
+                retry_after_seconds,
+            },
+            enums::LoginThrottleError::AccountLocked {
+                retry_after_seconds,
+            } => AppError::TooManyRequests {
+                detail: "Account temporarily locked".to_string(),
+                retry_after_seconds,
+            },
+            enums::LoginThrottleError::Database(e) => {
+                tracing::error!("Login throttle database error: {}", e);
+                AppError::Internal("Failed to check login attempts".to_string())
+            }
+            enums::LoginThrottleError::Redis(e) => {
+                tracing::error!("Login throttle Redis error: {}", e);
+                AppError::Internal("Failed to check login attempts".to_string())
+            }
+        }
+    }
+}
+
+impl From<enums::RefreshTokenError> for AppError {
+    fn from(e: enums::RefreshTokenError) -> Self {
+        match e {
+            enums::RefreshTokenError::Invalid => {
+                AppError::Unauthorized("Invalid or expired refresh token".to_string())
+            }
+            enums::RefreshTokenError::Reused => AppError::Unauthorized(
+                "Refresh token was already used; its session has been revoked".to_string(),
+            ),
+            enums::RefreshTokenError::Database(e) => {
+                tracing::error!("Refresh token database error: {}", e);
+                AppError::Internal("Failed to refresh token".to_string())
+            }
+        }
+    }
+}
 
 #[utoipa::path(
     post,
@@ -13,32 +129,16 @@ use crate::{models, utils};
     request_body = models::users::RegisterRequest,
     responses(
         (status = StatusCode::CREATED, description = "User registered successfully", body = models::users::AuthResponse),
-        (status = StatusCode::BAD_REQUEST, description = "Invalid input or email already exists"),
+        (status = StatusCode::CONFLICT, description = "Email already exists"),
+        (status = StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
         (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
     )
 )]
 pub async fn register(
     State(state): State<models::AppState>,
-    Json(payload): Json<models::users::RegisterRequest>,
-) -> Result<(StatusCode, Json<models::users::AuthResponse>), (StatusCode, String)> {
-    if !payload.email.contains('@') {
-        return Err((StatusCode::BAD_REQUEST, "Invalid email format".to_string()));
-    }
-
-    if payload.password.len() < 6 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Password must be at least 6 characters".to_string(),
-        ));
-    }
-
-    let password_hash = bcrypt::hash(&payload.password, 12).map_err(|e| {
-        tracing::error!("Failed to hash password: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Failed to hash password".to_string(),
-        )
-    })?;
+    ValidatedJson(payload): ValidatedJson<models::users::RegisterRequest>,
+) -> Result<(StatusCode, Json<models::users::AuthResponse>), AppError> {
+    let password_hash: String = hash_new_password(&payload.password)?;
 
     let user: models::users::User = state
         .services
@@ -50,30 +150,12 @@ pub async fn register(
             payload.last_name,
         )
         .await
-        .map_err(|e| {
-            tracing::error!("Failed to create user: {}", e);
-            let msg = if e.to_string().contains("duplicate key") {
-                "Email already exists".to_string()
-            } else {
-                "Failed to create user".to_string()
-            };
-            (StatusCode::BAD_REQUEST, msg)
+        .map_err(|e| match AppError::from(e) {
+            AppError::Conflict(_) => AppError::Conflict("Email already exists".to_string()),
+            e => e,
         })?;
 
-    let token: String = utils::create_jwt_token(
-        user.id,
-        user.email.clone(),
-        &state.jwt_secret,
-        state.jwt_expire_hours,
-    )?;
-
-    Ok((
-        StatusCode::CREATED,
-        Json(models::users::AuthResponse {
-            user: models::users::UserResponse::from(user),
-            token,
-        }),
-    ))
+    Ok((StatusCode::CREATED, Json(issue_tokens(&state, user).await?)))
 }
 
 #[utoipa::path(
@@ -83,60 +165,471 @@ pub async fn register(
     request_body = models::users::LoginRequest,
     responses(
         (status = StatusCode::OK, description = "Login successful", body = models::users::AuthResponse),
+        (status = StatusCode::ACCEPTED, description = "Password accepted; a two-factor code is required", body = models::users::TwoFactorChallengeResponse),
         (status = StatusCode::BAD_REQUEST, description = "Invalid credentials"),
+        (status = StatusCode::TOO_MANY_REQUESTS, description = "Too many failed attempts or account locked; see Retry-After"),
         (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
     )
 )]
 pub async fn login(
     State(state): State<models::AppState>,
+    ConnectInfo(peer): ConnectInfo<std::net::SocketAddr>,
+    headers: HeaderMap,
     Json(payload): Json<models::users::LoginRequest>,
-) -> Result<Json<models::users::AuthResponse>, (StatusCode, String)> {
-    let user: models::users::User = state
+) -> Result<Response, AppError> {
+    let ip: String = utils::client_ip(&headers, peer, state.trust_forwarded_for);
+
+    let user: Option<models::users::User> = state
         .services
         .user_service
         .get_by_email(&payload.email)
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to fetch user: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to fetch user".to_string(),
-            )
-        })?
-        .ok_or((
-            StatusCode::BAD_REQUEST,
-            "Invalid email or password".to_string(),
-        ))?;
-
-    let password_valid: bool =
-        bcrypt::verify(&payload.password, &user.password_hash).map_err(|e| {
-            tracing::error!("Failed to verify password: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to verify password".to_string(),
-            )
-        })?;
+        .await?;
 
-    if !password_valid {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "Invalid email or password".to_string(),
-        ));
+    state
+        .services
+        .login_attempt_service
+        .check(&payload.email, &ip, user.as_ref())
+        .await?;
+
+    let password_valid: bool = match &user {
+        Some(user) => bcrypt::verify(&payload.password, &user.password_hash)?,
+        None => false,
+    };
+
+    let user: models::users::User = match user {
+        Some(user) if password_valid => user,
+        user => {
+            state
+                .services
+                .login_attempt_service
+                .record_failure(&payload.email, &ip, user.as_ref())
+                .await?;
+            return Err(AppError::BadRequest(
+                "Invalid email or password".to_string(),
+            ));
+        }
+    };
+
+    // With 2FA on, failures are only cleared once the second factor passes.
+    if user.totp_enabled_at.is_some() {
+        let challenge_token: String = state
+            .services
+            .two_factor_service
+            .start_challenge(user.id)
+            .await?;
+
+        return Ok((
+            StatusCode::ACCEPTED,
+            Json(models::users::TwoFactorChallengeResponse {
+                challenge_token,
+                expires_in: state.services.two_factor_service.challenge_ttl_seconds(),
+            }),
+        )
+            .into_response());
     }
 
+    state
+        .services
+        .login_attempt_service
+        .record_success(&payload.email)
+        .await?;
+
+    Ok(Json(issue_tokens(&state, user).await?).into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/2fa/verify",
+    tag = "auth",
+    request_body = models::users::TwoFactorVerifyRequest,
+    responses(
+        (status = StatusCode::OK, description = "Login successful", body = models::users::AuthResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid two-factor code"),
+        (status = StatusCode::UNAUTHORIZED, description = "Invalid or expired challenge token"),
+        (status = StatusCode::TOO_MANY_REQUESTS, description = "Too many failed attempts or account locked; see Retry-After"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn verify_two_factor(
+    State(state): State<models::AppState>,
+    ConnectInfo(peer): ConnectInfo<std::net::SocketAddr>,
+    headers: HeaderMap,
+    Json(payload): Json<models::users::TwoFactorVerifyRequest>,
+) -> Result<Json<models::users::AuthResponse>, AppError> {
+    let ip: String = utils::client_ip(&headers, peer, state.trust_forwarded_for);
+
+    let user: models::users::User = state
+        .services
+        .two_factor_service
+        .challenge_user(&payload.challenge_token)
+        .await?;
+
+    state
+        .services
+        .login_attempt_service
+        .check(&user.email, &ip, Some(&user))
+        .await?;
+
+    match state
+        .services
+        .two_factor_service
+        .verify_challenge(&payload.challenge_token, &user, &payload.code)
+        .await
+    {
+        Ok(()) => {}
+        Err(enums::TwoFactorError::InvalidCode) => {
+            // Wrong codes count towards the same lockout as wrong passwords.
+            state
+                .services
+                .login_attempt_service
+                .record_failure(&user.email, &ip, Some(&user))
+                .await?;
+            return Err(enums::TwoFactorError::InvalidCode.into());
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    state
+        .services
+        .login_attempt_service
+        .record_success(&user.email)
+        .await?;
+
+    Ok(Json(issue_tokens(&state, user).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/2fa/setup",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::OK, description = "Pending TOTP secret", body = models::users::TwoFactorSetupResponse),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::CONFLICT, description = "Two-factor authentication already enabled"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn setup_two_factor(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+) -> Result<Json<models::users::TwoFactorSetupResponse>, AppError> {
+    Ok(Json(
+        state.services.two_factor_service.setup(claims.sub).await?,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/2fa/confirm",
+    tag = "auth",
+    request_body = models::users::TwoFactorCodeRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::OK, description = "Two-factor authentication enabled", body = models::users::RecoveryCodesResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid two-factor code"),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::CONFLICT, description = "Already enabled or setup not started"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn confirm_two_factor(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    Json(payload): Json<models::users::TwoFactorCodeRequest>,
+) -> Result<Json<models::users::RecoveryCodesResponse>, AppError> {
+    let recovery_codes: Vec<String> = state
+        .services
+        .two_factor_service
+        .confirm(claims.sub, &payload.code)
+        .await?;
+
+    Ok(Json(models::users::RecoveryCodesResponse {
+        recovery_codes,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/refresh",
+    tag = "auth",
+    request_body = models::users::RefreshRequest,
+    responses(
+        (status = StatusCode::OK, description = "Tokens rotated", body = models::users::AuthResponse),
+        (status = StatusCode::UNAUTHORIZED, description = "Invalid, expired or reused refresh token"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn refresh(
+    State(state): State<models::AppState>,
+    Json(payload): Json<models::users::RefreshRequest>,
+) -> Result<Json<models::users::AuthResponse>, AppError> {
+    let (user, refresh_token): (models::users::User, String) = state
+        .services
+        .refresh_token_service
+        .rotate(&payload.refresh_token)
+        .await?;
+
     let token: String = utils::create_jwt_token(
         user.id,
         user.email.clone(),
+        user.role,
+        user.token_version,
         &state.jwt_secret,
-        state.jwt_expire_hours,
+        state.jwt_expire_minutes,
     )?;
 
     Ok(Json(models::users::AuthResponse {
         user: models::users::UserResponse::from(user),
         token,
+        refresh_token,
+        expires_in: state.jwt_expire_minutes * 60,
     }))
 }
 
+async fn issue_tokens(
+    state: &models::AppState,
+    user: models::users::User,
+) -> Result<models::users::AuthResponse, AppError> {
+    let token: String = utils::create_jwt_token(
+        user.id,
+        user.email.clone(),
+        user.role,
+        user.token_version,
+        &state.jwt_secret,
+        state.jwt_expire_minutes,
+    )?;
+
+    let refresh_token: String = state.services.refresh_token_service.issue(user.id).await?;
+
+    Ok(models::users::AuthResponse {
+        user: models::users::UserResponse::from(user),
+        token,
+        refresh_token,
+        expires_in: state.jwt_expire_minutes * 60,
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout",
+    tag = "auth",
+    request_body(content = Option<models::users::LogoutRequest>),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Access token revoked"),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn logout(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    payload: Option<Json<models::users::LogoutRequest>>,
+) -> Result<StatusCode, AppError> {
+    let payload: models::users::LogoutRequest =
+        payload.map(|Json(payload)| payload).unwrap_or_default();
+
+    state
+        .services
+        .session_service
+        .logout(&claims, payload.refresh_token.as_deref())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout-all",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "All sessions revoked"),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn logout_all(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+) -> Result<StatusCode, AppError> {
+    state
+        .services
+        .session_service
+        .logout_all(claims.sub)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/forgot",
+    tag = "auth",
+    request_body = models::users::ForgotPasswordRequest,
+    responses(
+        (status = StatusCode::ACCEPTED, description = "Reset token sent if the account exists"),
+        (status = StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn forgot_password(
+    State(state): State<models::AppState>,
+    ValidatedJson(payload): ValidatedJson<models::users::ForgotPasswordRequest>,
+) -> Result<StatusCode, AppError> {
+    state
+        .services
+        .password_service
+        .request_reset(&payload.email)
+        .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/reset",
+    tag = "auth",
+    request_body = models::users::ResetPasswordRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Password reset; all sessions revoked"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid, expired or used token"),
+        (status = StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn reset_password(
+    State(state): State<models::AppState>,
+    ValidatedJson(payload): ValidatedJson<models::users::ResetPasswordRequest>,
+) -> Result<StatusCode, AppError> {
+    let password_hash: String = hash_new_password(&payload.new_password)?;
+
+    state
+        .services
+        .password_service
+        .reset(&payload.token, &password_hash)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/change",
+    tag = "auth",
+    request_body = models::users::ChangePasswordRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::OK, description = "Password changed; other sessions revoked", body = models::users::AuthResponse),
+        (status = StatusCode::BAD_REQUEST, description = "Wrong current password"),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn change_password(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    ValidatedJson(payload): ValidatedJson<models::users::ChangePasswordRequest>,
+) -> Result<Json<models::users::AuthResponse>, AppError> {
+    let user: models::users::User = state
+        .services
+        .user_service
+        .get_by_id(claims.sub)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    let password_valid: bool = bcrypt::verify(&payload.current_password, &user.password_hash)?;
+
+    if !password_valid {
+        return Err(AppError::BadRequest(
+            "Current password is incorrect".to_string(),
+        ));
+    }
+
+    let password_hash: String = hash_new_password(&payload.new_password)?;
+
+    state
+        .services
+        .password_service
+        .change(user.id, &password_hash)
+        .await?;
+
+    let user: models::users::User = state
+        .services
+        .user_service
+        .get_by_id(user.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok(Json(issue_tokens(&state, user).await?))
+}
+
+fn hash_new_password(password: &str) -> Result<String, AppError> {
+    Ok(bcrypt::hash(password, 12)?)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/verify-email",
+    tag = "auth",
+    request_body = models::users::VerifyEmailRequest,
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Email verified"),
+        (status = StatusCode::BAD_REQUEST, description = "Invalid, expired or used token"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn verify_email(
+    State(state): State<models::AppState>,
+    Json(payload): Json<models::users::VerifyEmailRequest>,
+) -> Result<StatusCode, AppError> {
+    state
+        .services
+        .email_verification_service
+        .verify(&payload.token)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/verify-email/resend",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::ACCEPTED, description = "Verification token sent"),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::CONFLICT, description = "Email already verified"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn resend_verification(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+) -> Result<StatusCode, AppError> {
+    let user: models::users::User = state
+        .services
+        .user_service
+        .get_by_id(claims.sub)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    if user.email_verified_at.is_some() {
+        return Err(AppError::Conflict("Email already verified".to_string()));
+    }
+
+    state
+        .services
+        .email_verification_service
+        .issue(&user)
+        .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
 #[utoipa::path(
     get,
     path = "/auth/profile",
@@ -150,30 +643,14 @@ pub async fn login(
 )]
 pub async fn profile(
     State(state): State<models::AppState>,
-    headers: HeaderMap,
-) -> Result<Json<models::users::UserResponse>, (StatusCode, String)> {
-    let token: &str = headers
-        .get("authorization")
-        .and_then(|h| h.to_str().ok())
-        .and_then(|h| h.strip_prefix("Bearer "))
-        .ok_or((StatusCode::UNAUTHORIZED, "Missing token".to_string()))?;
-
-    let claims: models::users::Claims = utils::extract_user_from_token(token, &state.jwt_secret)
-        .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid token".to_string()))?;
-
+    Extension(claims): Extension<models::users::Claims>,
+) -> Result<Json<models::users::UserResponse>, AppError> {
     let user: models::users::User = state
         .services
         .user_service
         .get_by_id(claims.sub)
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to fetch user: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to fetch user".to_string(),
-            )
-        })?
-        .ok_or((StatusCode::NOT_FOUND, "User not found".to_string()))?;
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
     Ok(Json(models::users::UserResponse::from(user)))
 }
@@ -187,36 +664,147 @@ pub async fn profile(
     responses(
         (status = StatusCode::OK, description = "Profile updated", body = models::users::UserResponse),
         (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed", body = errors::ProblemDetails, content_type = "application/problem+json"),
         (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
     )
 )]
 pub async fn update_profile(
     State(state): State<models::AppState>,
-    headers: HeaderMap,
-    Json(payload): Json<models::users::UpdateUserRequest>,
-) -> Result<Json<models::users::UserResponse>, (StatusCode, String)> {
-    let token: &str = headers
-        .get("authorization")
-        .and_then(|h| h.to_str().ok())
-        .and_then(|h| h.strip_prefix("Bearer "))
-        .ok_or((StatusCode::UNAUTHORIZED, "Missing token".to_string()))?;
+    Extension(claims): Extension<models::users::Claims>,
+    ValidatedJson(payload): ValidatedJson<models::users::UpdateUserRequest>,
+) -> Result<Json<models::users::UserResponse>, AppError> {
+    let user: models::users::User = state
+        .services
+        .user_service
+        .update(claims.sub, payload.first_name, payload.last_name)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-    let claims: models::users::Claims = utils::extract_user_from_token(token, &state.jwt_secret)
-        .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid token".to_string()))?;
+    Ok(Json(models::users::UserResponse::from(user)))
+}
 
+#[utoipa::path(
+    patch,
+    path = "/auth/profile",
+    tag = "auth",
+    request_body(content = models::users::PatchUserRequest, content_type = "application/merge-patch+json"),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::OK, description = "Profile updated", body = models::users::UserResponse),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::UNPROCESSABLE_ENTITY, description = "Validation failed or a name set to null", body = errors::ProblemDetails, content_type = "application/problem+json"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn patch_profile(
+    State(state): State<models::AppState>,
+    Extension(claims): Extension<models::users::Claims>,
+    ValidatedJson(payload): ValidatedJson<models::users::PatchUserRequest>,
+) -> Result<Json<models::users::UserResponse>, AppError> {
     let user: models::users::User = state
         .services
         .user_service
-        .update(claims.sub, payload.first_name, payload.last_name)
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to update user: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to update user".to_string(),
-            )
-        })?
-        .ok_or((StatusCode::NOT_FOUND, "User not found".to_string()))?;
+        .patch(
+            claims.sub,
+            payload.first_name.into_option(),
+            payload.last_name.into_option(),
+        )
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
     Ok(Json(models::users::UserResponse::from(user)))
 }
+
+#[utoipa::path(
+    put,
+    path = "/admin/users/{id}/role",
+    tag = "auth",
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    request_body = models::users::UpdateRoleRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::OK, description = "Role updated; the user's existing access and refresh tokens are revoked", body = models::users::UserResponse),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::FORBIDDEN, description = "Admins only"),
+        (status = StatusCode::NOT_FOUND, description = "User not found"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn update_role(
+    State(state): State<models::AppState>,
+    _admin: middleware::RequireRole<middleware::AdminOnly>,
+    Path(id): Path<i32>,
+    Json(payload): Json<models::users::UpdateRoleRequest>,
+) -> Result<Json<models::users::UserResponse>, AppError> {
+    let user: models::users::User = state
+        .services
+        .user_service
+        .update_role(id, payload.role)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok(Json(models::users::UserResponse::from(user)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/lockouts",
+    tag = "auth",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::OK, description = "Accounts currently locked after failed logins", body = Vec<models::lockouts::LockoutResponse>),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::FORBIDDEN, description = "Admins only"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn list_lockouts(
+    State(state): State<models::AppState>,
+    _admin: middleware::RequireRole<middleware::AdminOnly>,
+) -> Result<Json<Vec<models::lockouts::LockoutResponse>>, AppError> {
+    let lockouts: Vec<models::lockouts::ActiveLockout> =
+        state.services.login_attempt_service.list_lockouts().await?;
+
+    Ok(Json(
+        lockouts
+            .into_iter()
+            .map(models::lockouts::LockoutResponse::from)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/users/{id}/lockout",
+    tag = "auth",
+    params(
+        ("id" = i32, Path, description = "User ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = StatusCode::NO_CONTENT, description = "Account unlocked"),
+        (status = StatusCode::UNAUTHORIZED, description = "Unauthorized"),
+        (status = StatusCode::FORBIDDEN, description = "Admins only"),
+        (status = StatusCode::NOT_FOUND, description = "User is not locked"),
+        (status = StatusCode::INTERNAL_SERVER_ERROR, description = "Internal server error")
+    )
+)]
+pub async fn unlock_user(
+    State(state): State<models::AppState>,
+    middleware::RequireRole(claims, _): middleware::RequireRole<middleware::AdminOnly>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, AppError> {
+    let unlocked: bool = state
+        .services
+        .login_attempt_service
+        .unlock(id, claims.sub)
+        .await?;
+
+    if !unlocked {
+        return Err(AppError::NotFound("User is not locked".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
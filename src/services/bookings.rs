@@ -0,0 +1,257 @@
+use crate::enums::{BookingError, BookingStatus};
+use crate::models::bookings::{Booking, BookingNight};
+use crate::models::holds::BookingHold;
+use crate::models::hotels::Hotel;
+use crate::models::quotes::{Quote, QuoteBreakdown};
+use crate::models::rate_plans::{CancellationPolicy, NightlyRate, StayPrice};
+use crate::models::room_types::RoomType;
+use crate::models::users::User;
+use crate::repositories::{
+    bookings::BookingRepository, hotels::HotelRepository, users::UserRepository,
+};
+use crate::services::{holds::HoldService, pricing::PricingService, quotes::QuoteService};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct BookingService {
+    repo: BookingRepository,
+    hotel_repo: HotelRepository,
+    quote_service: QuoteService,
+    pricing_service: PricingService,
+    hold_service: HoldService,
+    user_repo: UserRepository,
+    require_verified_email: bool,
+}
+
+impl BookingService {
+    pub fn new(
+        repo: BookingRepository,
+        hotel_repo: HotelRepository,
+        quote_service: QuoteService,
+        pricing_service: PricingService,
+        hold_service: HoldService,
+        user_repo: UserRepository,
+        require_verified_email: bool,
+    ) -> Self {
+        Self {
+            repo,
+            hotel_repo,
+            quote_service,
+            pricing_service,
+            hold_service,
+            user_repo,
+            require_verified_email,
+        }
+    }
+
+    pub async fn list_by_user(&self, user_id: i32) -> Result<Vec<Booking>, sqlx::Error> {
+        self.repo.list_by_user(user_id).await
+    }
+
+    pub async fn get_for_user(&self, user_id: i32, id: i32) -> Result<Booking, BookingError> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .filter(|booking| booking.user_id == user_id)
+            .ok_or(BookingError::NotFound)
+    }
+
+    pub async fn get_for_hotel(&self, hotel_id: i32, id: i32) -> Result<Booking, BookingError> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .filter(|booking| booking.hotel_id == hotel_id)
+            .ok_or(BookingError::NotFound)
+    }
+
+    pub async fn list_nights(&self, booking_id: i32) -> Result<Vec<NightlyRate>, sqlx::Error> {
+        let nights: Vec<BookingNight> = self.repo.list_nights(booking_id).await?;
+
+        Ok(nights.into_iter().map(NightlyRate::from).collect())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        room_type_id: i32,
+        rate_plan_id: Option<i32>,
+        quote_id: Option<Uuid>,
+        hold_id: Option<Uuid>,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<(Booking, Vec<NightlyRate>), BookingError> {
+        let user: User = self
+            .user_repo
+            .get_by_id(user_id)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        if self.require_verified_email && user.email_verified_at.is_none() {
+            return Err(BookingError::EmailNotVerified);
+        }
+
+        let room_type: RoomType = self
+            .quote_service
+            .validate_stay(hotel_id, room_type_id, check_in, check_out, guests)
+            .await?;
+
+        if let Some(hold_id) = hold_id {
+            let hold: BookingHold = self.hold_service.get_active(user_id, hold_id).await?;
+            if (
+                hold.hotel_id,
+                hold.room_type_id,
+                hold.check_in,
+                hold.check_out,
+                hold.guests,
+            ) != (hotel_id, room_type_id, check_in, check_out, guests)
+            {
+                return Err(BookingError::HoldMismatch);
+            }
+        }
+
+        let (price, total_price): (StayPrice, Decimal) = match quote_id {
+            Some(quote_id) => {
+                let quote: Quote = self.quote_service.get_unused(user_id, quote_id).await?;
+                if (
+                    quote.hotel_id,
+                    quote.room_type_id,
+                    quote.rate_plan_id,
+                    quote.check_in,
+                    quote.check_out,
+                    quote.guests,
+                ) != (
+                    hotel_id,
+                    room_type_id,
+                    rate_plan_id,
+                    check_in,
+                    check_out,
+                    guests,
+                ) {
+                    return Err(BookingError::QuoteMismatch);
+                }
+                (quote.stay_price(), quote.total_price)
+            }
+            None => {
+                let breakdown: QuoteBreakdown = self
+                    .quote_service
+                    .price_stay(&room_type, rate_plan_id, check_in, check_out, guests)
+                    .await?;
+                (breakdown.price, breakdown.total_price)
+            }
+        };
+
+        let cancellation_policy: CancellationPolicy = self
+            .pricing_service
+            .cancellation_policy(room_type_id, price.rate_plan_id)
+            .await?;
+
+        let booking: Booking = self
+            .repo
+            .create_if_available(
+                user_id,
+                hotel_id,
+                room_type_id,
+                guests,
+                &price,
+                total_price,
+                quote_id,
+                hold_id,
+                &cancellation_policy,
+                &user.email,
+            )
+            .await?;
+
+        if let Some(hold_id) = hold_id {
+            self.hold_service.forget(hold_id).await;
+        }
+
+        Ok((booking, price.nights))
+    }
+
+    /// Moves a booking through the hotel-side lifecycle on behalf of the
+    /// hotel's staff. Check-in and no-show are only accepted from the arrival
+    /// date on, as seen in the hotel's timezone.
+    pub async fn transition(
+        &self,
+        hotel_id: i32,
+        id: i32,
+        to: BookingStatus,
+    ) -> Result<Booking, BookingError> {
+        let booking: Booking = self.get_for_hotel(hotel_id, id).await?;
+
+        if !booking.status.can_transition_to(to) {
+            return Err(BookingError::InvalidTransition {
+                from: booking.status,
+                to,
+            });
+        }
+
+        if matches!(to, BookingStatus::CheckedIn | BookingStatus::NoShow) {
+            let hotel: Hotel = self
+                .hotel_repo
+                .find_by_id(hotel_id)
+                .await?
+                .ok_or(BookingError::NotFound)?;
+
+            if hotel.today() < booking.check_in {
+                return Err(BookingError::BeforeArrival(to));
+            }
+        }
+
+        self.repo
+            .update_status(id, booking.status, to)
+            .await?
+            .ok_or(BookingError::InvalidTransition {
+                from: booking.status,
+                to,
+            })
+    }
+
+    pub async fn cancel(&self, user_id: i32, id: i32) -> Result<Booking, BookingError> {
+        let booking: Booking = self.get_for_user(user_id, id).await?;
+        let to: BookingStatus = BookingStatus::Cancelled;
+
+        if !booking.status.can_transition_to(to) {
+            return Err(BookingError::InvalidTransition {
+                from: booking.status,
+                to,
+            });
+        }
+
+        let hotel: Hotel = self
+            .hotel_repo
+            .find_by_id(booking.hotel_id)
+            .await?
+            .ok_or(BookingError::NotFound)?;
+        let first_night: Decimal = self
+            .repo
+            .list_nights(booking.id)
+            .await?
+            .first()
+            .map_or(booking.total_price, |night| night.price);
+
+        let penalty_amount: Decimal = booking.cancellation_policy().penalty(
+            booking.total_price,
+            first_night,
+            hotel.check_in_at(booking.check_in),
+            chrono::Utc::now(),
+        );
+
+        self.repo
+            .cancel(
+                id,
+                booking.status,
+                penalty_amount,
+                booking.total_price - penalty_amount,
+            )
+            .await?
+            .ok_or(BookingError::InvalidTransition {
+                from: booking.status,
+                to,
+            })
+    }
+}
@@ -0,0 +1,41 @@
+use crate::enums::EmailVerificationError;
+use crate::models::emails::{NewEmail, NewEmailVerification};
+use crate::models::users::User;
+use crate::repositories::email_verifications::EmailVerificationRepository;
+use crate::utils;
+
+#[derive(Clone)]
+pub struct EmailVerificationService {
+    repo: EmailVerificationRepository,
+    ttl_hours: i64,
+}
+
+impl EmailVerificationService {
+    pub fn new(repo: EmailVerificationRepository, ttl_hours: i64) -> Self {
+        Self { repo, ttl_hours }
+    }
+
+    /// A fresh verification token for `recipient`, with the email that
+    /// delivers it, ready to store alongside the user.
+    pub fn prepare(&self, recipient: &str) -> NewEmailVerification {
+        let token: String = utils::generate_token();
+
+        NewEmailVerification {
+            token_hash: utils::hash_token(&token),
+            expires_at: chrono::Utc::now().naive_utc() + chrono::Duration::hours(self.ttl_hours),
+            email: NewEmail::email_verification(recipient, &token, self.ttl_hours),
+        }
+    }
+
+    /// Emails a fresh verification token to the user.
+    pub async fn issue(&self, user: &User) -> Result<(), sqlx::Error> {
+        self.repo.create(user.id, &self.prepare(&user.email)).await
+    }
+
+    pub async fn verify(&self, token: &str) -> Result<i32, EmailVerificationError> {
+        self.repo
+            .consume(&utils::hash_token(token), chrono::Utc::now().naive_utc())
+            .await?
+            .ok_or(EmailVerificationError::InvalidToken)
+    }
+}
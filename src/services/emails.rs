@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::mailer::Mailer;
+use crate::models::emails::{self, OutboxEmail};
+use crate::repositories::email_outbox::EmailOutboxRepository;
+
+const DELIVERY_BATCH_SIZE: i64 = 50;
+/// How long a claimed email stays invisible to other workers while it is sent.
+const DELIVERY_LEASE_SECONDS: i64 = 120;
+
+#[derive(Clone)]
+pub struct EmailService {
+    repo: EmailOutboxRepository,
+    mailer: Arc<dyn Mailer>,
+    max_attempts: i32,
+}
+
+impl EmailService {
+    pub fn new(repo: EmailOutboxRepository, mailer: Arc<dyn Mailer>, max_attempts: i32) -> Self {
+        Self {
+            repo,
+            mailer,
+            max_attempts,
+        }
+    }
+
+    /// Sends every email that is due, returning how many were delivered.
+    pub async fn deliver_due(&self) -> Result<usize, sqlx::Error> {
+        let mut delivered: usize = 0;
+
+        loop {
+            let now: chrono::NaiveDateTime = chrono::Utc::now().naive_utc();
+            let batch: Vec<OutboxEmail> = self
+                .repo
+                .claim_due(
+                    now,
+                    now + chrono::Duration::seconds(DELIVERY_LEASE_SECONDS),
+                    DELIVERY_BATCH_SIZE,
+                )
+                .await?;
+
+            for email in &batch {
+                if self.deliver(email).await? {
+                    delivered += 1;
+                }
+            }
+
+            if (batch.len() as i64) < DELIVERY_BATCH_SIZE {
+                return Ok(delivered);
+            }
+        }
+    }
+
+    async fn deliver(&self, email: &OutboxEmail) -> Result<bool, sqlx::Error> {
+        match self.mailer.send(email).await {
+            Ok(()) => {
+                self.repo
+                    .mark_sent(email.id, chrono::Utc::now().naive_utc())
+                    .await?;
+                Ok(true)
+            }
+            Err(e) => {
+                let attempts: i32 = email.attempts + 1;
+                let next_attempt_at: Option<chrono::NaiveDateTime> = (attempts < self.max_attempts)
+                    .then(|| chrono::Utc::now().naive_utc() + emails::retry_delay(attempts));
+
+                match next_attempt_at {
+                    Some(at) => tracing::warn!(
+                        "Failed to send email {} (attempt {}), retrying at {}: {}",
+                        email.id,
+                        attempts,
+                        at,
+                        e
+                    ),
+                    None => tracing::error!(
+                        "Giving up on email {} after {} attempts: {}",
+                        email.id,
+                        attempts,
+                        e
+                    ),
+                }
+
+                self.repo
+                    .mark_failed(email.id, &e.to_string(), next_attempt_at)
+                    .await?;
+                Ok(false)
+            }
+        }
+    }
+
+    pub fn spawn_worker(self, interval_seconds: u64) {
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(interval_seconds.max(1)));
+
+            loop {
+                interval.tick().await;
+
+                match self.deliver_due().await {
+                    Ok(0) => {}
+                    Ok(delivered) => tracing::info!("Delivered {} emails", delivered),
+                    Err(e) => tracing::error!("Failed to deliver emails: {}", e),
+                }
+            }
+        });
+    }
+}
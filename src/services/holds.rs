@@ -0,0 +1,137 @@
+use crate::enums::{BookingError, HoldStatus};
+use crate::models::holds::BookingHold;
+use crate::repositories::holds::HoldRepository;
+use crate::services::quotes::QuoteService;
+use uuid::Uuid;
+
+const SWEEP_BATCH_SIZE: i64 = 100;
+
+#[derive(Clone)]
+pub struct HoldService {
+    repo: HoldRepository,
+    quote_service: QuoteService,
+    ttl_minutes: i64,
+}
+
+impl HoldService {
+    pub fn new(repo: HoldRepository, quote_service: QuoteService, ttl_minutes: i64) -> Self {
+        Self {
+            repo,
+            quote_service,
+            ttl_minutes,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        room_type_id: i32,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<BookingHold, BookingError> {
+        self.quote_service
+            .validate_stay(hotel_id, room_type_id, check_in, check_out, guests)
+            .await?;
+
+        let expires_at: chrono::NaiveDateTime =
+            chrono::Utc::now().naive_utc() + chrono::Duration::minutes(self.ttl_minutes);
+        let hold: BookingHold = self
+            .repo
+            .create(
+                user_id,
+                hotel_id,
+                room_type_id,
+                check_in,
+                check_out,
+                guests,
+                expires_at,
+            )
+            .await?;
+
+        if let Err(e) = self.repo.set_marker(hold.id, self.ttl_minutes * 60).await {
+            self.repo.release(hold.id, HoldStatus::Released).await?;
+            return Err(e.into());
+        }
+
+        Ok(hold)
+    }
+
+    pub async fn get_for_user(&self, user_id: i32, id: Uuid) -> Result<BookingHold, BookingError> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .filter(|hold| hold.user_id == user_id)
+            .ok_or(BookingError::HoldNotFound)
+    }
+
+    pub async fn get_active(&self, user_id: i32, id: Uuid) -> Result<BookingHold, BookingError> {
+        let hold: BookingHold = self.get_for_user(user_id, id).await?;
+
+        if hold.status != HoldStatus::Active
+            || hold.expires_at <= chrono::Utc::now().naive_utc()
+            || !self.repo.marker_exists(id).await?
+        {
+            return Err(BookingError::HoldExpired);
+        }
+
+        Ok(hold)
+    }
+
+    pub async fn release(&self, user_id: i32, id: Uuid) -> Result<BookingHold, BookingError> {
+        self.get_for_user(user_id, id).await?;
+
+        let hold: BookingHold = self
+            .repo
+            .release(id, HoldStatus::Released)
+            .await?
+            .ok_or(BookingError::HoldExpired)?;
+        self.forget(id).await;
+
+        Ok(hold)
+    }
+
+    pub async fn forget(&self, id: Uuid) {
+        if let Err(e) = self.repo.delete_marker(id).await {
+            tracing::warn!("Failed to delete hold marker {}: {}", id, e);
+        }
+    }
+
+    pub async fn release_expired(&self) -> Result<usize, sqlx::Error> {
+        let mut released: usize = 0;
+
+        loop {
+            let holds: Vec<BookingHold> = self
+                .repo
+                .release_expired(chrono::Utc::now().naive_utc(), SWEEP_BATCH_SIZE)
+                .await?;
+            released += holds.len();
+
+            for hold in &holds {
+                self.forget(hold.id).await;
+            }
+
+            if (holds.len() as i64) < SWEEP_BATCH_SIZE {
+                return Ok(released);
+            }
+        }
+    }
+
+    pub fn spawn_sweeper(self, interval_seconds: u64) {
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(interval_seconds.max(1)));
+
+            loop {
+                interval.tick().await;
+
+                match self.release_expired().await {
+                    Ok(0) => {}
+                    Ok(released) => tracing::info!("Released {} expired holds", released),
+                    Err(e) => tracing::error!("Failed to release expired holds: {}", e),
+                }
+            }
+        });
+    }
+}
@@ -1,24 +1,131 @@
-use crate::models::hotels::Hotel;
-use crate::repositories::hotels::HotelRepository;
+use crate::enums::BookingError;
+use crate::enums::{HotelSort, SortOrder};
+use crate::models::Patch;
+use crate::models::hotels::{Hotel, HotelAvailability, HotelCursor, HotelOffer, HotelSearchHit};
+use crate::models::quotes::QuoteBreakdown;
+use crate::models::room_types::RoomType;
+use crate::repositories::{hotels::HotelRepository, room_types::RoomTypeRepository};
+use crate::services::quotes::QuoteService;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct HotelService {
     repo: HotelRepository,
+    room_type_repo: RoomTypeRepository,
+    quote_service: QuoteService,
 }
 
 impl HotelService {
-    pub fn new(repo: HotelRepository) -> Self {
-        Self { repo }
+    pub fn new(
+        repo: HotelRepository,
+        room_type_repo: RoomTypeRepository,
+        quote_service: QuoteService,
+    ) -> Self {
+        Self {
+            repo,
+            room_type_repo,
+            quote_service,
+        }
     }
 
-    pub async fn list_all(&self) -> Result<Vec<Hotel>, sqlx::Error> {
-        self.repo.list_all().await
+    /// Returns one page of hotels and the number matching the filters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list(
+        &self,
+        city: Option<String>,
+        country: Option<String>,
+        min_rating: Option<Decimal>,
+        sort: HotelSort,
+        order: SortOrder,
+        after: Option<HotelCursor>,
+        limit: i64,
+    ) -> Result<(Vec<Hotel>, i64), sqlx::Error> {
+        let hotels: Vec<Hotel> = self
+            .repo
+            .list(
+                city.clone(),
+                country.clone(),
+                min_rating,
+                sort,
+                order,
+                after,
+                limit,
+            )
+            .await?;
+        let total: i64 = self.repo.count(city, country, min_rating).await?;
+
+        Ok((hotels, total))
+    }
+
+    /// Hotels with a room type bookable for the whole stay, each with its
+    /// cheapest offer, cheapest first.
+    pub async fn search_available(
+        &self,
+        city: Option<String>,
+        country: Option<String>,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<Vec<HotelOffer>, BookingError> {
+        let available: Vec<HotelAvailability> = self
+            .repo
+            .search_available(city, country, check_in, check_out, guests)
+            .await?;
+
+        let room_type_ids: Vec<i32> = available
+            .iter()
+            .flat_map(|availability| availability.room_type_ids.iter().copied())
+            .collect();
+        let room_types: Vec<RoomType> = self.room_type_repo.list_by_ids(&room_type_ids).await?;
+        let mut cheapest: HashMap<i32, (i32, QuoteBreakdown)> = self
+            .quote_service
+            .cheapest_stays(&room_types, check_in, check_out, guests)
+            .await?;
+
+        let mut offers: Vec<HotelOffer> = available
+            .into_iter()
+            .filter_map(|availability| {
+                let (room_type_id, breakdown) = cheapest.remove(&availability.hotel.id)?;
+                Some(HotelOffer {
+                    hotel: availability.hotel,
+                    room_type_id,
+                    breakdown,
+                })
+            })
+            .collect();
+
+        offers.sort_by(|a, b| {
+            a.breakdown
+                .total_price
+                .cmp(&b.breakdown.total_price)
+                .then(a.hotel.id.cmp(&b.hotel.id))
+        });
+
+        Ok(offers)
+    }
+
+    /// Ranked full-text matches, falling back to similar names and places
+    /// when nothing matches. The flag is true for fallback results.
+    pub async fn search(
+        &self,
+        query: &str,
+        language: &str,
+        limit: i64,
+    ) -> Result<(Vec<HotelSearchHit>, bool), sqlx::Error> {
+        let hits: Vec<HotelSearchHit> = self.repo.search(query, language, limit).await?;
+        if !hits.is_empty() {
+            return Ok((hits, false));
+        }
+
+        Ok((self.repo.search_similar(query, limit).await?, true))
     }
 
     pub async fn get_by_id(&self, id: i32) -> Result<Option<Hotel>, sqlx::Error> {
         self.repo.find_by_id(id).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
         &self,
         name: String,
@@ -26,23 +133,79 @@ impl HotelService {
         address: String,
         city: String,
         country: String,
+        timezone: Option<String>,
+        check_in_time: Option<chrono::NaiveTime>,
+        language: Option<String>,
+        owner_id: i32,
     ) -> Result<Hotel, sqlx::Error> {
         self.repo
-            .create(name, description, address, city, country)
+            .create(
+                name,
+                description,
+                address,
+                city,
+                country,
+                timezone,
+                check_in_time,
+                language,
+                owner_id,
+            )
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update(
         &self,
         id: i32,
-        name: Option<String>,
+        name: String,
         description: Option<String>,
+        address: String,
+        city: String,
+        country: String,
+        timezone: Option<String>,
+        check_in_time: Option<chrono::NaiveTime>,
+        language: Option<String>,
+    ) -> Result<Option<Hotel>, sqlx::Error> {
+        self.repo
+            .update(
+                id,
+                name,
+                description,
+                address,
+                city,
+                country,
+                timezone,
+                check_in_time,
+                language,
+            )
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn patch(
+        &self,
+        id: i32,
+        name: Option<String>,
+        description: Patch<String>,
         address: Option<String>,
         city: Option<String>,
         country: Option<String>,
+        timezone: Option<String>,
+        check_in_time: Option<chrono::NaiveTime>,
+        language: Option<String>,
     ) -> Result<Option<Hotel>, sqlx::Error> {
         self.repo
-            .update(id, name, description, address, city, country)
+            .patch(
+                id,
+                name,
+                description,
+                address,
+                city,
+                country,
+                timezone,
+                check_in_time,
+                language,
+            )
             .await
     }
 
@@ -0,0 +1,51 @@
+use crate::models::inventory::{CalendarDay, CalendarDayResponse, RoomTypeCalendarResponse};
+use crate::repositories::inventory::InventoryRepository;
+
+#[derive(Clone)]
+pub struct InventoryService {
+    repo: InventoryRepository,
+}
+
+impl InventoryService {
+    pub fn new(repo: InventoryRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn calendar(
+        &self,
+        hotel_id: i32,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<RoomTypeCalendarResponse>, sqlx::Error> {
+        let days: Vec<CalendarDay> = self.repo.calendar(hotel_id, from, to).await?;
+
+        let mut calendars: Vec<RoomTypeCalendarResponse> = Vec::new();
+        for day in days {
+            match calendars.last_mut() {
+                Some(calendar) if calendar.room_type_id == day.room_type_id => {
+                    calendar.days.push(CalendarDayResponse::from(day));
+                }
+                _ => calendars.push(RoomTypeCalendarResponse {
+                    room_type_id: day.room_type_id,
+                    room_type_name: day.room_type_name.clone(),
+                    days: vec![CalendarDayResponse::from(day)],
+                }),
+            }
+        }
+
+        Ok(calendars)
+    }
+
+    pub async fn update_restrictions(
+        &self,
+        room_type_id: i32,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        stop_sell: Option<bool>,
+        closed_to_arrival: Option<bool>,
+    ) -> Result<u64, sqlx::Error> {
+        self.repo
+            .update_restrictions(room_type_id, from, to, stop_sell, closed_to_arrival)
+            .await
+    }
+}
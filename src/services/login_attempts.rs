@@ -0,0 +1,156 @@
+use crate::enums::LoginThrottleError;
+use crate::models::lockouts::{AccountLockout, ActiveLockout};
+use crate::models::users::User;
+use crate::repositories::{login_attempts::LoginAttemptRepository, users::UserRepository};
+use crate::utils;
+
+#[derive(Clone)]
+pub struct LoginAttemptService {
+    repo: LoginAttemptRepository,
+    user_repo: UserRepository,
+    window_seconds: i64,
+    max_failures_per_email: i64,
+    max_failures_per_ip: i64,
+    lockout_minutes: i64,
+}
+
+impl LoginAttemptService {
+    pub fn new(
+        repo: LoginAttemptRepository,
+        user_repo: UserRepository,
+        window_seconds: i64,
+        max_failures_per_email: i64,
+        max_failures_per_ip: i64,
+        lockout_minutes: i64,
+    ) -> Self {
+        Self {
+            repo,
+            user_repo,
+            window_seconds,
+            max_failures_per_email,
+            max_failures_per_ip,
+            lockout_minutes,
+        }
+    }
+
+    /// Rejects a login attempt before its password is checked if the address
+    /// or email has failed too often recently, or the account is locked.
+    pub async fn check(
+        &self,
+        email: &str,
+        ip: &str,
+        user: Option<&User>,
+    ) -> Result<(), LoginThrottleError> {
+        let now: chrono::NaiveDateTime = chrono::Utc::now().naive_utc();
+
+        self.check_window(&ip_key(ip), self.max_failures_per_ip)
+            .await?;
+
+        if let Some(user) = user
+            && let Some(lockout) = self.repo.find_active_lockout(user.id, now).await?
+        {
+            return Err(LoginThrottleError::AccountLocked {
+                retry_after_seconds: (lockout.locked_until - now).num_seconds().max(1),
+            });
+        }
+
+        self.check_window(&email_key(email), self.max_failures_per_email)
+            .await
+    }
+
+    /// Counts a failed login against the address and email, locking the
+    /// account once the email reaches its limit.
+    pub async fn record_failure(
+        &self,
+        email: &str,
+        ip: &str,
+        user: Option<&User>,
+    ) -> Result<(), LoginThrottleError> {
+        let now_ms: i64 = chrono::Utc::now().timestamp_millis();
+        let window_ms: i64 = self.window_seconds * 1000;
+        let member: String = format!("{}:{}", now_ms, &utils::generate_token()[..8]);
+
+        self.repo
+            .record_failure(&ip_key(ip), &member, now_ms, window_ms)
+            .await?;
+        let failures: i64 = self
+            .repo
+            .record_failure(&email_key(email), &member, now_ms, window_ms)
+            .await?;
+
+        if let Some(user) = user
+            && failures >= self.max_failures_per_email
+        {
+            let lockout: AccountLockout = self
+                .repo
+                .create_lockout(
+                    user.id,
+                    chrono::Utc::now().naive_utc()
+                        + chrono::Duration::minutes(self.lockout_minutes),
+                    failures as i32,
+                    ip,
+                )
+                .await?;
+            tracing::warn!(
+                "Locked user {} until {} after {} failed logins",
+                user.id,
+                lockout.locked_until,
+                failures
+            );
+        }
+
+        Ok(())
+    }
+
+    pub async fn record_success(&self, email: &str) -> Result<(), redis::RedisError> {
+        self.repo.clear_failures(&email_key(email)).await
+    }
+
+    pub async fn list_lockouts(&self) -> Result<Vec<ActiveLockout>, sqlx::Error> {
+        self.repo
+            .list_active_lockouts(chrono::Utc::now().naive_utc())
+            .await
+    }
+
+    /// Lifts the user's active locks and forgets their recent failures.
+    /// Returns `false` if the user was not locked.
+    pub async fn unlock(&self, user_id: i32, admin_id: i32) -> Result<bool, LoginThrottleError> {
+        let unlocked: u64 = self
+            .repo
+            .unlock(user_id, admin_id, chrono::Utc::now().naive_utc())
+            .await?;
+
+        if unlocked == 0 {
+            return Ok(false);
+        }
+
+        if let Some(user) = self.user_repo.get_by_id(user_id).await? {
+            self.repo.clear_failures(&email_key(&user.email)).await?;
+        }
+
+        Ok(true)
+    }
+
+    async fn check_window(&self, key: &str, max_failures: i64) -> Result<(), LoginThrottleError> {
+        let now_ms: i64 = chrono::Utc::now().timestamp_millis();
+        let window_ms: i64 = self.window_seconds * 1000;
+
+        if self.repo.count_failures(key, now_ms, window_ms).await? < max_failures {
+            return Ok(());
+        }
+
+        let oldest_ms: i64 = self.repo.oldest_failure(key).await?.unwrap_or(now_ms);
+
+        Err(LoginThrottleError::TooManyAttempts {
+            retry_after_seconds: ((oldest_ms + window_ms - now_ms) / 1000).max(1),
+        })
+    }
+}
+
+fn email_key(email: &str) -> String {
+    format!("login_failures:email:{}", email.trim().to_lowercase())
+}
+
+fn ip_key(ip: &str) -> String {
+    format!("login_failures:ip:{}", ip)
+}
@@ -1,33 +1,217 @@
+pub mod bookings;
+pub mod email_verifications;
+pub mod emails;
 pub mod health;
+pub mod holds;
 pub mod hotels;
+pub mod inventory;
+pub mod login_attempts;
+pub mod passwords;
+pub mod pricing;
+pub mod quotes;
+pub mod rate_limits;
+pub mod rate_plans;
+pub mod refresh_tokens;
+pub mod reviews;
+pub mod room_types;
+pub mod rooms;
+pub mod sessions;
+pub mod staff;
+pub mod taxes;
+pub mod two_factor;
 pub mod users;
 
+pub use bookings::BookingService;
+pub use email_verifications::EmailVerificationService;
+pub use emails::EmailService;
 pub use health::HealthService;
+pub use holds::HoldService;
 pub use hotels::HotelService;
+pub use inventory::InventoryService;
+pub use login_attempts::LoginAttemptService;
+pub use passwords::PasswordService;
+pub use pricing::PricingService;
+pub use quotes::QuoteService;
+pub use rate_limits::RateLimitService;
+pub use rate_plans::RatePlanService;
+pub use refresh_tokens::RefreshTokenService;
+pub use reviews::ReviewService;
+pub use room_types::RoomTypeService;
+pub use rooms::RoomService;
+pub use sessions::SessionService;
+pub use staff::StaffService;
+pub use taxes::TaxService;
+pub use two_factor::TwoFactorService;
 pub use users::UserService;
 
+use crate::models::rate_limits::RateLimit;
 use crate::repositories::{
-    health::HealthRepository, hotels::HotelRepository, users::UserRepository,
+    bookings::BookingRepository, email_outbox::EmailOutboxRepository,
+    email_verifications::EmailVerificationRepository, health::HealthRepository,
+    holds::HoldRepository, hotels::HotelRepository, inventory::InventoryRepository,
+    login_attempts::LoginAttemptRepository, password_resets::PasswordResetRepository,
+    quotes::QuoteRepository, rate_limits::RateLimitRepository, rate_plans::RatePlanRepository,
+    refresh_tokens::RefreshTokenRepository, reviews::ReviewRepository,
+    room_types::RoomTypeRepository, rooms::RoomRepository, sessions::SessionRepository,
+    staff::StaffRepository, taxes::TaxRepository, two_factor::TwoFactorRepository,
+    users::UserRepository,
 };
+use crate::{mailer::Mailer, settings};
 use redis::aio::MultiplexedConnection;
 use sqlx::{Pool, Postgres};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Services {
+    pub booking_service: BookingService,
+    pub email_service: EmailService,
+    pub email_verification_service: EmailVerificationService,
     pub health_service: HealthService,
+    pub hold_service: HoldService,
     pub hotel_service: HotelService,
+    pub inventory_service: InventoryService,
+    pub login_attempt_service: LoginAttemptService,
+    pub password_service: PasswordService,
+    pub pricing_service: PricingService,
+    pub quote_service: QuoteService,
+    pub rate_limit_service: RateLimitService,
+    pub rate_plan_service: RatePlanService,
+    pub refresh_token_service: RefreshTokenService,
+    pub review_service: ReviewService,
+    pub room_type_service: RoomTypeService,
+    pub room_service: RoomService,
+    pub session_service: SessionService,
+    pub staff_service: StaffService,
+    pub tax_service: TaxService,
+    pub two_factor_service: TwoFactorService,
     pub user_service: UserService,
 }
 
 impl Services {
-    pub fn new(pool: Pool<Postgres>, redis_conn: MultiplexedConnection) -> Self {
+    pub fn new(
+        pool: Pool<Postgres>,
+        redis_conn: MultiplexedConnection,
+        mailer: Arc<dyn Mailer>,
+        settings: &settings::Settings,
+    ) -> Self {
+        let pricing_service: PricingService = PricingService::new(
+            RoomTypeRepository::new(pool.clone()),
+            RatePlanRepository::new(pool.clone()),
+        );
+
+        let quote_service: QuoteService = QuoteService::new(
+            QuoteRepository::new(pool.clone()),
+            TaxRepository::new(pool.clone()),
+            RoomTypeRepository::new(pool.clone()),
+            pricing_service.clone(),
+            settings.quote_ttl_minutes,
+        );
+
+        let hold_service: HoldService = HoldService::new(
+            HoldRepository::new(pool.clone(), redis_conn.clone()),
+            quote_service.clone(),
+            settings.hold_ttl_minutes,
+        );
+
+        let session_service: SessionService = SessionService::new(
+            SessionRepository::new(pool.clone(), redis_conn.clone()),
+            RefreshTokenRepository::new(pool.clone()),
+        );
+
+        let email_verification_service: EmailVerificationService = EmailVerificationService::new(
+            EmailVerificationRepository::new(pool.clone()),
+            settings.email_verification_ttl_hours,
+        );
+
         Self {
+            booking_service: BookingService::new(
+                BookingRepository::new(pool.clone()),
+                HotelRepository::new(pool.clone()),
+                quote_service.clone(),
+                pricing_service.clone(),
+                hold_service.clone(),
+                UserRepository::new(pool.clone()),
+                settings.require_verified_email_for_bookings,
+            ),
+            email_service: EmailService::new(
+                EmailOutboxRepository::new(pool.clone()),
+                mailer,
+                settings.email_max_attempts,
+            ),
+            email_verification_service: email_verification_service.clone(),
             health_service: HealthService::new(HealthRepository::new(
                 pool.clone(),
                 redis_conn.clone(),
             )),
-            hotel_service: HotelService::new(HotelRepository::new(pool.clone())),
-            user_service: UserService::new(UserRepository::new(pool.clone())),
+            hold_service,
+            hotel_service: HotelService::new(
+                HotelRepository::new(pool.clone()),
+                RoomTypeRepository::new(pool.clone()),
+                quote_service.clone(),
+            ),
+            inventory_service: InventoryService::new(InventoryRepository::new(pool.clone())),
+            login_attempt_service: LoginAttemptService::new(
+                LoginAttemptRepository::new(pool.clone(), redis_conn.clone()),
+                UserRepository::new(pool.clone()),
+                settings.login_failure_window_seconds,
+                settings.login_max_failures_per_email,
+                settings.login_max_failures_per_ip,
+                settings.login_lockout_minutes,
+            ),
+            password_service: PasswordService::new(
+                PasswordResetRepository::new(pool.clone()),
+                UserRepository::new(pool.clone()),
+                session_service.clone(),
+                settings.password_reset_ttl_minutes,
+            ),
+            pricing_service,
+            quote_service,
+            rate_limit_service: RateLimitService::new(
+                RateLimitRepository::new(redis_conn.clone()),
+                settings.rate_limit_enabled,
+                RateLimit {
+                    capacity: settings.rate_limit_auth_capacity,
+                    per_minute: settings.rate_limit_auth_per_minute,
+                },
+                RateLimit {
+                    capacity: settings.rate_limit_search_capacity,
+                    per_minute: settings.rate_limit_search_per_minute,
+                },
+                RateLimit {
+                    capacity: settings.rate_limit_mutations_capacity,
+                    per_minute: settings.rate_limit_mutations_per_minute,
+                },
+            ),
+            rate_plan_service: RatePlanService::new(RatePlanRepository::new(pool.clone())),
+            refresh_token_service: RefreshTokenService::new(
+                RefreshTokenRepository::new(pool.clone()),
+                UserRepository::new(pool.clone()),
+                settings.refresh_token_ttl_days,
+            ),
+            review_service: ReviewService::new(
+                ReviewRepository::new(pool.clone()),
+                BookingRepository::new(pool.clone()),
+            ),
+            room_type_service: RoomTypeService::new(RoomTypeRepository::new(pool.clone())),
+            room_service: RoomService::new(RoomRepository::new(pool.clone())),
+            session_service: session_service.clone(),
+            staff_service: StaffService::new(
+                StaffRepository::new(pool.clone()),
+                UserRepository::new(pool.clone()),
+            ),
+            tax_service: TaxService::new(TaxRepository::new(pool.clone())),
+            two_factor_service: TwoFactorService::new(
+                TwoFactorRepository::new(pool.clone(), redis_conn.clone()),
+                UserRepository::new(pool.clone()),
+                settings.totp_issuer.clone(),
+                settings.two_factor_challenge_ttl_seconds,
+            ),
+            user_service: UserService::new(
+                UserRepository::new(pool.clone()),
+                session_service,
+                email_verification_service,
+                settings.admin_emails.clone(),
+            ),
         }
     }
 }
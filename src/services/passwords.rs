@@ -0,0 +1,76 @@
+use crate::enums::PasswordError;
+use crate::models::emails::NewEmail;
+use crate::repositories::{password_resets::PasswordResetRepository, users::UserRepository};
+use crate::services::SessionService;
+use crate::utils;
+
+#[derive(Clone)]
+pub struct PasswordService {
+    repo: PasswordResetRepository,
+    user_repo: UserRepository,
+    session_service: SessionService,
+    reset_ttl_minutes: i64,
+}
+
+impl PasswordService {
+    pub fn new(
+        repo: PasswordResetRepository,
+        user_repo: UserRepository,
+        session_service: SessionService,
+        reset_ttl_minutes: i64,
+    ) -> Self {
+        Self {
+            repo,
+            user_repo,
+            session_service,
+            reset_ttl_minutes,
+        }
+    }
+
+    /// Emails a reset token to the account registered under `email`, if any.
+    pub async fn request_reset(&self, email: &str) -> Result<(), sqlx::Error> {
+        let Some(user) = self.user_repo.get_by_email(email).await? else {
+            return Ok(());
+        };
+
+        let token: String = utils::generate_token();
+        let expires_at: chrono::NaiveDateTime =
+            chrono::Utc::now().naive_utc() + chrono::Duration::minutes(self.reset_ttl_minutes);
+
+        self.repo
+            .create(
+                user.id,
+                &utils::hash_token(&token),
+                expires_at,
+                &NewEmail::password_reset(&user.email, &token, self.reset_ttl_minutes),
+            )
+            .await
+    }
+
+    /// Sets a new password through a reset token and signs the user out everywhere.
+    pub async fn reset(&self, token: &str, password_hash: &str) -> Result<(), PasswordError> {
+        let user_id: i32 = self
+            .repo
+            .consume(
+                &utils::hash_token(token),
+                password_hash,
+                chrono::Utc::now().naive_utc(),
+            )
+            .await?
+            .ok_or(PasswordError::InvalidToken)?;
+
+        self.session_service.logout_all(user_id).await?;
+
+        Ok(())
+    }
+
+    /// Sets a new password for a signed-in user and signs them out everywhere.
+    pub async fn change(&self, user_id: i32, password_hash: &str) -> Result<(), PasswordError> {
+        self.user_repo
+            .update_password(user_id, password_hash)
+            .await?;
+        self.session_service.logout_all(user_id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use crate::enums::BookingError;
+use crate::models::rate_plans::{
+    CancellationPolicy, NightlyRate, RatePlan, RatePlanSeason, StayPrice,
+};
+use crate::models::room_types::RoomType;
+use crate::repositories::{rate_plans::RatePlanRepository, room_types::RoomTypeRepository};
+use chrono::Datelike;
+use rust_decimal::Decimal;
+
+#[derive(Clone)]
+pub struct PricingService {
+    room_type_repo: RoomTypeRepository,
+    rate_plan_repo: RatePlanRepository,
+}
+
+impl PricingService {
+    pub fn new(room_type_repo: RoomTypeRepository, rate_plan_repo: RatePlanRepository) -> Self {
+        Self {
+            room_type_repo,
+            rate_plan_repo,
+        }
+    }
+
+    pub async fn quote(
+        &self,
+        hotel_id: i32,
+        room_type_id: i32,
+        rate_plan_id: Option<i32>,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+    ) -> Result<StayPrice, BookingError> {
+        if check_out <= check_in || check_in < chrono::Utc::now().date_naive() {
+            return Err(BookingError::InvalidDates);
+        }
+
+        let room_type: RoomType = self
+            .room_type_repo
+            .find_by_id(hotel_id, room_type_id)
+            .await?
+            .ok_or(BookingError::RoomTypeNotFound)?;
+
+        self.price_room_type(&room_type, rate_plan_id, check_in, check_out)
+            .await
+    }
+
+    pub async fn cancellation_policy(
+        &self,
+        room_type_id: i32,
+        rate_plan_id: Option<i32>,
+    ) -> Result<CancellationPolicy, BookingError> {
+        let Some(rate_plan_id) = rate_plan_id else {
+            return Ok(CancellationPolicy::default());
+        };
+
+        let rate_plan = self
+            .rate_plan_repo
+            .find_by_id(room_type_id, rate_plan_id)
+            .await?
+            .ok_or(BookingError::RatePlanNotFound)?;
+
+        Ok(rate_plan.cancellation_policy())
+    }
+
+    pub async fn price_room_type(
+        &self,
+        room_type: &RoomType,
+        rate_plan_id: Option<i32>,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+    ) -> Result<StayPrice, BookingError> {
+        let Some(rate_plan_id) = rate_plan_id else {
+            return Ok(price_stay(
+                None,
+                room_type.base_price,
+                None,
+                &[],
+                check_in,
+                check_out,
+            ));
+        };
+
+        let rate_plan = self
+            .rate_plan_repo
+            .find_by_id(room_type.id, rate_plan_id)
+            .await?
+            .ok_or(BookingError::RatePlanNotFound)?;
+        let seasons: Vec<RatePlanSeason> =
+            self.rate_plan_repo.list_seasons(&[rate_plan.id]).await?;
+
+        Ok(price_stay(
+            Some(rate_plan.id),
+            rate_plan.base_price,
+            rate_plan.weekend_price,
+            &seasons,
+            check_in,
+            check_out,
+        ))
+    }
+
+    /// Prices the stay at each room type's base price and under each of its
+    /// rate plans, paired with the room type ID. Rate plans and seasons are
+    /// loaded for all room types at once.
+    pub async fn price_all_plans(
+        &self,
+        room_types: &[RoomType],
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+    ) -> Result<Vec<(i32, StayPrice)>, sqlx::Error> {
+        let room_type_ids: Vec<i32> = room_types.iter().map(|room_type| room_type.id).collect();
+        let rate_plans: Vec<RatePlan> = self
+            .rate_plan_repo
+            .list_by_room_types(&room_type_ids)
+            .await?;
+        let rate_plan_ids: Vec<i32> = rate_plans.iter().map(|rate_plan| rate_plan.id).collect();
+        let seasons: Vec<RatePlanSeason> = self.rate_plan_repo.list_seasons(&rate_plan_ids).await?;
+
+        let mut prices: Vec<(i32, StayPrice)> = Vec::new();
+        for room_type in room_types {
+            prices.push((
+                room_type.id,
+                price_stay(None, room_type.base_price, None, &[], check_in, check_out),
+            ));
+            for rate_plan in rate_plans
+                .iter()
+                .filter(|rate_plan| rate_plan.room_type_id == room_type.id)
+            {
+                let plan_seasons: Vec<RatePlanSeason> = seasons
+                    .iter()
+                    .filter(|season| season.rate_plan_id == rate_plan.id)
+                    .cloned()
+                    .collect();
+
+                prices.push((
+                    room_type.id,
+                    price_stay(
+                        Some(rate_plan.id),
+                        rate_plan.base_price,
+                        rate_plan.weekend_price,
+                        &plan_seasons,
+                        check_in,
+                        check_out,
+                    ),
+                ));
+            }
+        }
+
+        Ok(prices)
+    }
+}
+
+fn price_stay(
+    rate_plan_id: Option<i32>,
+    base_price: Decimal,
+    weekend_price: Option<Decimal>,
+    seasons: &[RatePlanSeason],
+    check_in: chrono::NaiveDate,
+    check_out: chrono::NaiveDate,
+) -> StayPrice {
+    let nights: Vec<NightlyRate> = check_in
+        .iter_days()
+        .take_while(|date| *date < check_out)
+        .map(|date| {
+            let weekend = matches!(date.weekday(), chrono::Weekday::Fri | chrono::Weekday::Sat);
+            // The most recently added season wins when seasons overlap.
+            let (price, weekend_override) = seasons
+                .iter()
+                .filter(|season| season.start_date <= date && date <= season.end_date)
+                .max_by_key(|season| season.id)
+                .map_or((base_price, weekend_price), |season| {
+                    (season.price, season.weekend_price)
+                });
+
+            NightlyRate {
+                date,
+                price: weekend_override.filter(|_| weekend).unwrap_or(price),
+            }
+        })
+        .collect();
+
+    StayPrice {
+        rate_plan_id,
+        check_in,
+        check_out,
+        total_price: nights.iter().map(|night| night.price).sum(),
+        nights,
+    }
+}
@@ -0,0 +1,280 @@
+use crate::enums::{BookingError, QuoteLineKind};
+use crate::models::quotes::{Quote, QuoteBreakdown, QuoteLine};
+use crate::models::rate_plans::StayPrice;
+use crate::models::room_types::RoomType;
+use crate::models::taxes::{HotelFee, TaxSettings};
+use crate::repositories::{
+    quotes::QuoteRepository, room_types::RoomTypeRepository, taxes::TaxRepository,
+};
+use crate::services::pricing::PricingService;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct QuoteService {
+    repo: QuoteRepository,
+    tax_repo: TaxRepository,
+    room_type_repo: RoomTypeRepository,
+    pricing_service: PricingService,
+    ttl_minutes: i64,
+}
+
+impl QuoteService {
+    pub fn new(
+        repo: QuoteRepository,
+        tax_repo: TaxRepository,
+        room_type_repo: RoomTypeRepository,
+        pricing_service: PricingService,
+        ttl_minutes: i64,
+    ) -> Self {
+        Self {
+            repo,
+            tax_repo,
+            room_type_repo,
+            pricing_service,
+            ttl_minutes,
+        }
+    }
+
+    pub async fn validate_stay(
+        &self,
+        hotel_id: i32,
+        room_type_id: i32,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<RoomType, BookingError> {
+        if check_out <= check_in || check_in < chrono::Utc::now().date_naive() {
+            return Err(BookingError::InvalidDates);
+        }
+
+        let room_type: RoomType = self
+            .room_type_repo
+            .find_by_id(hotel_id, room_type_id)
+            .await?
+            .ok_or(BookingError::RoomTypeNotFound)?;
+
+        if guests < 1 || guests > room_type.capacity {
+            return Err(BookingError::TooManyGuests);
+        }
+
+        Ok(room_type)
+    }
+
+    pub async fn price_stay(
+        &self,
+        room_type: &RoomType,
+        rate_plan_id: Option<i32>,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<QuoteBreakdown, BookingError> {
+        let price: StayPrice = self
+            .pricing_service
+            .price_room_type(room_type, rate_plan_id, check_in, check_out)
+            .await?;
+        let settings: TaxSettings = self
+            .tax_repo
+            .find_settings(room_type.hotel_id)
+            .await?
+            .ok_or(BookingError::RoomTypeNotFound)?;
+        let fees: Vec<HotelFee> = self.tax_repo.list_fees(room_type.hotel_id).await?;
+
+        Ok(build_breakdown(price, guests, &settings, &fees))
+    }
+
+    /// Cheapest fully priced stay at each hotel, with its room type, across
+    /// the base prices and rate plans of `room_types`. Keyed by hotel ID;
+    /// hotels with no room types in the slice are absent.
+    pub async fn cheapest_stays(
+        &self,
+        room_types: &[RoomType],
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<HashMap<i32, (i32, QuoteBreakdown)>, BookingError> {
+        if room_types.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let hotel_ids: HashMap<i32, i32> = room_types
+            .iter()
+            .map(|room_type| (room_type.id, room_type.hotel_id))
+            .collect();
+        let prices: Vec<(i32, StayPrice)> = self
+            .pricing_service
+            .price_all_plans(room_types, check_in, check_out)
+            .await?;
+
+        let mut cheapest: HashMap<i32, (i32, StayPrice)> = HashMap::new();
+        for (room_type_id, price) in prices {
+            let hotel_id: i32 = hotel_ids[&room_type_id];
+            if cheapest
+                .get(&hotel_id)
+                .is_none_or(|(_, best)| price.total_price < best.total_price)
+            {
+                cheapest.insert(hotel_id, (room_type_id, price));
+            }
+        }
+
+        let unique_hotel_ids: Vec<i32> = cheapest.keys().copied().collect();
+        let settings: HashMap<i32, TaxSettings> = self
+            .tax_repo
+            .list_settings(&unique_hotel_ids)
+            .await?
+            .into_iter()
+            .map(|row| (row.hotel_id, row.settings))
+            .collect();
+        let fees: Vec<HotelFee> = self
+            .tax_repo
+            .list_fees_for_hotels(&unique_hotel_ids)
+            .await?;
+
+        // Fees are per stay and taxes grow with the room total, so the cheapest
+        // room total is also the cheapest overall.
+        cheapest
+            .into_iter()
+            .map(|(hotel_id, (room_type_id, price))| {
+                let settings: &TaxSettings = settings
+                    .get(&hotel_id)
+                    .ok_or(BookingError::RoomTypeNotFound)?;
+                let hotel_fees: Vec<HotelFee> = fees
+                    .iter()
+                    .filter(|fee| fee.hotel_id == hotel_id)
+                    .cloned()
+                    .collect();
+
+                Ok((
+                    hotel_id,
+                    (
+                        room_type_id,
+                        build_breakdown(price, guests, settings, &hotel_fees),
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        room_type_id: i32,
+        rate_plan_id: Option<i32>,
+        check_in: chrono::NaiveDate,
+        check_out: chrono::NaiveDate,
+        guests: i32,
+    ) -> Result<Quote, BookingError> {
+        let room_type: RoomType = self
+            .validate_stay(hotel_id, room_type_id, check_in, check_out, guests)
+            .await?;
+        let breakdown: QuoteBreakdown = self
+            .price_stay(&room_type, rate_plan_id, check_in, check_out, guests)
+            .await?;
+        let expires_at: chrono::NaiveDateTime =
+            chrono::Utc::now().naive_utc() + chrono::Duration::minutes(self.ttl_minutes);
+
+        Ok(self
+            .repo
+            .create(
+                user_id,
+                hotel_id,
+                room_type_id,
+                guests,
+                &breakdown,
+                expires_at,
+            )
+            .await?)
+    }
+
+    pub async fn get_for_user(&self, user_id: i32, id: Uuid) -> Result<Quote, BookingError> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .filter(|quote| quote.user_id == Some(user_id))
+            .ok_or(BookingError::QuoteNotFound)
+    }
+
+    /// The user's quote if it can still back a booking. It is only marked as
+    /// used when the booking is written.
+    pub async fn get_unused(&self, user_id: i32, id: Uuid) -> Result<Quote, BookingError> {
+        let quote: Quote = self.get_for_user(user_id, id).await?;
+
+        if quote.used_at.is_some() || quote.expires_at <= chrono::Utc::now().naive_utc() {
+            return Err(BookingError::QuoteExpired);
+        }
+
+        Ok(quote)
+    }
+}
+
+fn build_breakdown(
+    price: StayPrice,
+    guests: i32,
+    settings: &TaxSettings,
+    fees: &[HotelFee],
+) -> QuoteBreakdown {
+    let mut lines: Vec<QuoteLine> = price
+        .nights
+        .iter()
+        .map(|night| QuoteLine {
+            kind: QuoteLineKind::RoomNight,
+            description: format!("Room night {}", night.date),
+            date: Some(night.date),
+            quantity: 1,
+            unit_price: night.price,
+            amount: night.price,
+        })
+        .collect();
+
+    let room_total: Decimal = price.total_price;
+    let fees_total: Decimal = fees.iter().map(|fee| fee.amount).sum();
+    lines.extend(fees.iter().map(|fee| QuoteLine {
+        kind: QuoteLineKind::Fee,
+        description: fee.name.clone(),
+        date: None,
+        quantity: 1,
+        unit_price: fee.amount,
+        amount: fee.amount,
+    }));
+
+    let person_nights: i32 = guests * price.nights.len() as i32;
+    let city_tax: Decimal = settings.city_tax_per_person_night * Decimal::from(person_nights);
+    if !city_tax.is_zero() {
+        lines.push(QuoteLine {
+            kind: QuoteLineKind::CityTax,
+            description: "City tax per person per night".to_string(),
+            date: None,
+            quantity: person_nights,
+            unit_price: settings.city_tax_per_person_night,
+            amount: city_tax,
+        });
+    }
+
+    // VAT is charged on the room and fees; the city tax is not subject to VAT.
+    let vat: Decimal = ((room_total + fees_total) * settings.vat_percent / Decimal::ONE_HUNDRED)
+        .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+    if !vat.is_zero() {
+        lines.push(QuoteLine {
+            kind: QuoteLineKind::Vat,
+            description: format!("VAT {}%", settings.vat_percent.normalize()),
+            date: None,
+            quantity: 1,
+            unit_price: vat,
+            amount: vat,
+        });
+    }
+
+    let taxes_total: Decimal = city_tax + vat;
+
+    QuoteBreakdown {
+        price,
+        lines,
+        room_total,
+        fees_total,
+        taxes_total,
+        total_price: room_total + fees_total + taxes_total,
+    }
+}
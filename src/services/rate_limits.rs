@@ -0,0 +1,65 @@
+use crate::enums::RateLimitGroup;
+use crate::models::rate_limits::{RateLimit, RateLimitDecision};
+use crate::repositories::rate_limits::RateLimitRepository;
+
+#[derive(Clone)]
+pub struct RateLimitService {
+    repo: RateLimitRepository,
+    enabled: bool,
+    auth: RateLimit,
+    search: RateLimit,
+    mutations: RateLimit,
+}
+
+impl RateLimitService {
+    pub fn new(
+        repo: RateLimitRepository,
+        enabled: bool,
+        auth: RateLimit,
+        search: RateLimit,
+        mutations: RateLimit,
+    ) -> Self {
+        Self {
+            repo,
+            enabled,
+            auth,
+            search,
+            mutations,
+        }
+    }
+
+    /// Takes a token from `client`'s bucket for `group`. Returns `None` when
+    /// rate limiting is turned off.
+    pub async fn check(
+        &self,
+        group: RateLimitGroup,
+        client: &str,
+    ) -> Result<Option<RateLimitDecision>, redis::RedisError> {
+        if !self.enabled {
+            return Ok(None);
+        }
+
+        let limit: RateLimit = match group {
+            RateLimitGroup::Auth => self.auth,
+            RateLimitGroup::Search => self.search,
+            RateLimitGroup::Mutations => self.mutations,
+        };
+
+        let (allowed, remaining, full_ms, retry_ms) = self
+            .repo
+            .take_token(
+                &format!("rate_limit:{}:{}", group, client),
+                limit.capacity,
+                limit.per_minute,
+            )
+            .await?;
+
+        Ok(Some(RateLimitDecision {
+            allowed,
+            limit: limit.capacity,
+            remaining,
+            reset_seconds: (full_ms + 999) / 1000,
+            retry_after_seconds: (retry_ms + 999) / 1000,
+        }))
+    }
+}
@@ -0,0 +1,158 @@
+use crate::models::rate_plans::{CancellationPolicy, RatePlan, RatePlanResponse, RatePlanSeason};
+use crate::repositories::rate_plans::RatePlanRepository;
+use rust_decimal::Decimal;
+
+#[derive(Clone)]
+pub struct RatePlanService {
+    repo: RatePlanRepository,
+}
+
+impl RatePlanService {
+    pub fn new(repo: RatePlanRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list_by_room_type(
+        &self,
+        room_type_id: i32,
+    ) -> Result<Vec<RatePlanResponse>, sqlx::Error> {
+        let rate_plans: Vec<RatePlan> = self.repo.list_by_room_type(room_type_id).await?;
+        let ids: Vec<i32> = rate_plans.iter().map(|rate_plan| rate_plan.id).collect();
+        let seasons: Vec<RatePlanSeason> = self.repo.list_seasons(&ids).await?;
+
+        Ok(rate_plans
+            .into_iter()
+            .map(|rate_plan| {
+                let own: Vec<RatePlanSeason> = seasons
+                    .iter()
+                    .filter(|season| season.rate_plan_id == rate_plan.id)
+                    .cloned()
+                    .collect();
+                RatePlanResponse::new(rate_plan, own)
+            })
+            .collect())
+    }
+
+    pub async fn get_by_id(
+        &self,
+        room_type_id: i32,
+        id: i32,
+    ) -> Result<Option<RatePlanResponse>, sqlx::Error> {
+        let Some(rate_plan) = self.repo.find_by_id(room_type_id, id).await? else {
+            return Ok(None);
+        };
+        let seasons: Vec<RatePlanSeason> = self.repo.list_seasons(&[rate_plan.id]).await?;
+
+        Ok(Some(RatePlanResponse::new(rate_plan, seasons)))
+    }
+
+    pub async fn create(
+        &self,
+        room_type_id: i32,
+        name: String,
+        description: Option<String>,
+        base_price: Decimal,
+        weekend_price: Option<Decimal>,
+        cancellation_policy: CancellationPolicy,
+    ) -> Result<RatePlanResponse, sqlx::Error> {
+        let rate_plan: RatePlan = self
+            .repo
+            .create(
+                room_type_id,
+                name,
+                description,
+                base_price,
+                weekend_price,
+                cancellation_policy,
+            )
+            .await?;
+
+        Ok(RatePlanResponse::new(rate_plan, Vec::new()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        room_type_id: i32,
+        id: i32,
+        name: Option<String>,
+        description: Option<String>,
+        base_price: Option<Decimal>,
+        weekend_price: Option<Decimal>,
+        cancellation_policy: Option<CancellationPolicy>,
+    ) -> Result<Option<RatePlanResponse>, sqlx::Error> {
+        if self
+            .repo
+            .update(
+                room_type_id,
+                id,
+                name,
+                description,
+                base_price,
+                weekend_price,
+                cancellation_policy,
+            )
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        self.get_by_id(room_type_id, id).await
+    }
+
+    pub async fn delete(&self, room_type_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        self.repo.delete(room_type_id, id).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_season(
+        &self,
+        room_type_id: i32,
+        rate_plan_id: i32,
+        name: String,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        price: Decimal,
+        weekend_price: Option<Decimal>,
+    ) -> Result<Option<RatePlanSeason>, sqlx::Error> {
+        if self
+            .repo
+            .find_by_id(room_type_id, rate_plan_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        self.repo
+            .create_season(
+                rate_plan_id,
+                name,
+                start_date,
+                end_date,
+                price,
+                weekend_price,
+            )
+            .await
+            .map(Some)
+    }
+
+    pub async fn delete_season(
+        &self,
+        room_type_id: i32,
+        rate_plan_id: i32,
+        id: i32,
+    ) -> Result<u64, sqlx::Error> {
+        if self
+            .repo
+            .find_by_id(room_type_id, rate_plan_id)
+            .await?
+            .is_none()
+        {
+            return Ok(0);
+        }
+
+        self.repo.delete_season(rate_plan_id, id).await
+    }
+}
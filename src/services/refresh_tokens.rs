@@ -0,0 +1,59 @@
+use crate::enums::RefreshTokenError;
+use crate::models::users::{RefreshToken, User};
+use crate::repositories::{refresh_tokens::RefreshTokenRepository, users::UserRepository};
+use crate::utils;
+
+#[derive(Clone)]
+pub struct RefreshTokenService {
+    repo: RefreshTokenRepository,
+    user_repo: UserRepository,
+    ttl_days: i64,
+}
+
+impl RefreshTokenService {
+    pub fn new(repo: RefreshTokenRepository, user_repo: UserRepository, ttl_days: i64) -> Self {
+        Self {
+            repo,
+            user_repo,
+            ttl_days,
+        }
+    }
+
+    /// Starts a new token family for a fresh login and returns the raw token.
+    pub async fn issue(&self, user_id: i32) -> Result<String, sqlx::Error> {
+        let token: String = utils::generate_token();
+
+        self.repo
+            .create(user_id, &utils::hash_token(&token), self.expires_at())
+            .await?;
+
+        Ok(token)
+    }
+
+    /// Rotates `token`, returning its user and the raw replacement token.
+    pub async fn rotate(&self, token: &str) -> Result<(User, String), RefreshTokenError> {
+        let next_token: String = utils::generate_token();
+
+        let next: RefreshToken = self
+            .repo
+            .rotate(
+                &utils::hash_token(token),
+                &utils::hash_token(&next_token),
+                chrono::Utc::now().naive_utc(),
+                self.expires_at(),
+            )
+            .await?;
+
+        let user: User = self
+            .user_repo
+            .get_by_id(next.user_id)
+            .await?
+            .ok_or(RefreshTokenError::Invalid)?;
+
+        Ok((user, next_token))
+    }
+
+    fn expires_at(&self) -> chrono::NaiveDateTime {
+        chrono::Utc::now().naive_utc() + chrono::Duration::days(self.ttl_days)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::enums::{BookingStatus, ReviewError};
+use crate::models::bookings::Booking;
+use crate::models::reviews::Review;
+use crate::repositories::{bookings::BookingRepository, reviews::ReviewRepository};
+
+#[derive(Clone)]
+pub struct ReviewService {
+    repo: ReviewRepository,
+    booking_repo: BookingRepository,
+}
+
+impl ReviewService {
+    pub fn new(repo: ReviewRepository, booking_repo: BookingRepository) -> Self {
+        Self { repo, booking_repo }
+    }
+
+    pub async fn list_by_hotel(
+        &self,
+        hotel_id: i32,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<Review>, i64), sqlx::Error> {
+        self.repo
+            .list_by_hotel(hotel_id, per_page, (page - 1) * per_page)
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        booking_id: i32,
+        score: i32,
+        comment: String,
+    ) -> Result<Review, ReviewError> {
+        let booking: Booking = self
+            .booking_repo
+            .find_by_id(booking_id)
+            .await?
+            .filter(|booking| booking.user_id == user_id && booking.hotel_id == hotel_id)
+            .ok_or(ReviewError::BookingNotFound)?;
+
+        if booking.status != BookingStatus::CheckedOut {
+            return Err(ReviewError::NotCheckedOut);
+        }
+
+        Ok(self
+            .repo
+            .create(booking.id, hotel_id, user_id, score, comment)
+            .await?)
+    }
+
+    pub async fn update(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        id: i32,
+        score: Option<i32>,
+        comment: Option<String>,
+    ) -> Result<Review, ReviewError> {
+        self.get_for_user(user_id, hotel_id, id).await?;
+
+        self.repo
+            .update(hotel_id, id, score, comment)
+            .await?
+            .ok_or(ReviewError::NotFound)
+    }
+
+    pub async fn delete(&self, user_id: i32, hotel_id: i32, id: i32) -> Result<(), ReviewError> {
+        self.get_for_user(user_id, hotel_id, id).await?;
+
+        if self.repo.delete(hotel_id, id).await? == 0 {
+            return Err(ReviewError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_for_user(
+        &self,
+        user_id: i32,
+        hotel_id: i32,
+        id: i32,
+    ) -> Result<Review, ReviewError> {
+        self.repo
+            .find_by_id(hotel_id, id)
+            .await?
+            .filter(|review| review.user_id == user_id)
+            .ok_or(ReviewError::NotFound)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::models::room_types::RoomType;
+use crate::repositories::room_types::RoomTypeRepository;
+use rust_decimal::Decimal;
+
+#[derive(Clone)]
+pub struct RoomTypeService {
+    repo: RoomTypeRepository,
+}
+
+impl RoomTypeService {
+    pub fn new(repo: RoomTypeRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list_by_hotel(&self, hotel_id: i32) -> Result<Vec<RoomType>, sqlx::Error> {
+        self.repo.list_by_hotel(hotel_id).await
+    }
+
+    pub async fn get_by_id(&self, hotel_id: i32, id: i32) -> Result<Option<RoomType>, sqlx::Error> {
+        self.repo.find_by_id(hotel_id, id).await
+    }
+
+    pub async fn create(
+        &self,
+        hotel_id: i32,
+        name: String,
+        description: Option<String>,
+        capacity: i32,
+        bed_configuration: String,
+        base_price: Decimal,
+    ) -> Result<RoomType, sqlx::Error> {
+        self.repo
+            .create(
+                hotel_id,
+                name,
+                description,
+                capacity,
+                bed_configuration,
+                base_price,
+            )
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        hotel_id: i32,
+        id: i32,
+        name: Option<String>,
+        description: Option<String>,
+        capacity: Option<i32>,
+        bed_configuration: Option<String>,
+        base_price: Option<Decimal>,
+    ) -> Result<Option<RoomType>, sqlx::Error> {
+        self.repo
+            .update(
+                hotel_id,
+                id,
+                name,
+                description,
+                capacity,
+                bed_configuration,
+                base_price,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, hotel_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        self.repo.delete(hotel_id, id).await
+    }
+}
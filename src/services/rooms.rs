@@ -0,0 +1,53 @@
+use crate::enums::RoomStatus;
+use crate::models::rooms::Room;
+use crate::repositories::rooms::RoomRepository;
+
+#[derive(Clone)]
+pub struct RoomService {
+    repo: RoomRepository,
+}
+
+impl RoomService {
+    pub fn new(repo: RoomRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list_by_hotel(&self, hotel_id: i32) -> Result<Vec<Room>, sqlx::Error> {
+        self.repo.list_by_hotel(hotel_id).await
+    }
+
+    pub async fn get_by_id(&self, hotel_id: i32, id: i32) -> Result<Option<Room>, sqlx::Error> {
+        self.repo.find_by_id(hotel_id, id).await
+    }
+
+    pub async fn create(
+        &self,
+        hotel_id: i32,
+        room_type_id: i32,
+        room_number: String,
+        floor: Option<i32>,
+        status: RoomStatus,
+    ) -> Result<Room, sqlx::Error> {
+        self.repo
+            .create(hotel_id, room_type_id, room_number, floor, status)
+            .await
+    }
+
+    pub async fn update(
+        &self,
+        hotel_id: i32,
+        id: i32,
+        room_type_id: Option<i32>,
+        room_number: Option<String>,
+        floor: Option<i32>,
+        status: Option<RoomStatus>,
+    ) -> Result<Option<Room>, sqlx::Error> {
+        self.repo
+            .update(hotel_id, id, room_type_id, room_number, floor, status)
+            .await
+    }
+
+    pub async fn delete(&self, hotel_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        self.repo.delete(hotel_id, id).await
+    }
+}
@@ -0,0 +1,74 @@
+use crate::enums::SessionError;
+use crate::models::users::Claims;
+use crate::repositories::{refresh_tokens::RefreshTokenRepository, sessions::SessionRepository};
+use crate::utils;
+
+#[derive(Clone)]
+pub struct SessionService {
+    repo: SessionRepository,
+    refresh_repo: RefreshTokenRepository,
+}
+
+impl SessionService {
+    pub fn new(repo: SessionRepository, refresh_repo: RefreshTokenRepository) -> Self {
+        Self { repo, refresh_repo }
+    }
+
+    /// Whether a decoded access token is still live: not logged out and issued
+    /// at the user's current token version.
+    pub async fn is_active(&self, claims: &Claims) -> Result<bool, SessionError> {
+        if !claims.jti.is_empty() && self.repo.is_denied(&claims.jti).await? {
+            return Ok(false);
+        }
+
+        let version: Option<i32> = match self.repo.cached_token_version(claims.sub).await? {
+            Some(version) => Some(version),
+            None => {
+                let version: Option<i32> = self.repo.token_version(claims.sub).await?;
+                if let Some(version) = version {
+                    self.repo.cache_token_version(claims.sub, version).await?;
+                }
+                version
+            }
+        };
+
+        Ok(version == Some(claims.ver))
+    }
+
+    /// Revokes one access token until it would have expired anyway, along with
+    /// the refresh token family it was paired with, if given.
+    pub async fn logout(
+        &self,
+        claims: &Claims,
+        refresh_token: Option<&str>,
+    ) -> Result<(), SessionError> {
+        let remaining: i64 = claims.exp - chrono::Utc::now().timestamp();
+
+        if remaining > 0 {
+            self.repo.deny(&claims.jti, remaining).await?;
+        }
+
+        if let Some(refresh_token) = refresh_token {
+            self.refresh_repo
+                .revoke_family(
+                    &utils::hash_token(refresh_token),
+                    claims.sub,
+                    chrono::Utc::now().naive_utc(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Invalidates every access and refresh token issued to the user so far.
+    pub async fn logout_all(&self, user_id: i32) -> Result<(), SessionError> {
+        let version: i32 = self.repo.bump_token_version(user_id).await?;
+        self.repo.cache_token_version(user_id, version).await?;
+        self.refresh_repo
+            .revoke_all(user_id, chrono::Utc::now().naive_utc())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::enums::{StaffError, StaffRole};
+use crate::models::staff::StaffMember;
+use crate::models::users::User;
+use crate::repositories::{staff::StaffRepository, users::UserRepository};
+
+#[derive(Clone)]
+pub struct StaffService {
+    repo: StaffRepository,
+    user_repo: UserRepository,
+}
+
+impl StaffService {
+    pub fn new(repo: StaffRepository, user_repo: UserRepository) -> Self {
+        Self { repo, user_repo }
+    }
+
+    pub async fn role_for(
+        &self,
+        hotel_id: i32,
+        user_id: i32,
+    ) -> Result<Option<StaffRole>, sqlx::Error> {
+        Ok(self
+            .repo
+            .find(hotel_id, user_id)
+            .await?
+            .map(|member| member.role))
+    }
+
+    pub async fn list(&self, hotel_id: i32) -> Result<Vec<StaffMember>, sqlx::Error> {
+        self.repo.list_by_hotel(hotel_id).await
+    }
+
+    pub async fn invite(
+        &self,
+        hotel_id: i32,
+        actor_role: StaffRole,
+        email: &str,
+        role: StaffRole,
+    ) -> Result<StaffMember, StaffError> {
+        if role == StaffRole::Owner && actor_role != StaffRole::Owner {
+            return Err(StaffError::OwnersOnly);
+        }
+
+        let user: User = self
+            .user_repo
+            .get_by_email(email)
+            .await?
+            .ok_or(StaffError::UserNotFound)?;
+
+        self.repo.add(hotel_id, user.id, role).await?;
+
+        self.repo
+            .find_member(hotel_id, user.id)
+            .await?
+            .ok_or(StaffError::NotFound)
+    }
+
+    pub async fn remove(
+        &self,
+        hotel_id: i32,
+        actor_role: StaffRole,
+        user_id: i32,
+    ) -> Result<(), StaffError> {
+        self.repo.remove(hotel_id, user_id, actor_role).await
+    }
+}
@@ -0,0 +1,54 @@
+use crate::models::taxes::{HotelFee, TaxesResponse};
+use crate::repositories::taxes::TaxRepository;
+use rust_decimal::Decimal;
+
+#[derive(Clone)]
+pub struct TaxService {
+    repo: TaxRepository,
+}
+
+impl TaxService {
+    pub fn new(repo: TaxRepository) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get_by_hotel(&self, hotel_id: i32) -> Result<Option<TaxesResponse>, sqlx::Error> {
+        let Some(settings) = self.repo.find_settings(hotel_id).await? else {
+            return Ok(None);
+        };
+        let fees: Vec<HotelFee> = self.repo.list_fees(hotel_id).await?;
+
+        Ok(Some(TaxesResponse::new(settings, fees)))
+    }
+
+    pub async fn update(
+        &self,
+        hotel_id: i32,
+        city_tax_per_person_night: Option<Decimal>,
+        vat_percent: Option<Decimal>,
+    ) -> Result<Option<TaxesResponse>, sqlx::Error> {
+        let Some(settings) = self
+            .repo
+            .update_settings(hotel_id, city_tax_per_person_night, vat_percent)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let fees: Vec<HotelFee> = self.repo.list_fees(hotel_id).await?;
+
+        Ok(Some(TaxesResponse::new(settings, fees)))
+    }
+
+    pub async fn create_fee(
+        &self,
+        hotel_id: i32,
+        name: String,
+        amount: Decimal,
+    ) -> Result<HotelFee, sqlx::Error> {
+        self.repo.create_fee(hotel_id, name, amount).await
+    }
+
+    pub async fn delete_fee(&self, hotel_id: i32, id: i32) -> Result<u64, sqlx::Error> {
+        self.repo.delete_fee(hotel_id, id).await
+    }
+}
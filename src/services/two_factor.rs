@@ -0,0 +1,248 @@
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use crate::enums::TwoFactorError;
+use crate::models::users::{TwoFactorSetupResponse, User};
+use crate::repositories::{two_factor::TwoFactorRepository, users::UserRepository};
+use crate::utils;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+/// Wrong codes allowed per challenge before it is thrown away.
+const MAX_CHALLENGE_ATTEMPTS: i64 = 5;
+
+#[derive(Clone)]
+pub struct TwoFactorService {
+    repo: TwoFactorRepository,
+    user_repo: UserRepository,
+    issuer: String,
+    challenge_ttl_seconds: i64,
+}
+
+impl TwoFactorService {
+    pub fn new(
+        repo: TwoFactorRepository,
+        user_repo: UserRepository,
+        issuer: String,
+        challenge_ttl_seconds: i64,
+    ) -> Self {
+        Self {
+            repo,
+            user_repo,
+            issuer,
+            challenge_ttl_seconds,
+        }
+    }
+
+    pub fn challenge_ttl_seconds(&self) -> i64 {
+        self.challenge_ttl_seconds
+    }
+
+    /// Generates a new pending secret. 2FA stays off until `confirm`.
+    pub async fn setup(&self, user_id: i32) -> Result<TwoFactorSetupResponse, TwoFactorError> {
+        let user: User = self.get_user(user_id).await?;
+
+        if user.totp_enabled_at.is_some() {
+            return Err(TwoFactorError::AlreadyEnabled);
+        }
+
+        let secret: String = utils::generate_totp_secret();
+
+        if self.repo.set_pending_secret(user.id, &secret).await? == 0 {
+            return Err(TwoFactorError::AlreadyEnabled);
+        }
+
+        let totp: TOTP = self.totp(&secret, &user.email)?;
+
+        Ok(TwoFactorSetupResponse {
+            secret,
+            otpauth_uri: totp.get_url(),
+        })
+    }
+
+    /// Enables 2FA once the user proves their authenticator works, returning
+    /// fresh recovery codes.
+    pub async fn confirm(&self, user_id: i32, code: &str) -> Result<Vec<String>, TwoFactorError> {
+        let user: User = self.get_user(user_id).await?;
+
+        if user.totp_enabled_at.is_some() {
+            return Err(TwoFactorError::AlreadyEnabled);
+        }
+
+        let secret: &str = user
+            .totp_secret
+            .as_deref()
+            .ok_or(TwoFactorError::NotSetUp)?;
+
+        if !self
+            .use_totp_code(user.id, secret, &user.email, code)
+            .await?
+        {
+            return Err(TwoFactorError::InvalidCode);
+        }
+
+        let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| {
+                let token: String = utils::generate_token();
+                format!("{}-{}", &token[..5], &token[5..10])
+            })
+            .collect();
+        let hashes: Vec<String> = codes
+            .iter()
+            .map(|code| utils::hash_token(&normalize_recovery_code(code)))
+            .collect();
+
+        if !self
+            .repo
+            .enable(user.id, &hashes, chrono::Utc::now().naive_utc())
+            .await?
+        {
+            return Err(TwoFactorError::AlreadyEnabled);
+        }
+
+        Ok(codes)
+    }
+
+    /// Starts the second login step for a user whose password checked out,
+    /// returning the raw challenge token.
+    pub async fn start_challenge(&self, user_id: i32) -> Result<String, TwoFactorError> {
+        let token: String = utils::generate_token();
+
+        self.repo
+            .create_challenge(
+                &utils::hash_token(&token),
+                user_id,
+                self.challenge_ttl_seconds,
+            )
+            .await?;
+
+        Ok(token)
+    }
+
+    /// Looks up the user a pending login challenge belongs to.
+    pub async fn challenge_user(&self, token: &str) -> Result<User, TwoFactorError> {
+        let user_id: i32 = self
+            .repo
+            .challenge_user(&utils::hash_token(token))
+            .await?
+            .ok_or(TwoFactorError::InvalidChallenge)?;
+
+        self.get_user(user_id).await
+    }
+
+    /// Completes `user`'s login challenge with a TOTP or recovery code. The
+    /// challenge is claimed before the code is checked, so concurrent requests
+    /// can't spend several codes against it; a wrong code puts it back.
+    pub async fn verify_challenge(
+        &self,
+        token: &str,
+        user: &User,
+        code: &str,
+    ) -> Result<(), TwoFactorError> {
+        let token_hash: String = utils::hash_token(token);
+        let ttl_ms: i64 = self
+            .repo
+            .claim_challenge(&token_hash)
+            .await?
+            .ok_or(TwoFactorError::InvalidChallenge)?;
+
+        if !self.check_code(user, code).await? {
+            let attempts: i64 = self
+                .repo
+                .record_failed_attempt(&token_hash, self.challenge_ttl_seconds)
+                .await?;
+            if attempts >= MAX_CHALLENGE_ATTEMPTS {
+                self.repo.clear_attempts(&token_hash).await?;
+            } else {
+                self.repo
+                    .restore_challenge(&token_hash, user.id, ttl_ms)
+                    .await?;
+            }
+            return Err(TwoFactorError::InvalidCode);
+        }
+
+        self.repo.clear_attempts(&token_hash).await?;
+
+        Ok(())
+    }
+
+    async fn check_code(&self, user: &User, code: &str) -> Result<bool, TwoFactorError> {
+        let code: &str = code.trim();
+
+        if code.len() == 6 && code.chars().all(|c| c.is_ascii_digit()) {
+            let Some(secret) = user.totp_secret.as_deref() else {
+                return Ok(false);
+            };
+            return self.use_totp_code(user.id, secret, &user.email, code).await;
+        }
+
+        Ok(self
+            .repo
+            .use_recovery_code(
+                user.id,
+                &utils::hash_token(&normalize_recovery_code(code)),
+                chrono::Utc::now().naive_utc(),
+            )
+            .await?)
+    }
+
+    /// Accepts a TOTP code from the current time step or one step either side,
+    /// at most once: a code is rejected once it or a later one has been used.
+    async fn use_totp_code(
+        &self,
+        user_id: i32,
+        secret: &str,
+        email: &str,
+        code: &str,
+    ) -> Result<bool, TwoFactorError> {
+        let totp: TOTP = self.totp(secret, email)?;
+        let Some(step) = matching_step(&totp, code.trim()) else {
+            return Ok(false);
+        };
+
+        Ok(self.repo.use_totp_step(user_id, step).await?)
+    }
+
+    async fn get_user(&self, user_id: i32) -> Result<User, TwoFactorError> {
+        Ok(self
+            .user_repo
+            .get_by_id(user_id)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?)
+    }
+
+    fn totp(&self, secret: &str, email: &str) -> Result<TOTP, TwoFactorError> {
+        let secret: Vec<u8> = Secret::Encoded(secret.to_string())
+            .to_bytes()
+            .map_err(|_| TwoFactorError::NotSetUp)?;
+
+        Ok(TOTP::new_unchecked(
+            Algorithm::SHA1,
+            6,
+            1,
+            30,
+            secret,
+            Some(self.issuer.clone()),
+            email.to_string(),
+        ))
+    }
+}
+
+/// Time step the code was generated for, allowing the totp's skew.
+fn matching_step(totp: &TOTP, code: &str) -> Option<i64> {
+    let current: i64 = chrono::Utc::now().timestamp() / totp.step as i64;
+    let skew: i64 = totp.skew as i64;
+    // Compare one step at a time so the match tells us which step it was.
+    let single_step: TOTP = TOTP {
+        skew: 0,
+        ..totp.clone()
+    };
+
+    (current - skew..=current + skew)
+        .find(|step| single_step.check(code, (step * totp.step as i64) as u64))
+}
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
@@ -1,16 +1,35 @@
+use crate::enums::{SessionError, UserRole};
+use crate::models::emails::NewEmailVerification;
 use crate::models::users::User;
 use crate::repositories::users::UserRepository;
+use crate::services::email_verifications::EmailVerificationService;
+use crate::services::sessions::SessionService;
 
 #[derive(Clone)]
 pub struct UserService {
     repo: UserRepository,
+    session_service: SessionService,
+    email_verification_service: EmailVerificationService,
+    admin_emails: Vec<String>,
 }
 
 impl UserService {
-    pub fn new(repo: UserRepository) -> Self {
-        Self { repo }
+    pub fn new(
+        repo: UserRepository,
+        session_service: SessionService,
+        email_verification_service: EmailVerificationService,
+        admin_emails: Vec<String>,
+    ) -> Self {
+        Self {
+            repo,
+            session_service,
+            email_verification_service,
+            admin_emails,
+        }
     }
 
+    /// Registers the user and queues their verification email in the same
+    /// transaction.
     pub async fn create(
         &self,
         email: String,
@@ -18,8 +37,23 @@ impl UserService {
         first_name: String,
         last_name: String,
     ) -> Result<User, sqlx::Error> {
+        let role: UserRole = if self.admin_emails.contains(&email.to_lowercase()) {
+            UserRole::Admin
+        } else {
+            UserRole::Guest
+        };
+
+        let verification: NewEmailVerification = self.email_verification_service.prepare(&email);
+
         self.repo
-            .create(email, password_hash, first_name, last_name)
+            .create(
+                email,
+                password_hash,
+                first_name,
+                last_name,
+                role,
+                &verification,
+            )
             .await
     }
 
@@ -32,11 +66,31 @@ impl UserService {
     }
 
     pub async fn update(
+        &self,
+        id: i32,
+        first_name: String,
+        last_name: String,
+    ) -> Result<Option<User>, sqlx::Error> {
+        self.repo.update(id, first_name, last_name).await
+    }
+
+    pub async fn patch(
         &self,
         id: i32,
         first_name: Option<String>,
         last_name: Option<String>,
     ) -> Result<Option<User>, sqlx::Error> {
-        self.repo.update(id, first_name, last_name).await
+        self.repo.patch(id, first_name, last_name).await
+    }
+
+    /// Changes the user's role and signs them out everywhere, since access
+    /// tokens carry the role they were issued with.
+    pub async fn update_role(&self, id: i32, role: UserRole) -> Result<Option<User>, SessionError> {
+        let Some(user) = self.repo.update_role(id, role).await? else {
+            return Ok(None);
+        };
+        self.session_service.logout_all(user.id).await?;
+
+        Ok(Some(user))
     }
 }
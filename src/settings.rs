@@ -16,7 +16,48 @@ pub struct Settings {
     pub postgres_max_pool: String,
 
     pub jwt_secret: String,
-    pub jwt_expire_hours: i64,
+    pub jwt_expire_minutes: i64,
+    pub refresh_token_ttl_days: i64,
+    pub password_reset_ttl_minutes: i64,
+    pub email_verification_ttl_hours: i64,
+    /// Whether guests must verify their email before they can book.
+    pub require_verified_email_for_bookings: bool,
+    pub admin_emails: Vec<String>,
+    pub totp_issuer: String,
+    pub two_factor_challenge_ttl_seconds: i64,
+    pub login_failure_window_seconds: i64,
+    pub login_max_failures_per_email: i64,
+    pub login_max_failures_per_ip: i64,
+    pub login_lockout_minutes: i64,
+    /// Take the client address from `X-Forwarded-For`; only safe behind a proxy
+    /// that overwrites the header.
+    pub trust_forwarded_for: bool,
+
+    pub quote_ttl_minutes: i64,
+
+    pub hold_ttl_minutes: i64,
+    pub hold_sweep_interval_seconds: u64,
+
+    pub rate_limit_enabled: bool,
+    /// Bucket size (burst) and refill rate for each rate-limited route group.
+    pub rate_limit_auth_capacity: i64,
+    pub rate_limit_auth_per_minute: i64,
+    pub rate_limit_search_capacity: i64,
+    pub rate_limit_search_per_minute: i64,
+    pub rate_limit_mutations_capacity: i64,
+    pub rate_limit_mutations_per_minute: i64,
+
+    /// One of `smtp`, `file` or `log`.
+    pub mail_transport: String,
+    pub mail_from: String,
+    pub mail_file_dir: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_starttls: bool,
+    pub email_worker_interval_seconds: u64,
+    pub email_max_attempts: i32,
 }
 
 impl Settings {
@@ -39,13 +80,162 @@ impl Settings {
 
             jwt_secret: env::var("JWT_SECRET")
                 .unwrap_or_else(|_| "your-secret-key-change-in-production".to_string()),
-            jwt_expire_hours: env::var("JWT_EXPIRE_HOURS")
-                .unwrap_or_else(|_| "24".to_string())
+            jwt_expire_minutes: env::var("JWT_EXPIRE_MINUTES")
+                .unwrap_or_else(|_| "15".to_string())
+                .parse()
+                .unwrap_or(15),
+            refresh_token_ttl_days: env::var("REFRESH_TOKEN_TTL_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+            password_reset_ttl_minutes: env::var("PASSWORD_RESET_TTL_MINUTES")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            email_verification_ttl_hours: env::var("EMAIL_VERIFICATION_TTL_HOURS")
+                .unwrap_or_else(|_| "48".to_string())
+                .parse()
+                .unwrap_or(48),
+            require_verified_email_for_bookings: env::var("REQUIRE_VERIFIED_EMAIL_FOR_BOOKINGS")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .unwrap_or(false),
+            admin_emails: env::var("ADMIN_EMAILS")
+                .unwrap_or_default()
+                .split(',')
+                .map(|email| email.trim().to_lowercase())
+                .filter(|email| !email.is_empty())
+                .collect(),
+            totp_issuer: env::var("TOTP_ISSUER").unwrap_or_else(|_| "Hotel Booking".to_string()),
+            two_factor_challenge_ttl_seconds: env::var("TWO_FACTOR_CHALLENGE_TTL_SECONDS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .unwrap_or(300),
+            login_failure_window_seconds: env::var("LOGIN_FAILURE_WINDOW_SECONDS")
+                .unwrap_or_else(|_| "900".to_string())
+                .parse()
+                .unwrap_or(900),
+            login_max_failures_per_email: env::var("LOGIN_MAX_FAILURES_PER_EMAIL")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+            login_max_failures_per_ip: env::var("LOGIN_MAX_FAILURES_PER_IP")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .unwrap_or(20),
+            login_lockout_minutes: env::var("LOGIN_LOCKOUT_MINUTES")
+                .unwrap_or_else(|_| "15".to_string())
+                .parse()
+                .unwrap_or(15),
+            trust_forwarded_for: env::var("TRUST_FORWARDED_FOR")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .unwrap_or(false),
+
+            quote_ttl_minutes: env::var("QUOTE_TTL_MINUTES")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+
+            hold_ttl_minutes: env::var("HOLD_TTL_MINUTES")
+                .unwrap_or_else(|_| "15".to_string())
+                .parse()
+                .unwrap_or(15),
+            hold_sweep_interval_seconds: env::var("HOLD_SWEEP_INTERVAL_SECONDS")
+                .unwrap_or_else(|_| "30".to_string())
                 .parse()
-                .unwrap_or(24),
+                .unwrap_or(30),
+
+            rate_limit_enabled: env::var("RATE_LIMIT_ENABLED")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .unwrap_or(true),
+            rate_limit_auth_capacity: env::var("RATE_LIMIT_AUTH_CAPACITY")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .unwrap_or(20),
+            rate_limit_auth_per_minute: env::var("RATE_LIMIT_AUTH_PER_MINUTE")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .unwrap_or(20),
+            rate_limit_search_capacity: env::var("RATE_LIMIT_SEARCH_CAPACITY")
+                .unwrap_or_else(|_| "120".to_string())
+                .parse()
+                .unwrap_or(120),
+            rate_limit_search_per_minute: env::var("RATE_LIMIT_SEARCH_PER_MINUTE")
+                .unwrap_or_else(|_| "120".to_string())
+                .parse()
+                .unwrap_or(120),
+            rate_limit_mutations_capacity: env::var("RATE_LIMIT_MUTATIONS_CAPACITY")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            rate_limit_mutations_per_minute: env::var("RATE_LIMIT_MUTATIONS_PER_MINUTE")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+
+            mail_transport: env::var("MAIL_TRANSPORT").unwrap_or_else(|_| "log".to_string()),
+            mail_from: env::var("MAIL_FROM")
+                .unwrap_or_else(|_| "Hotel Booking <no-reply@example.com>".to_string()),
+            mail_file_dir: env::var("MAIL_FILE_DIR").unwrap_or_else(|_| "mail".to_string()),
+            smtp_host: env::var("SMTP_HOST").unwrap_or_else(|_| "localhost".to_string()),
+            smtp_port: env::var("SMTP_PORT")
+                .unwrap_or_else(|_| "587".to_string())
+                .parse()
+                .unwrap_or(587),
+            smtp_username: env::var("SMTP_USERNAME").ok().filter(|v| !v.is_empty()),
+            smtp_password: env::var("SMTP_PASSWORD").ok().filter(|v| !v.is_empty()),
+            smtp_starttls: env::var("SMTP_STARTTLS")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .unwrap_or(true),
+            email_worker_interval_seconds: env::var("EMAIL_WORKER_INTERVAL_SECONDS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+            email_max_attempts: env::var("EMAIL_MAX_ATTEMPTS")
+                .unwrap_or_else(|_| "8".to_string())
+                .parse()
+                .unwrap_or(8),
         }
     }
 
+    /// Rejects settings the services cannot run with.
+    pub fn validate(&self) -> Result<(), String> {
+        let rate_limits: [(&str, i64); 6] = [
+            ("RATE_LIMIT_AUTH_CAPACITY", self.rate_limit_auth_capacity),
+            (
+                "RATE_LIMIT_AUTH_PER_MINUTE",
+                self.rate_limit_auth_per_minute,
+            ),
+            (
+                "RATE_LIMIT_SEARCH_CAPACITY",
+                self.rate_limit_search_capacity,
+            ),
+            (
+                "RATE_LIMIT_SEARCH_PER_MINUTE",
+                self.rate_limit_search_per_minute,
+            ),
+            (
+                "RATE_LIMIT_MUTATIONS_CAPACITY",
+                self.rate_limit_mutations_capacity,
+            ),
+            (
+                "RATE_LIMIT_MUTATIONS_PER_MINUTE",
+                self.rate_limit_mutations_per_minute,
+            ),
+        ];
+
+        for (name, value) in rate_limits {
+            if value <= 0 {
+                return Err(format!("{} must be positive, got {}", name, value));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn get_redis_url(&self) -> String {
         format!(
             "redis://{}:{}/{}",
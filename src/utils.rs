@@ -1,20 +1,28 @@
+use crate::enums::UserRole;
+use crate::errors::AppError;
 use crate::models;
-use axum::http::StatusCode;
 use chrono::Utc;
 use jsonwebtoken::{DecodingKey, EncodingKey, Validation, decode, encode};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
 
 pub fn create_jwt_token(
     user_id: i32,
     email: String,
+    role: UserRole,
+    token_version: i32,
     jwt_secret: &str,
-    jwt_expire_hours: i64,
-) -> Result<String, (StatusCode, String)> {
+    jwt_expire_minutes: i64,
+) -> Result<String, AppError> {
     let now: i64 = Utc::now().timestamp();
     let claims: models::users::Claims = models::users::Claims {
         sub: user_id,
         email,
+        role,
+        jti: generate_token(),
+        ver: token_version,
         iat: now,
-        exp: now + (jwt_expire_hours * 3600),
+        exp: now + (jwt_expire_minutes * 60),
     };
 
     encode(
@@ -24,10 +32,7 @@ pub fn create_jwt_token(
     )
     .map_err(|e| {
         tracing::error!("Failed to generate token: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Failed to generate token".to_string(),
-        )
+        AppError::Internal("Failed to generate token".to_string())
     })
 }
 
@@ -40,3 +45,44 @@ pub fn extract_user_from_token(token: &str, secret: &str) -> Result<models::user
     .map(|data| data.claims)
     .map_err(|e| format!("Invalid token: {}", e))
 }
+
+/// Generates an opaque, URL-safe token with 256 bits of randomness.
+pub fn generate_token() -> String {
+    let mut bytes: [u8; 32] = [0; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Generates a 160-bit TOTP secret, base32-encoded as authenticator apps expect.
+pub fn generate_totp_secret() -> String {
+    let mut bytes: [u8; 20] = [0; 20];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    totp_rs::Secret::Raw(bytes.to_vec())
+        .to_encoded()
+        .to_string()
+}
+
+/// Hashes an opaque token for storage; only the hash is ever persisted.
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Address of the client that sent the request, taken from the first
+/// `X-Forwarded-For` entry when the proxy in front of us is trusted.
+pub fn client_ip(
+    headers: &axum::http::HeaderMap,
+    peer: std::net::SocketAddr,
+    trust_forwarded_for: bool,
+) -> String {
+    trust_forwarded_for
+        .then(|| {
+            headers
+                .get("x-forwarded-for")
+                .and_then(|h| h.to_str().ok())
+                .and_then(|h| h.split(',').next())
+                .map(|ip| ip.trim().to_string())
+                .filter(|ip| !ip.is_empty())
+        })
+        .flatten()
+        .unwrap_or_else(|| peer.ip().to_string())
+}
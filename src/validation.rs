@@ -0,0 +1,151 @@
+use axum::{
+    Json,
+    extract::{FromRequest, Request, rejection::JsonRejection},
+};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::errors::{AppError, FieldError};
+use crate::models::Patch;
+
+/// `Json<T>` that also checks the `#[validate(...)]` rules declared on `T`,
+/// rejecting the request with a 422 that lists every invalid field.
+pub struct ValidatedJson<T>(pub T);
+
+impl<S, T> FromRequest<S> for ValidatedJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value): Json<T> =
+            Json::<T>::from_request(req, state)
+                .await
+                .map_err(|rejection| match rejection {
+                    JsonRejection::JsonDataError(e) => AppError::Validation(vec![FieldError {
+                        field: "body".to_string(),
+                        code: "invalid_type".to_string(),
+                        message: e.body_text(),
+                    }]),
+                    rejection => AppError::BadRequest(rejection.body_text()),
+                })?;
+
+        value.validate()?;
+
+        Ok(Self(value))
+    }
+}
+
+impl From<ValidationErrors> for AppError {
+    fn from(errors: ValidationErrors) -> Self {
+        let mut fields: Vec<FieldError> = errors
+            .field_errors()
+            .into_iter()
+            .flat_map(|(field, errors)| {
+                errors.iter().map(move |error| FieldError {
+                    field: field.to_string(),
+                    code: error.code.to_string(),
+                    message: message(error),
+                })
+            })
+            .collect();
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+
+        AppError::Validation(fields)
+    }
+}
+
+/// Rule for IANA timezone names such as `Europe/Vienna`.
+pub fn validate_timezone(timezone: &str) -> Result<(), ValidationError> {
+    timezone.parse::<chrono_tz::Tz>().map(|_| ()).map_err(|_| {
+        ValidationError::new("timezone")
+            .with_message("must be an IANA timezone name such as Europe/Vienna".into())
+    })
+}
+
+/// Timezone rule for a patch field; absent and `null` are left to other rules.
+pub fn validate_timezone_patch(timezone: &Patch<String>) -> Result<(), ValidationError> {
+    match timezone {
+        Patch::Value(timezone) => validate_timezone(timezone),
+        Patch::Missing | Patch::Null => Ok(()),
+    }
+}
+
+/// Rule for ISO 639-1 language codes such as `en`.
+pub fn validate_language(language: &str) -> Result<(), ValidationError> {
+    if language.len() == 2 && language.bytes().all(|b| b.is_ascii_lowercase()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("language")
+            .with_message("must be a lowercase ISO 639-1 code such as en".into()))
+    }
+}
+
+/// Language rule for a patch field; absent and `null` are left to other rules.
+pub fn validate_language_patch(language: &Patch<String>) -> Result<(), ValidationError> {
+    match language {
+        Patch::Value(language) => validate_language(language),
+        Patch::Missing | Patch::Null => Ok(()),
+    }
+}
+
+/// Rejects strings made only of whitespace. Empty strings are left to the
+/// `length` rule so they report a single error.
+pub fn not_blank(value: &str) -> Result<(), ValidationError> {
+    if !value.is_empty() && value.trim().is_empty() {
+        Err(ValidationError::new("blank").with_message("cannot be blank".into()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Blank rule for a patch field; absent and `null` are left to other rules.
+pub fn not_blank_patch(value: &Patch<String>) -> Result<(), ValidationError> {
+    match value {
+        Patch::Value(value) => not_blank(value),
+        Patch::Missing | Patch::Null => Ok(()),
+    }
+}
+
+/// Rejects amounts too large for the DECIMAL(10, 2) money columns once rounded
+/// to cents. The sign is left to the handlers.
+pub fn validate_amount(amount: &Decimal) -> Result<(), ValidationError> {
+    // Largest value a DECIMAL(10, 2) column holds.
+    let max: Decimal = Decimal::new(9_999_999_999, 2);
+
+    if amount.round_dp(2).abs() > max {
+        Err(ValidationError::new("amount").with_message(format!("must be at most {}", max).into()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Rejects `null` for patch fields backed by a NOT NULL column.
+pub fn not_null<T>(value: &Patch<T>) -> Result<(), ValidationError> {
+    match value {
+        Patch::Null => {
+            Err(ValidationError::new("not_null").with_message("cannot be cleared".into()))
+        }
+        Patch::Missing | Patch::Value(_) => Ok(()),
+    }
+}
+
+fn message(error: &ValidationError) -> String {
+    if let Some(message) = &error.message {
+        return message.to_string();
+    }
+
+    match error.code.as_ref() {
+        "length" => match (error.params.get("min"), error.params.get("max")) {
+            (Some(min), Some(max)) => format!("must be between {} and {} characters", min, max),
+            (Some(min), None) => format!("must be at least {} characters", min),
+            (None, Some(max)) => format!("must be at most {} characters", max),
+            _ => "has an invalid length".to_string(),
+        },
+        "email" => "must be a valid email address".to_string(),
+        code => format!("failed the {} rule", code),
+    }
+}
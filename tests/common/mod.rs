@@ -0,0 +1,179 @@
+//! Helpers shared by the integration test suites. Each suite compiles this
+//! module on its own and only uses part of it.
+#![allow(dead_code)]
+
+use chrono::{Duration, NaiveDate, Utc};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub const BASE_URL: &str = "http://localhost:8000";
+/// Registered as an admin through `ADMIN_EMAILS`.
+pub const ADMIN_EMAIL: &str = "admin@example.com";
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub fn unique_email(prefix: &str) -> String {
+    format!(
+        "{}_{}_{}@example.com",
+        prefix,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis(),
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    )
+}
+
+/// Registers a guest under `email` and returns the response body, with the
+/// `user` and their tokens.
+pub async fn register_user(client: &reqwest::Client, email: &str) -> serde_json::Value {
+    client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email,
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()
+}
+
+/// Registers a guest and returns their access token.
+pub async fn register(client: &reqwest::Client, prefix: &str) -> String {
+    register_user(client, &unique_email(prefix)).await["token"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+pub async fn login(client: &reqwest::Client, email: &str) -> String {
+    let login_resp = client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({
+            "email": email,
+            "password": "password123"
+        }))
+        .send()
+        .await
+        .expect("Failed to login");
+    let login_body = login_resp.json::<serde_json::Value>().await.unwrap();
+    login_body["token"].as_str().unwrap().to_string()
+}
+
+pub async fn admin_token(client: &reqwest::Client) -> String {
+    // A conflict just means the admin already exists.
+    client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": ADMIN_EMAIL,
+            "password": "password123",
+            "first_name": "Ada",
+            "last_name": "Admin"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    login(client, ADMIN_EMAIL).await
+}
+
+/// Makes the user registered with `register_body` a hotel manager and returns
+/// a fresh token for them.
+pub async fn promote_to_manager(
+    client: &reqwest::Client,
+    register_body: &serde_json::Value,
+    email: &str,
+) -> String {
+    let admin = admin_token(client).await;
+    client
+        .put(format!(
+            "{}/admin/users/{}/role",
+            BASE_URL, register_body["user"]["id"]
+        ))
+        .header("Authorization", format!("Bearer {}", admin))
+        .json(&json!({ "role": "hotel_manager" }))
+        .send()
+        .await
+        .expect("Failed to promote user");
+
+    // Changing the role revokes the user's tokens, so log in again.
+    login(client, email).await
+}
+
+/// Registers a hotel manager and returns their access token.
+pub async fn register_manager(client: &reqwest::Client, prefix: &str) -> String {
+    let email = unique_email(prefix);
+    let register_body = register_user(client, &email).await;
+
+    promote_to_manager(client, &register_body, &email).await
+}
+
+pub fn days_from_today(days: i64) -> NaiveDate {
+    Utc::now().date_naive() + Duration::days(days)
+}
+
+/// Creates a hotel with a "Double" room type priced at 100.00 and `rooms`
+/// rooms of that type, returning the hotel and room type ids.
+pub async fn create_inventory(client: &reqwest::Client, token: &str, rooms: usize) -> (i64, i64) {
+    let hotel = json!({
+        "name": "Inventory Test Hotel",
+        "address": "1 Inventory St",
+        "city": "Vienna",
+        "country": "Austria"
+    });
+    create_hotel_inventory(client, token, &hotel, rooms).await
+}
+
+/// Like `create_inventory`, for a hotel created from the given payload.
+pub async fn create_hotel_inventory(
+    client: &reqwest::Client,
+    token: &str,
+    hotel: &serde_json::Value,
+    rooms: usize,
+) -> (i64, i64) {
+    let hotel_resp = client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(hotel)
+        .send()
+        .await
+        .expect("Failed to create hotel");
+    let hotel_id = hotel_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let room_type_resp = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Double",
+            "capacity": 2,
+            "bed_configuration": "1 queen",
+            "base_price": "100.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to create room type");
+    let room_type_id = room_type_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    for number in 0..rooms {
+        client
+            .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&json!({
+                "room_type_id": room_type_id,
+                "room_number": format!("{}", 100 + number)
+            }))
+            .send()
+            .await
+            .expect("Failed to create room");
+    }
+
+    (hotel_id, room_type_id)
+}
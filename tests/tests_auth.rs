@@ -0,0 +1,306 @@
+mod common;
+
+use common::{BASE_URL, register_user, unique_email};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn refresh(client: &reqwest::Client, refresh_token: &serde_json::Value) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/refresh", BASE_URL))
+        .json(&json!({ "refresh_token": refresh_token }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn profile(client: &reqwest::Client, token: &serde_json::Value) -> reqwest::Response {
+    client
+        .get(format!("{}/auth/profile", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", token.as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_register_returns_refresh_token() {
+    let client = reqwest::Client::new();
+
+    let body = register_user(&client, &unique_email("auth_pair")).await;
+
+    assert!(body["token"].is_string());
+    assert!(body["refresh_token"].is_string());
+    assert!(body["expires_in"].as_i64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn test_login_returns_refresh_token() {
+    let client = reqwest::Client::new();
+    let email = unique_email("auth_login_pair");
+    client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email,
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+
+    let response = client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": "password123" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert!(body["token"].is_string());
+    assert!(body["refresh_token"].is_string());
+}
+
+#[tokio::test]
+async fn test_refresh_200_rotates_tokens() {
+    let client = reqwest::Client::new();
+    let registered = register_user(&client, &unique_email("auth_refresh")).await;
+
+    let response = refresh(&client, &registered["refresh_token"]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_ne!(body["refresh_token"], registered["refresh_token"]);
+    assert_eq!(body["user"]["id"], registered["user"]["id"]);
+
+    let profile = client
+        .get(format!("{}/auth/profile", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", body["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(profile.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_refresh_401_unknown_token() {
+    let client = reqwest::Client::new();
+
+    let response = refresh(&client, &json!("not-a-refresh-token")).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_refresh_reuse_revokes_family() {
+    let client = reqwest::Client::new();
+    let registered = register_user(&client, &unique_email("auth_reuse")).await;
+    let first = registered["refresh_token"].clone();
+    let second = refresh(&client, &first)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["refresh_token"]
+        .clone();
+
+    let reused = refresh(&client, &first).await;
+    let after_reuse = refresh(&client, &second).await;
+
+    assert_eq!(reused.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(after_reuse.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_refresh_reuse_keeps_other_sessions() {
+    let client = reqwest::Client::new();
+    let email = unique_email("auth_sessions");
+    let registered = client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email,
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let other_session = client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": "password123" }))
+        .send()
+        .await
+        .expect("Failed to login")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    refresh(&client, &registered["refresh_token"]).await;
+    refresh(&client, &registered["refresh_token"]).await;
+
+    let response = refresh(&client, &other_session["refresh_token"]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_logout_204_revokes_access_token() {
+    let client = reqwest::Client::new();
+    let body = register_user(&client, &unique_email("auth_logout")).await;
+
+    let response = client
+        .post(format!("{}/auth/logout", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", body["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(
+        profile(&client, &body["token"]).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn test_logout_revokes_refresh_token() {
+    let client = reqwest::Client::new();
+    let body = register_user(&client, &unique_email("auth_logout_refresh")).await;
+
+    client
+        .post(format!("{}/auth/logout", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", body["token"].as_str().unwrap()),
+        )
+        .json(&json!({ "refresh_token": body["refresh_token"] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    let response = refresh(&client, &body["refresh_token"]).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_logout_keeps_other_sessions() {
+    let client = reqwest::Client::new();
+    let email = unique_email("auth_logout_other");
+    let registered = client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email,
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let other_session = client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": "password123" }))
+        .send()
+        .await
+        .expect("Failed to login")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+
+    client
+        .post(format!("{}/auth/logout", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", registered["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(
+        profile(&client, &other_session["token"]).await.status(),
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn test_logout_all_revokes_every_session() {
+    let client = reqwest::Client::new();
+    let email = unique_email("auth_logout_all");
+    let registered = client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email,
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let other_session = client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": "password123" }))
+        .send()
+        .await
+        .expect("Failed to login")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(format!("{}/auth/logout-all", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", registered["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(
+        profile(&client, &other_session["token"]).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        refresh(&client, &other_session["refresh_token"])
+            .await
+            .status(),
+        StatusCode::UNAUTHORIZED
+    );
+
+    let fresh = client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": "password123" }))
+        .send()
+        .await
+        .expect("Failed to login")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        profile(&client, &fresh["token"]).await.status(),
+        StatusCode::OK
+    );
+}
@@ -0,0 +1,468 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::{BASE_URL, create_inventory, days_from_today, register, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn book(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    check_in: NaiveDate,
+    check_out: NaiveDate,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "check_in": check_in,
+            "check_out": check_out,
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn post_action(
+    client: &reqwest::Client,
+    token: &str,
+    booking_id: i64,
+    action: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/bookings/{}/{}", BASE_URL, booking_id, action))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn post_staff_action(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    booking_id: i64,
+    action: &str,
+) -> reqwest::Response {
+    client
+        .post(format!(
+            "{}/hotels/{}/bookings/{}/{}",
+            BASE_URL, hotel_id, booking_id, action
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_create_booking_201_created() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "booker").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(10),
+        days_from_today(13),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["status"], "pending");
+    assert_eq!(body["nights"], 3);
+    assert_eq!(body["total_price"], "300.00");
+}
+
+#[tokio::test]
+async fn test_create_booking_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/bookings", BASE_URL))
+        .json(&json!({
+            "hotel_id": 1,
+            "room_type_id": 1,
+            "check_in": days_from_today(1),
+            "check_out": days_from_today(2),
+            "guests": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_create_booking_400_invalid_dates() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "booker_dates").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(5),
+        days_from_today(5),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_booking_400_too_many_guests() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "booker_guests").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "check_in": days_from_today(3),
+            "check_out": days_from_today(4),
+            "guests": 5
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_booking_409_no_availability() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "booker_full").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let first = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(20),
+        days_from_today(23),
+    )
+    .await;
+    assert_eq!(first.status(), StatusCode::CREATED);
+
+    let overlapping = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(22),
+        days_from_today(25),
+    )
+    .await;
+    let adjacent = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(23),
+        days_from_today(25),
+    )
+    .await;
+
+    assert_eq!(overlapping.status(), StatusCode::CONFLICT);
+    assert_eq!(adjacent.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_booking_lifecycle_200_ok() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "staff_lifecycle").await;
+    let guest_token = register(&client, "booker_lifecycle").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let create_resp = book(
+        &client,
+        &guest_token,
+        hotel_id,
+        room_type_id,
+        days_from_today(0),
+        days_from_today(1),
+    )
+    .await;
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    for (action, status) in [
+        ("confirm", "confirmed"),
+        ("check-in", "checked_in"),
+        ("check-out", "checked_out"),
+    ] {
+        let response =
+            post_staff_action(&client, &manager_token, hotel_id, booking_id, action).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.json::<serde_json::Value>().await.unwrap();
+        assert_eq!(body["status"], status);
+    }
+
+    let response = post_action(&client, &guest_token, booking_id, "cancel").await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_booking_transition_403_guest() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "staff_guarded").await;
+    let guest_token = register(&client, "booker_guarded").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let create_resp = book(
+        &client,
+        &guest_token,
+        hotel_id,
+        room_type_id,
+        days_from_today(0),
+        days_from_today(1),
+    )
+    .await;
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    for action in ["confirm", "check-in", "check-out", "no-show"] {
+        let response = post_staff_action(&client, &guest_token, hotel_id, booking_id, action).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
+
+#[tokio::test]
+async fn test_booking_transition_404_other_hotel() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "staff_foreign").await;
+    let guest_token = register(&client, "booker_foreign").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let (other_hotel_id, _) = create_inventory(&client, &manager_token, 1).await;
+    let create_resp = book(
+        &client,
+        &guest_token,
+        hotel_id,
+        room_type_id,
+        days_from_today(3),
+        days_from_today(4),
+    )
+    .await;
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let response = post_staff_action(
+        &client,
+        &manager_token,
+        other_hotel_id,
+        booking_id,
+        "confirm",
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_booking_409_before_arrival() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "staff_early").await;
+    let guest_token = register(&client, "booker_early").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let create_resp = book(
+        &client,
+        &guest_token,
+        hotel_id,
+        room_type_id,
+        days_from_today(2),
+        days_from_today(3),
+    )
+    .await;
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    let confirm = post_staff_action(&client, &manager_token, hotel_id, booking_id, "confirm").await;
+    assert_eq!(confirm.status(), StatusCode::OK);
+
+    for action in ["check-in", "no-show"] {
+        let response =
+            post_staff_action(&client, &manager_token, hotel_id, booking_id, action).await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    let cancel = post_action(&client, &guest_token, booking_id, "cancel").await;
+    assert_eq!(cancel.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_booking_409_illegal_transition() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "staff_illegal").await;
+    let guest_token = register(&client, "booker_illegal").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let create_resp = book(
+        &client,
+        &guest_token,
+        hotel_id,
+        room_type_id,
+        days_from_today(0),
+        days_from_today(2),
+    )
+    .await;
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let check_in =
+        post_staff_action(&client, &manager_token, hotel_id, booking_id, "check-in").await;
+    assert_eq!(check_in.status(), StatusCode::CONFLICT);
+
+    let cancel = post_action(&client, &guest_token, booking_id, "cancel").await;
+    assert_eq!(cancel.status(), StatusCode::OK);
+
+    let confirm = post_staff_action(&client, &manager_token, hotel_id, booking_id, "confirm").await;
+    assert_eq!(confirm.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_cancelled_booking_releases_inventory() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "booker_release").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let create_resp = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(30),
+        days_from_today(31),
+    )
+    .await;
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    post_action(&client, &token, booking_id, "cancel").await;
+
+    let response = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(30),
+        days_from_today(31),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_get_booking_404_other_user() {
+    let client = reqwest::Client::new();
+    let owner_token = register_manager(&client, "booking_owner").await;
+    let other_token = register(&client, "booking_other").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &owner_token, 1).await;
+    let create_resp = book(
+        &client,
+        &owner_token,
+        hotel_id,
+        room_type_id,
+        days_from_today(7),
+        days_from_today(8),
+    )
+    .await;
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let response = client
+        .get(format!("{}/bookings/{}", BASE_URL, booking_id))
+        .header("Authorization", format!("Bearer {}", other_token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["code"], "not_found");
+    assert_eq!(body["detail"], "Booking not found");
+}
+
+#[tokio::test]
+async fn test_list_bookings_200_ok() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "booking_lister").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 2).await;
+    book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(2),
+        days_from_today(3),
+    )
+    .await;
+
+    let response = client
+        .get(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_concurrent_bookings_never_overbook() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "booker_concurrent").await;
+    let rooms = 3;
+    let attempts = 20;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, rooms).await;
+    let check_in = days_from_today(40);
+    let check_out = days_from_today(42);
+
+    let mut handles = Vec::new();
+    for _ in 0..attempts {
+        let client = client.clone();
+        let token = token.clone();
+        handles.push(tokio::spawn(async move {
+            book(&client, &token, hotel_id, room_type_id, check_in, check_out)
+                .await
+                .status()
+        }));
+    }
+
+    let mut created = 0;
+    let mut conflicts = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            StatusCode::CREATED => created += 1,
+            StatusCode::CONFLICT => conflicts += 1,
+            status => panic!("Unexpected status {}", status),
+        }
+    }
+
+    assert_eq!(created, rooms);
+    assert_eq!(conflicts, attempts - rooms);
+}
@@ -0,0 +1,307 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use common::{BASE_URL, create_hotel_inventory, days_from_today, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+fn hotel(timezone: &str) -> serde_json::Value {
+    json!({
+        "name": "Cancellation Test Hotel",
+        "address": "1 Refund Rd",
+        "city": "Vienna",
+        "country": "Austria",
+        "timezone": timezone,
+        "check_in_time": "00:00:00"
+    })
+}
+
+async fn create_rate_plan(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    policy: serde_json::Value,
+) -> reqwest::Response {
+    client
+        .post(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans",
+            BASE_URL, hotel_id, room_type_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Flexible",
+            "base_price": "120.00",
+            "cancellation_policy": policy
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn book_and_cancel(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    rate_plan_id: Option<i64>,
+    check_in: NaiveDate,
+) -> serde_json::Value {
+    let create_resp = client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "rate_plan_id": rate_plan_id,
+            "check_in": check_in,
+            "check_out": check_in + Duration::days(2),
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(create_resp.status(), StatusCode::CREATED);
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let cancel_resp = client
+        .post(format!("{}/bookings/{}/cancel", BASE_URL, booking_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(cancel_resp.status(), StatusCode::OK);
+    cancel_resp.json::<serde_json::Value>().await.unwrap()
+}
+
+async fn rate_plan_id(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    policy: serde_json::Value,
+) -> i64 {
+    let response = create_rate_plan(client, token, hotel_id, room_type_id, policy).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    response.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_create_rate_plan_default_cancellation_policy() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "policy_default").await;
+    let (hotel_id, room_type_id) = create_hotel_inventory(&client, &token, &hotel("UTC"), 1).await;
+
+    let response = client
+        .post(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans",
+            BASE_URL, hotel_id, room_type_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "Standard", "base_price": "100.00" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["cancellation_policy"]["free_cancellation_hours"], 24);
+    assert_eq!(body["cancellation_policy"]["penalty"], "first_night");
+}
+
+#[tokio::test]
+async fn test_create_rate_plan_400_percentage_without_percent() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "policy_invalid").await;
+    let (hotel_id, room_type_id) = create_hotel_inventory(&client, &token, &hotel("UTC"), 1).await;
+
+    let response = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({ "free_cancellation_hours": 24, "penalty": "percentage" }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_hotel_422_unknown_timezone() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "policy_timezone").await;
+
+    let response = client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&hotel("Mars/Olympus_Mons"))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "timezone");
+}
+
+#[tokio::test]
+async fn test_cancel_booking_free_before_deadline() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "cancel_free").await;
+    let (hotel_id, room_type_id) = create_hotel_inventory(&client, &token, &hotel("UTC"), 1).await;
+
+    let body = book_and_cancel(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        None,
+        days_from_today(30),
+    )
+    .await;
+
+    assert_eq!(body["status"], "cancelled");
+    assert_eq!(body["penalty_amount"], "0.00");
+    assert_eq!(body["refund_amount"], "200.00");
+}
+
+#[tokio::test]
+async fn test_cancel_booking_first_night_penalty() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "cancel_first_night").await;
+    let (hotel_id, room_type_id) = create_hotel_inventory(&client, &token, &hotel("UTC"), 1).await;
+    let rate_plan_id = rate_plan_id(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({ "free_cancellation_hours": 10000, "penalty": "first_night" }),
+    )
+    .await;
+
+    let body = book_and_cancel(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        Some(rate_plan_id),
+        days_from_today(30),
+    )
+    .await;
+
+    assert_eq!(body["penalty_amount"], "120.00");
+    assert_eq!(body["refund_amount"], "120.00");
+}
+
+#[tokio::test]
+async fn test_cancel_booking_percentage_penalty() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "cancel_percentage").await;
+    let (hotel_id, room_type_id) = create_hotel_inventory(&client, &token, &hotel("UTC"), 1).await;
+    let rate_plan_id = rate_plan_id(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({
+            "free_cancellation_hours": 10000,
+            "penalty": "percentage",
+            "penalty_percent": "25"
+        }),
+    )
+    .await;
+
+    let body = book_and_cancel(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        Some(rate_plan_id),
+        days_from_today(30),
+    )
+    .await;
+
+    assert_eq!(body["penalty_amount"], "60.00");
+    assert_eq!(body["refund_amount"], "180.00");
+}
+
+#[tokio::test]
+async fn test_cancel_booking_non_refundable() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "cancel_non_refundable").await;
+    let (hotel_id, room_type_id) = create_hotel_inventory(&client, &token, &hotel("UTC"), 1).await;
+    let rate_plan_id = rate_plan_id(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({ "free_cancellation_hours": 0, "penalty": "non_refundable" }),
+    )
+    .await;
+
+    let body = book_and_cancel(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        Some(rate_plan_id),
+        days_from_today(60),
+    )
+    .await;
+
+    assert_eq!(body["penalty_amount"], "240.00");
+    assert_eq!(body["refund_amount"], "0.00");
+}
+
+#[tokio::test]
+async fn test_cancel_booking_deadline_uses_hotel_timezone() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "cancel_timezone").await;
+    let policy = json!({ "free_cancellation_hours": 34, "penalty": "first_night" });
+    let check_in = days_from_today(2);
+
+    // Midnight on the check-in date is 26 hours earlier in UTC+14 than in UTC-12, which moves
+    // the 34 hour deadline to before today in one hotel and past today in the other.
+    let (east_hotel_id, east_room_type_id) =
+        create_hotel_inventory(&client, &token, &hotel("Pacific/Kiritimati"), 1).await;
+    let east_plan_id = rate_plan_id(
+        &client,
+        &token,
+        east_hotel_id,
+        east_room_type_id,
+        policy.clone(),
+    )
+    .await;
+    let (west_hotel_id, west_room_type_id) =
+        create_hotel_inventory(&client, &token, &hotel("Etc/GMT+12"), 1).await;
+    let west_plan_id =
+        rate_plan_id(&client, &token, west_hotel_id, west_room_type_id, policy).await;
+
+    let east = book_and_cancel(
+        &client,
+        &token,
+        east_hotel_id,
+        east_room_type_id,
+        Some(east_plan_id),
+        check_in,
+    )
+    .await;
+    let west = book_and_cancel(
+        &client,
+        &token,
+        west_hotel_id,
+        west_room_type_id,
+        Some(west_plan_id),
+        check_in,
+    )
+    .await;
+
+    assert_eq!(east["penalty_amount"], "120.00");
+    assert_eq!(west["penalty_amount"], "0.00");
+    assert_eq!(west["refund_amount"], "240.00");
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use common::unique_email;
+use sqlx::{Pool, Postgres};
+
+/// Connects to the API's database with the same settings as the server, but
+/// through `localhost` like `BASE_URL`.
+async fn pool() -> Pool<Postgres> {
+    dotenvy::dotenv().ok();
+    let var = |name: &str, default: &str| std::env::var(name).unwrap_or(default.to_string());
+
+    sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&format!(
+            "postgres://{}:{}@localhost:{}/{}",
+            var("POSTGRES_USER", "postgres"),
+            var("POSTGRES_PASSWORD", "postgres"),
+            var("POSTGRES_PORT", "5432"),
+            var("POSTGRES_DB", "postgres")
+        ))
+        .await
+        .expect("Failed to connect to Postgres")
+}
+
+/// Queues an email that is due now, the way the services do.
+async fn enqueue(pool: &Pool<Postgres>, recipient: &str) -> i64 {
+    sqlx::query_scalar(
+        "INSERT INTO email_outbox (recipient, subject, body, next_attempt_at)
+         VALUES ($1, 'Outbox test', 'Hello', $2)
+         RETURNING id",
+    )
+    .bind(recipient)
+    .bind(chrono::Utc::now().naive_utc())
+    .fetch_one(pool)
+    .await
+    .expect("Failed to queue email")
+}
+
+#[derive(sqlx::FromRow)]
+struct Delivery {
+    status: String,
+    attempts: i32,
+    next_attempt_at: chrono::NaiveDateTime,
+    last_error: Option<String>,
+    sent_at: Option<chrono::NaiveDateTime>,
+}
+
+async fn delivery(pool: &Pool<Postgres>, id: i64) -> Delivery {
+    sqlx::query_as(
+        "SELECT status, attempts, next_attempt_at, last_error, sent_at
+         FROM email_outbox WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to load email")
+}
+
+/// Waits for the worker to make its first attempt at the email.
+async fn first_attempt(pool: &Pool<Postgres>, id: i64) -> Delivery {
+    for _ in 0..40 {
+        let delivery: Delivery = delivery(pool, id).await;
+        if delivery.attempts > 0 {
+            return delivery;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+    }
+
+    panic!("Email {} was never picked up by the worker", id);
+}
+
+#[tokio::test]
+async fn test_worker_sends_queued_email_once() {
+    let pool = pool().await;
+    let id = enqueue(&pool, &unique_email("outbox_sent")).await;
+
+    let sent = first_attempt(&pool, id).await;
+    // Give the worker a few more ticks to pick the email up again.
+    tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+    let later = delivery(&pool, id).await;
+
+    assert_eq!(sent.status, "sent");
+    assert_eq!(sent.attempts, 1);
+    assert!(sent.sent_at.is_some());
+    assert!(sent.last_error.is_none());
+    assert_eq!(later.status, "sent");
+    assert_eq!(later.attempts, 1);
+    assert_eq!(later.sent_at, sent.sent_at);
+}
+
+#[tokio::test]
+async fn test_worker_backs_off_after_failed_delivery() {
+    let pool = pool().await;
+    let queued_at = chrono::Utc::now().naive_utc();
+    let id = enqueue(&pool, "not-an-address").await;
+
+    let failed = first_attempt(&pool, id).await;
+    tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+    let later = delivery(&pool, id).await;
+
+    assert_eq!(failed.status, "pending");
+    assert_eq!(failed.attempts, 1);
+    assert!(failed.sent_at.is_none());
+    assert!(
+        failed
+            .last_error
+            .as_deref()
+            .is_some_and(|e| e.starts_with("invalid message"))
+    );
+    // The first retry waits 30 seconds.
+    assert!(failed.next_attempt_at >= queued_at + chrono::Duration::seconds(29));
+    assert_eq!(later.attempts, 1);
+    assert_eq!(later.next_attempt_at, failed.next_attempt_at);
+}
+
+#[tokio::test]
+async fn test_register_queues_verification_email_with_user() {
+    let pool: Pool<Postgres> = pool().await;
+    let email: String = unique_email("outbox_register");
+    common::register_user(&reqwest::Client::new(), &email).await;
+
+    let tokens: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM email_verification_tokens t
+         JOIN users u ON u.id = t.user_id WHERE u.email = $1",
+    )
+    .bind(&email)
+    .fetch_one(&pool)
+    .await
+    .expect("Failed to count tokens");
+    let subjects: Vec<String> =
+        sqlx::query_scalar("SELECT subject FROM email_outbox WHERE recipient = $1")
+            .bind(&email)
+            .fetch_all(&pool)
+            .await
+            .expect("Failed to load emails");
+
+    assert_eq!(tokens, 1);
+    assert_eq!(subjects, vec!["Verify your email address"]);
+}
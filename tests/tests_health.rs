@@ -1,6 +1,7 @@
-use reqwest::StatusCode;
+mod common;
 
-const BASE_URL: &str = "http://localhost:8000";
+use common::BASE_URL;
+use reqwest::StatusCode;
 
 #[tokio::test]
 async fn test_health_live_200_ok() {
@@ -0,0 +1,235 @@
+mod common;
+
+use common::{BASE_URL, create_inventory, days_from_today, register, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+fn stay(hotel_id: i64, room_type_id: i64) -> serde_json::Value {
+    json!({
+        "hotel_id": hotel_id,
+        "room_type_id": room_type_id,
+        "check_in": days_from_today(30),
+        "check_out": days_from_today(32),
+        "guests": 2
+    })
+}
+
+async fn create_hold(
+    client: &reqwest::Client,
+    token: &str,
+    payload: &serde_json::Value,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/holds", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(payload)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn create_booking(
+    client: &reqwest::Client,
+    token: &str,
+    payload: &serde_json::Value,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(payload)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn release_hold(client: &reqwest::Client, token: &str, hold_id: &str) -> reqwest::Response {
+    client
+        .delete(format!("{}/holds/{}", BASE_URL, hold_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn first_night(client: &reqwest::Client, token: &str, hotel_id: i64) -> serde_json::Value {
+    let response = client
+        .get(format!(
+            "{}/hotels/{}/calendar?from={}&to={}",
+            BASE_URL,
+            hotel_id,
+            days_from_today(30),
+            days_from_today(30)
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request");
+    response.json::<serde_json::Value>().await.unwrap()[0]["days"][0].clone()
+}
+
+fn with_hold(payload: &serde_json::Value, hold_id: &str) -> serde_json::Value {
+    let mut payload = payload.clone();
+    payload["hold_id"] = json!(hold_id);
+    payload
+}
+
+#[tokio::test]
+async fn test_create_hold_201_created() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "holder_create").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = create_hold(&client, &token, &stay(hotel_id, room_type_id)).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["status"], "active");
+    assert!(body["id"].is_string());
+    assert!(body["expires_at"].is_string());
+
+    let night = first_night(&client, &token, hotel_id).await;
+    assert_eq!(night["held"], 1);
+    assert_eq!(night["available"], 0);
+}
+
+#[tokio::test]
+async fn test_create_hold_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/holds", BASE_URL))
+        .json(&stay(1, 1))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_held_inventory_409_for_others() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "holder_blocking").await;
+    let other_token = register(&client, "holder_blocked").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let payload = stay(hotel_id, room_type_id);
+    create_hold(&client, &token, &payload).await;
+
+    let hold_resp = create_hold(&client, &other_token, &payload).await;
+    let booking_resp = create_booking(&client, &other_token, &payload).await;
+
+    assert_eq!(hold_resp.status(), StatusCode::CONFLICT);
+    assert_eq!(booking_resp.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_booking_consumes_hold() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "holder_consume").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let payload = stay(hotel_id, room_type_id);
+    let hold_resp = create_hold(&client, &token, &payload).await;
+    let hold_id = hold_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = create_booking(&client, &token, &with_hold(&payload, &hold_id)).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["hold_id"], hold_id.as_str());
+
+    let night = first_night(&client, &token, hotel_id).await;
+    assert_eq!(night["held"], 0);
+    assert_eq!(night["sold"], 1);
+
+    let hold = client
+        .get(format!("{}/holds/{}", BASE_URL, hold_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert_eq!(hold["status"], "consumed");
+
+    let reuse = create_booking(&client, &token, &with_hold(&payload, &hold_id)).await;
+    assert_eq!(reuse.status(), StatusCode::GONE);
+    let body = reuse.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["code"], "gone");
+}
+
+#[tokio::test]
+async fn test_booking_with_hold_400_mismatch() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "holder_mismatch").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let payload = stay(hotel_id, room_type_id);
+    let hold_resp = create_hold(&client, &token, &payload).await;
+    let hold_id = hold_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let mut mismatched = with_hold(&payload, &hold_id);
+    mismatched["guests"] = json!(1);
+    let response = create_booking(&client, &token, &mismatched).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_hold_404_other_user() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "holder_owner").await;
+    let other_token = register(&client, "holder_intruder").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let payload = stay(hotel_id, room_type_id);
+    let hold_resp = create_hold(&client, &token, &payload).await;
+    let hold_id = hold_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let get_resp = client
+        .get(format!("{}/holds/{}", BASE_URL, hold_id))
+        .header("Authorization", format!("Bearer {}", other_token))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let booking_resp = create_booking(&client, &other_token, &with_hold(&payload, &hold_id)).await;
+
+    assert_eq!(get_resp.status(), StatusCode::NOT_FOUND);
+    assert_eq!(booking_resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_release_hold_frees_inventory() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "holder_release").await;
+    let other_token = register(&client, "holder_after_release").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let payload = stay(hotel_id, room_type_id);
+    let hold_resp = create_hold(&client, &token, &payload).await;
+    let hold_id = hold_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = release_hold(&client, &token, &hold_id).await;
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(first_night(&client, &token, hotel_id).await["held"], 0);
+    assert_eq!(
+        create_booking(&client, &other_token, &payload)
+            .await
+            .status(),
+        StatusCode::CREATED
+    );
+    assert_eq!(
+        release_hold(&client, &token, &hold_id).await.status(),
+        StatusCode::GONE
+    );
+}
@@ -1,7 +1,55 @@
+mod common;
+
+use common::{BASE_URL, promote_to_manager};
 use reqwest::StatusCode;
 use serde_json::json;
 
-const BASE_URL: &str = "http://localhost:8000";
+async fn manager_with_hotel(client: &reqwest::Client, prefix: &str) -> (String, i64) {
+    let email = format!(
+        "{}_{}@example.com",
+        prefix,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    );
+    let register_body = client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email.clone(),
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let token = promote_to_manager(client, &register_body, &email).await;
+    let hotel_id = client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Original Hotel",
+            "description": "Original description",
+            "address": "123 Main St",
+            "city": "Boston",
+            "country": "USA",
+            "timezone": "America/New_York",
+            "check_in_time": "14:00:00"
+        }))
+        .send()
+        .await
+        .expect("Failed to create hotel")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    (token, hotel_id)
+}
 
 #[tokio::test]
 async fn test_list_hotels_200_ok() {
@@ -16,6 +64,168 @@ async fn test_list_hotels_200_ok() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+async fn create_hotel_in(client: &reqwest::Client, token: &str, name: &str, city: &str) -> i64 {
+    client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": name,
+            "address": "1 List St",
+            "city": city,
+            "country": "Listland"
+        }))
+        .send()
+        .await
+        .expect("Failed to create hotel")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap()
+}
+
+async fn list_page(client: &reqwest::Client, query: &str) -> reqwest::Response {
+    client
+        .get(format!("{}/hotels?{}", BASE_URL, query))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+fn names(page: &serde_json::Value) -> Vec<&str> {
+    page["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|hotel| hotel["name"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_list_hotels_paginates_with_cursor() {
+    let client = reqwest::Client::new();
+    let (token, _) = manager_with_hotel(&client, "lister").await;
+    let city = format!(
+        "Pagetown{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    );
+    for name in ["Cedar", "Alder", "Birch"] {
+        create_hotel_in(&client, &token, name, &city).await;
+    }
+
+    let response = list_page(&client, &format!("city={}&limit=2", city)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let first = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(names(&first), vec!["Alder", "Birch"]);
+    assert_eq!(first["limit"], 2);
+    assert_eq!(first["total_estimate"], 3);
+    let cursor = first["next_cursor"].as_str().unwrap();
+
+    let second = list_page(&client, &format!("city={}&limit=2&cursor={}", city, cursor))
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert_eq!(names(&second), vec!["Cedar"]);
+    assert!(second["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn test_list_hotels_sorts_and_filters() {
+    let client = reqwest::Client::new();
+    let (token, _) = manager_with_hotel(&client, "sorter").await;
+    let city = format!(
+        "Sortville{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    );
+    for name in ["Alder", "Birch", "Cedar"] {
+        create_hotel_in(&client, &token, name, &city).await;
+    }
+
+    let page = list_page(&client, &format!("city={}&sort=name&order=desc", city))
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert_eq!(names(&page), vec!["Cedar", "Birch", "Alder"]);
+
+    let page = list_page(&client, &format!("city={}&sort=created_at&limit=1", city))
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert_eq!(names(&page), vec!["Alder"]);
+
+    // New hotels are unrated.
+    let page = list_page(&client, &format!("city={}&min_rating=1", city))
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert!(names(&page).is_empty());
+    assert_eq!(page["total_estimate"], 0);
+}
+
+#[tokio::test]
+async fn test_list_hotels_city_filter_is_literal() {
+    let client = reqwest::Client::new();
+    let (token, _) = manager_with_hotel(&client, "literal").await;
+    let city = format!(
+        "Literalton{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    );
+    create_hotel_in(&client, &token, "Aspen", &city).await;
+
+    let page = list_page(&client, &format!("city={}", city.to_uppercase()))
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert_eq!(names(&page), vec!["Aspen"]);
+
+    // `%25` is `%`; LIKE wildcards must not widen the match.
+    for pattern in ["Literalton%25", "%25", "Literalton_%25"] {
+        let page = list_page(&client, &format!("city={}", pattern))
+            .await
+            .json::<serde_json::Value>()
+            .await
+            .unwrap();
+        assert!(names(&page).is_empty(), "{}", pattern);
+        assert_eq!(page["total_estimate"], 0);
+    }
+}
+
+#[tokio::test]
+async fn test_list_hotels_400_invalid_cursor_or_limit() {
+    let client = reqwest::Client::new();
+
+    let response = list_page(&client, "limit=0").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = list_page(&client, "cursor=zz").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let page = list_page(&client, "limit=1")
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let cursor = page["next_cursor"].as_str().unwrap();
+    let response = list_page(&client, &format!("sort=rating&cursor={}", cursor)).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["code"], "bad_request");
+}
+
 #[tokio::test]
 async fn test_get_hotel_200_ok() {
     let client = reqwest::Client::new();
@@ -39,7 +249,7 @@ async fn test_get_hotel_200_ok() {
         .await
         .expect("Failed to register");
     let register_body = register_resp.json::<serde_json::Value>().await.unwrap();
-    let token = register_body["token"].as_str().unwrap();
+    let token = &promote_to_manager(&client, &register_body, &email).await;
     let create_payload = json!({
         "name": "Test Hotel",
         "description": "A test hotel",
@@ -80,8 +290,16 @@ async fn test_get_hotel_404_not_found() {
         .expect("Failed to send request");
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
-    let body = response.text().await.unwrap();
-    assert!(body.contains("not found") || body.contains("Hotel"));
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["type"], "about:blank");
+    assert_eq!(body["title"], "Not Found");
+    assert_eq!(body["status"], 404);
+    assert_eq!(body["code"], "not_found");
+    assert_eq!(body["detail"], "Hotel not found");
 }
 
 #[tokio::test]
@@ -107,7 +325,7 @@ async fn test_create_hotel_201_created() {
         .await
         .expect("Failed to register");
     let register_body = register_resp.json::<serde_json::Value>().await.unwrap();
-    let token = register_body["token"].as_str().unwrap();
+    let token = &promote_to_manager(&client, &register_body, &email).await;
     let create_payload = json!({
         "name": "Luxury Hotel",
         "description": "A luxury 5-star hotel",
@@ -153,7 +371,7 @@ async fn test_create_hotel_422_invalid_input() {
         .await
         .expect("Failed to register");
     let register_body = register_resp.json::<serde_json::Value>().await.unwrap();
-    let token = register_body["token"].as_str().unwrap();
+    let token = &promote_to_manager(&client, &register_body, &email).await;
     let create_payload = json!({"name": "Hotel"});
 
     let response = client
@@ -167,6 +385,61 @@ async fn test_create_hotel_422_invalid_input() {
     assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
 }
 
+#[tokio::test]
+async fn test_create_hotel_422_field_errors() {
+    let client = reqwest::Client::new();
+    let email = format!(
+        "creator_fields_{}@example.com",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    );
+    let register_body = client
+        .post(&format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email.clone(),
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let token = &promote_to_manager(&client, &register_body, &email).await;
+
+    let response = client
+        .post(&format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "",
+            "address": "123 Main St",
+            "city": "New York",
+            "country": "x".repeat(10_000)
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["code"], "validation_failed");
+    let fields: Vec<&str> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["country", "name"]);
+}
+
 #[tokio::test]
 async fn test_create_hotel_401_unauthorized() {
     let client = reqwest::Client::new();
@@ -233,7 +506,7 @@ async fn test_update_hotel_200_ok() {
         .await
         .expect("Failed to register");
     let register_body = register_resp.json::<serde_json::Value>().await.unwrap();
-    let token = register_body["token"].as_str().unwrap();
+    let token = &promote_to_manager(&client, &register_body, &email).await;
     let create_payload = json!({
         "name": "Original Hotel",
         "description": "Original description",
@@ -333,7 +606,10 @@ async fn test_update_hotel_404_not_found() {
     let register_body = register_resp.json::<serde_json::Value>().await.unwrap();
     let token = register_body["token"].as_str().unwrap();
     let update_payload = json!({
-        "name": "Updated Hotel"
+        "name": "Updated Hotel",
+        "address": "456 Elm St",
+        "city": "New York",
+        "country": "USA"
     });
 
     let response = client
@@ -349,6 +625,133 @@ async fn test_update_hotel_404_not_found() {
     assert!(body.contains("not found") || body.contains("Hotel"));
 }
 
+#[tokio::test]
+async fn test_update_hotel_resets_omitted_optional_fields() {
+    let client = reqwest::Client::new();
+    let (token, hotel_id) = manager_with_hotel(&client, "replacer").await;
+
+    let response = client
+        .put(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Replaced Hotel",
+            "address": "456 Elm St",
+            "city": "New York",
+            "country": "USA"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["name"], "Replaced Hotel");
+    assert!(body["description"].is_null());
+    assert_eq!(body["timezone"], "UTC");
+    assert_eq!(body["check_in_time"], "15:00:00");
+    assert_eq!(body["language"], "en");
+}
+
+#[tokio::test]
+async fn test_update_hotel_422_missing_required_fields() {
+    let client = reqwest::Client::new();
+    let (token, hotel_id) = manager_with_hotel(&client, "replacer2").await;
+
+    let response = client
+        .put(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "Only A Name" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_patch_hotel_200_leaves_absent_fields() {
+    let client = reqwest::Client::new();
+    let (token, hotel_id) = manager_with_hotel(&client, "patcher").await;
+
+    let response = client
+        .patch(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("Content-Type", "application/merge-patch+json")
+        .body(json!({ "name": "Patched Hotel" }).to_string())
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["name"], "Patched Hotel");
+    assert_eq!(body["description"], "Original description");
+    assert_eq!(body["address"], "123 Main St");
+    assert_eq!(body["city"], "Boston");
+    assert_eq!(body["timezone"], "America/New_York");
+    assert_eq!(body["check_in_time"], "14:00:00");
+}
+
+#[tokio::test]
+async fn test_patch_hotel_200_null_clears_description() {
+    let client = reqwest::Client::new();
+    let (token, hotel_id) = manager_with_hotel(&client, "patcher2").await;
+
+    let response = client
+        .patch(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "description": null }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert!(body["description"].is_null());
+    assert_eq!(body["name"], "Original Hotel");
+}
+
+#[tokio::test]
+async fn test_patch_hotel_422_null_required_field() {
+    let client = reqwest::Client::new();
+    let (token, hotel_id) = manager_with_hotel(&client, "patcher3").await;
+
+    let response = client
+        .patch(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": null, "city": "" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "city");
+    assert_eq!(body["errors"][0]["code"], "length");
+    assert_eq!(body["errors"][1]["field"], "name");
+    assert_eq!(body["errors"][1]["code"], "not_null");
+}
+
+#[tokio::test]
+async fn test_patch_hotel_422_blank_field() {
+    let client = reqwest::Client::new();
+    let (token, hotel_id) = manager_with_hotel(&client, "patcher_blank").await;
+
+    let response = client
+        .patch(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "   ", "country": "Austria" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"].as_array().unwrap().len(), 1);
+    assert_eq!(body["errors"][0]["field"], "name");
+    assert_eq!(body["errors"][0]["code"], "blank");
+}
+
 #[tokio::test]
 async fn test_delete_hotel_204_no_content() {
     let client = reqwest::Client::new();
@@ -372,7 +775,7 @@ async fn test_delete_hotel_204_no_content() {
         .await
         .expect("Failed to register");
     let register_body = register_resp.json::<serde_json::Value>().await.unwrap();
-    let token = register_body["token"].as_str().unwrap();
+    let token = &promote_to_manager(&client, &register_body, &email).await;
     let create_payload = json!({
         "name": "Hotel to Delete",
         "address": "789 Pine St",
@@ -462,3 +865,362 @@ async fn test_delete_hotel_404_not_found() {
     let body = response.text().await.unwrap();
     assert!(body.contains("not found") || body.contains("Hotel"));
 }
+
+async fn create_hotel_with_room_types(
+    client: &reqwest::Client,
+    token: &str,
+    city: &str,
+    prices: &[&str],
+) -> (i64, Vec<i64>) {
+    let create_resp = client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Availability Hotel",
+            "address": "1 Search St",
+            "city": city,
+            "country": "Czechia"
+        }))
+        .send()
+        .await
+        .expect("Failed to create hotel");
+    let hotel_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let mut room_type_ids = Vec::new();
+    for (index, price) in prices.iter().enumerate() {
+        let room_type_resp = client
+            .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&json!({
+                "name": format!("Type {}", index),
+                "capacity": 2,
+                "bed_configuration": "1 double",
+                "base_price": price
+            }))
+            .send()
+            .await
+            .expect("Failed to create room type");
+        let room_type_id = room_type_resp.json::<serde_json::Value>().await.unwrap()["id"]
+            .as_i64()
+            .unwrap();
+        client
+            .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&json!({
+                "room_type_id": room_type_id,
+                "room_number": format!("{}", 100 + index)
+            }))
+            .send()
+            .await
+            .expect("Failed to create room");
+        room_type_ids.push(room_type_id);
+    }
+
+    (hotel_id, room_type_ids)
+}
+
+async fn register_availability_user(client: &reqwest::Client, prefix: &str) -> String {
+    let email = format!(
+        "{}_{}@example.com",
+        prefix,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let register_resp = client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": email,
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    let register_body = register_resp.json::<serde_json::Value>().await.unwrap();
+    promote_to_manager(client, &register_body, &email).await
+}
+
+#[tokio::test]
+async fn test_search_availability_200_ok() {
+    let client = reqwest::Client::new();
+    let token = register_availability_user(&client, "availability").await;
+    let city = format!(
+        "Prague{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let (hotel_id, _) =
+        create_hotel_with_room_types(&client, &token, &city, &["150.00", "90.00"]).await;
+    let (empty_hotel_id, _) = create_hotel_with_room_types(&client, &token, &city, &[]).await;
+    let check_in = chrono::Utc::now().date_naive() + chrono::Duration::days(10);
+    let check_out = check_in + chrono::Duration::days(2);
+
+    let response = client
+        .get(format!("{}/hotels/availability", BASE_URL))
+        .query(&[
+            ("city", city.clone()),
+            ("check_in", check_in.to_string()),
+            ("check_out", check_out.to_string()),
+            ("guests", "2".to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let results = body.as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["hotel"]["id"], hotel_id);
+    assert_ne!(results[0]["hotel"]["id"], empty_hotel_id);
+    assert_eq!(results[0]["cheapest_total_price"], "180.00");
+    assert!(results[0]["rate_plan_id"].is_null());
+}
+
+#[tokio::test]
+async fn test_search_availability_prices_rate_plans_and_taxes() {
+    let client = reqwest::Client::new();
+    let token = register_availability_user(&client, "availability_priced").await;
+    let city = format!(
+        "Olomouc{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let (hotel_id, room_type_ids) =
+        create_hotel_with_room_types(&client, &token, &city, &["100.00"]).await;
+    let rate_plan_id = client
+        .post(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans",
+            BASE_URL, hotel_id, room_type_ids[0]
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "Non-refundable", "base_price": "80.00" }))
+        .send()
+        .await
+        .expect("Failed to create rate plan")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    client
+        .put(format!("{}/hotels/{}/taxes", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "city_tax_per_person_night": "2.50", "vat_percent": "10.00" }))
+        .send()
+        .await
+        .expect("Failed to update taxes");
+    client
+        .post(format!("{}/hotels/{}/fees", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "Cleaning", "amount": "15.00" }))
+        .send()
+        .await
+        .expect("Failed to create fee");
+    let check_in = chrono::Utc::now().date_naive() + chrono::Duration::days(10);
+    let check_out = check_in + chrono::Duration::days(2);
+
+    let response = client
+        .get(format!("{}/hotels/availability", BASE_URL))
+        .query(&[
+            ("city", city.to_uppercase()),
+            ("check_in", check_in.to_string()),
+            ("check_out", check_out.to_string()),
+            ("guests", "2".to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let results = body.as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["room_type_id"], room_type_ids[0]);
+    assert_eq!(results[0]["rate_plan_id"], rate_plan_id);
+    // 2 nights at 80.00, 15.00 fee, 2 guests x 2 nights x 2.50 city tax and
+    // 10% VAT on the rooms and fee.
+    assert_eq!(results[0]["cheapest_total_price"], "202.50");
+}
+
+#[tokio::test]
+async fn test_search_availability_prices_each_hotel_separately() {
+    let client = reqwest::Client::new();
+    let token = register_availability_user(&client, "availability_many").await;
+    let city = format!(
+        "Brno{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let (fee_hotel_id, _) = create_hotel_with_room_types(&client, &token, &city, &["100.00"]).await;
+    let (plain_hotel_id, _) =
+        create_hotel_with_room_types(&client, &token, &city, &["120.00", "130.00"]).await;
+    client
+        .post(format!("{}/hotels/{}/fees", BASE_URL, fee_hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "Resort fee", "amount": "50.00" }))
+        .send()
+        .await
+        .expect("Failed to create fee");
+    let check_in = chrono::Utc::now().date_naive() + chrono::Duration::days(10);
+    let check_out = check_in + chrono::Duration::days(2);
+
+    let response = client
+        .get(format!("{}/hotels/availability", BASE_URL))
+        .query(&[
+            ("city", city.clone()),
+            ("check_in", check_in.to_string()),
+            ("check_out", check_out.to_string()),
+            ("guests", "2".to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let results = body.as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    // The fee only applies to its own hotel, which makes it the dearer one.
+    assert_eq!(results[0]["hotel"]["id"], plain_hotel_id);
+    assert_eq!(results[0]["cheapest_total_price"], "240.00");
+    assert_eq!(results[1]["hotel"]["id"], fee_hotel_id);
+    assert_eq!(results[1]["cheapest_total_price"], "250.00");
+}
+
+#[tokio::test]
+async fn test_search_availability_city_wildcards_are_literal() {
+    let client = reqwest::Client::new();
+    let token = register_availability_user(&client, "availability_literal").await;
+    let city = format!(
+        "Ostrava{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    create_hotel_with_room_types(&client, &token, &city, &["90.00"]).await;
+    let check_in = chrono::Utc::now().date_naive() + chrono::Duration::days(10);
+    let check_out = check_in + chrono::Duration::days(1);
+
+    for pattern in ["Ostrava%", "%", "Ostrava_%"] {
+        let response = client
+            .get(format!("{}/hotels/availability", BASE_URL))
+            .query(&[
+                ("city", pattern.to_string()),
+                ("check_in", check_in.to_string()),
+                ("check_out", check_out.to_string()),
+            ])
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.json::<serde_json::Value>().await.unwrap();
+        assert!(body.as_array().unwrap().is_empty(), "{}", pattern);
+    }
+}
+
+#[tokio::test]
+async fn test_search_availability_excludes_fully_booked() {
+    let client = reqwest::Client::new();
+    let token = register_availability_user(&client, "availability_full").await;
+    let city = format!(
+        "Brno{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let (hotel_id, room_type_ids) =
+        create_hotel_with_room_types(&client, &token, &city, &["80.00"]).await;
+    let check_in = chrono::Utc::now().date_naive() + chrono::Duration::days(15);
+    let check_out = check_in + chrono::Duration::days(3);
+    client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_ids[0],
+            "check_in": check_in + chrono::Duration::days(1),
+            "check_out": check_out + chrono::Duration::days(1),
+            "guests": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to book");
+
+    let response = client
+        .get(format!("{}/hotels/availability", BASE_URL))
+        .query(&[
+            ("city", city.clone()),
+            ("check_in", check_in.to_string()),
+            ("check_out", check_out.to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_search_availability_excludes_small_rooms() {
+    let client = reqwest::Client::new();
+    let token = register_availability_user(&client, "availability_guests").await;
+    let city = format!(
+        "Ostrava{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    create_hotel_with_room_types(&client, &token, &city, &["70.00"]).await;
+    let check_in = chrono::Utc::now().date_naive() + chrono::Duration::days(5);
+
+    let response = client
+        .get(format!("{}/hotels/availability", BASE_URL))
+        .query(&[
+            ("city", city.clone()),
+            ("check_in", check_in.to_string()),
+            (
+                "check_out",
+                (check_in + chrono::Duration::days(1)).to_string(),
+            ),
+            ("guests", "4".to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_search_availability_400_invalid_dates() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/hotels/availability", BASE_URL))
+        .query(&[("check_in", "2030-01-10"), ("check_out", "2030-01-09")])
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,301 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::{BASE_URL, create_inventory, days_from_today, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn book(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    check_in: NaiveDate,
+    check_out: NaiveDate,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "check_in": check_in,
+            "check_out": check_out,
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn calendar(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> reqwest::Response {
+    client
+        .get(format!("{}/hotels/{}/calendar", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .query(&[("from", from.to_string()), ("to", to.to_string())])
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn restrict(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    payload: serde_json::Value,
+) -> reqwest::Response {
+    client
+        .put(format!("{}/hotels/{}/calendar", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_calendar_200_ok() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "calendar").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 2).await;
+    let created = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(10),
+        days_from_today(12),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+
+    let response = calendar(
+        &client,
+        &token,
+        hotel_id,
+        days_from_today(9),
+        days_from_today(12),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let room_types = body.as_array().unwrap();
+    assert_eq!(room_types.len(), 1);
+    assert_eq!(room_types[0]["room_type_id"], room_type_id);
+    let days = room_types[0]["days"].as_array().unwrap();
+    assert_eq!(days.len(), 4);
+    let sold: Vec<i64> = days.iter().map(|d| d["sold"].as_i64().unwrap()).collect();
+    let available: Vec<i64> = days
+        .iter()
+        .map(|d| d["available"].as_i64().unwrap())
+        .collect();
+    assert_eq!(sold, vec![0, 1, 1, 0]);
+    assert_eq!(available, vec![2, 1, 1, 2]);
+    assert!(days.iter().all(|d| d["total"] == 2 && d["held"] == 0));
+}
+
+#[tokio::test]
+async fn test_calendar_400_invalid_range() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "calendar_range").await;
+    let (hotel_id, _) = create_inventory(&client, &token, 1).await;
+
+    let response = calendar(
+        &client,
+        &token,
+        hotel_id,
+        days_from_today(5),
+        days_from_today(4),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_calendar_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/hotels/1/calendar", BASE_URL))
+        .query(&[("from", "2030-01-01"), ("to", "2030-01-02")])
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_cancelled_booking_updates_calendar() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "calendar_cancel").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let created = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(3),
+        days_from_today(4),
+    )
+    .await;
+    let booking_id = created.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    client
+        .post(format!("{}/bookings/{}/cancel", BASE_URL, booking_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to cancel");
+
+    let response = calendar(
+        &client,
+        &token,
+        hotel_id,
+        days_from_today(3),
+        days_from_today(3),
+    )
+    .await;
+
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body[0]["days"][0]["sold"], 0);
+    assert_eq!(body[0]["days"][0]["available"], 1);
+}
+
+#[tokio::test]
+async fn test_stop_sell_blocks_booking() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "stop_sell").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let restricted = restrict(
+        &client,
+        &token,
+        hotel_id,
+        json!({
+            "room_type_id": room_type_id,
+            "from": days_from_today(21),
+            "to": days_from_today(21),
+            "stop_sell": true
+        }),
+    )
+    .await;
+    assert_eq!(restricted.status(), StatusCode::NO_CONTENT);
+
+    let blocked = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(20),
+        days_from_today(22),
+    )
+    .await;
+    assert_eq!(blocked.status(), StatusCode::CONFLICT);
+
+    restrict(
+        &client,
+        &token,
+        hotel_id,
+        json!({
+            "room_type_id": room_type_id,
+            "from": days_from_today(21),
+            "to": days_from_today(21),
+            "stop_sell": false
+        }),
+    )
+    .await;
+    let allowed = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(20),
+        days_from_today(22),
+    )
+    .await;
+    assert_eq!(allowed.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_closed_to_arrival_blocks_only_arrivals() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "closed_to_arrival").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 2).await;
+    restrict(
+        &client,
+        &token,
+        hotel_id,
+        json!({
+            "room_type_id": room_type_id,
+            "from": days_from_today(31),
+            "to": days_from_today(31),
+            "closed_to_arrival": true
+        }),
+    )
+    .await;
+
+    let arriving = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(31),
+        days_from_today(33),
+    )
+    .await;
+    let staying_through = book(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(30),
+        days_from_today(33),
+    )
+    .await;
+
+    assert_eq!(arriving.status(), StatusCode::CONFLICT);
+    assert_eq!(staying_through.status(), StatusCode::CREATED);
+
+    let response = calendar(
+        &client,
+        &token,
+        hotel_id,
+        days_from_today(31),
+        days_from_today(31),
+    )
+    .await;
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body[0]["days"][0]["closed_to_arrival"], true);
+    assert_eq!(body[0]["days"][0]["stop_sell"], false);
+}
+
+#[tokio::test]
+async fn test_update_restrictions_404_room_type_not_found() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "restrictions_missing").await;
+    let (hotel_id, _) = create_inventory(&client, &token, 1).await;
+
+    let response = restrict(
+        &client,
+        &token,
+        hotel_id,
+        json!({
+            "room_type_id": 999999,
+            "from": days_from_today(1),
+            "to": days_from_today(2),
+            "stop_sell": true
+        }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,304 @@
+mod common;
+
+use common::{ADMIN_EMAIL, BASE_URL, register_user, unique_email};
+use reqwest::StatusCode;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const MAX_FAILURES_PER_EMAIL: usize = 5;
+const MAX_FAILURES_PER_IP: usize = 20;
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Client address sent through X-Forwarded-For so each test is throttled on
+/// its own.
+fn unique_ip() -> String {
+    let n: u128 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos()
+        + COUNTER.fetch_add(1, Ordering::SeqCst) as u128;
+    format!("10.{}.{}.{}", (n >> 16) % 256, (n >> 8) % 256, n % 256)
+}
+
+async fn login(
+    client: &reqwest::Client,
+    email: &str,
+    password: &str,
+    ip: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/login", BASE_URL))
+        .header("X-Forwarded-For", ip)
+        .json(&json!({
+            "email": email,
+            "password": password
+        }))
+        .send()
+        .await
+        .expect("Failed to login")
+}
+
+async fn fail_logins(client: &reqwest::Client, email: &str, ip: &str, count: usize) {
+    for _ in 0..count {
+        let response = login(client, email, "wrongpassword", ip).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
+async fn admin_token(client: &reqwest::Client) -> String {
+    // Registered as an admin through ADMIN_EMAILS; a conflict just means it already exists.
+    client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": ADMIN_EMAIL,
+            "password": "password123",
+            "first_name": "Ada",
+            "last_name": "Admin"
+        }))
+        .send()
+        .await
+        .expect("Failed to register");
+    login(client, ADMIN_EMAIL, "password123", &unique_ip())
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+fn current_code(secret: &str) -> String {
+    TOTP::new_unchecked(
+        Algorithm::SHA1,
+        6,
+        1,
+        30,
+        Secret::Encoded(secret.to_string()).to_bytes().unwrap(),
+        None,
+        String::new(),
+    )
+    .generate_current()
+    .unwrap()
+}
+
+/// Turns on 2FA for the registered user and returns their TOTP secret.
+async fn enable_two_factor(client: &reqwest::Client, registered: &serde_json::Value) -> String {
+    let token = registered["token"].as_str().unwrap();
+    let secret = client
+        .post(format!("{}/auth/2fa/setup", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["secret"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    client
+        .post(format!("{}/auth/2fa/confirm", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "code": current_code(&secret) }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    secret
+}
+
+async fn challenge(client: &reqwest::Client, email: &str, ip: &str) -> String {
+    let response = login(client, email, "password123", ip).await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    response.json::<serde_json::Value>().await.unwrap()["challenge_token"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+async fn verify(
+    client: &reqwest::Client,
+    challenge_token: &str,
+    code: &str,
+    ip: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/2fa/verify", BASE_URL))
+        .header("X-Forwarded-For", ip)
+        .json(&json!({ "challenge_token": challenge_token, "code": code }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_login_429_after_repeated_failures() {
+    let client = reqwest::Client::new();
+    let email = unique_email("lockout_email");
+    register_user(&client, &email).await;
+
+    fail_logins(&client, &email, &unique_ip(), MAX_FAILURES_PER_EMAIL).await;
+
+    // The correct password is refused too, even from another address.
+    let response = login(&client, &email, "password123", &unique_ip()).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: i64 = response.headers()["retry-after"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(retry_after > 0);
+}
+
+#[tokio::test]
+async fn test_login_success_resets_failures() {
+    let client = reqwest::Client::new();
+    let email = unique_email("lockout_reset");
+    register_user(&client, &email).await;
+    let ip = unique_ip();
+
+    fail_logins(&client, &email, &ip, MAX_FAILURES_PER_EMAIL - 1).await;
+    let response = login(&client, &email, "password123", &ip).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    fail_logins(&client, &email, &ip, MAX_FAILURES_PER_EMAIL - 1).await;
+    let response = login(&client, &email, "password123", &ip).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_login_429_for_unknown_email() {
+    let client = reqwest::Client::new();
+    let email = unique_email("lockout_unknown");
+
+    fail_logins(&client, &email, &unique_ip(), MAX_FAILURES_PER_EMAIL).await;
+
+    let response = login(&client, &email, "password123", &unique_ip()).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_login_429_after_repeated_failures_from_one_ip() {
+    let client = reqwest::Client::new();
+    let ip = unique_ip();
+
+    for _ in 0..MAX_FAILURES_PER_IP {
+        let response = login(&client, &unique_email("lockout_ip"), "wrongpassword", &ip).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let email = unique_email("lockout_ip_victim");
+    register_user(&client, &email).await;
+
+    let response = login(&client, &email, "password123", &ip).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key("retry-after"));
+
+    // Other addresses are unaffected.
+    let response = login(&client, &email, "password123", &unique_ip()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_admin_lists_and_unlocks_lockout() {
+    let client = reqwest::Client::new();
+    let email = unique_email("lockout_admin");
+    let user_id = register_user(&client, &email).await["user"]["id"]
+        .as_i64()
+        .unwrap();
+    let ip = unique_ip();
+
+    fail_logins(&client, &email, &ip, MAX_FAILURES_PER_EMAIL).await;
+
+    let admin = admin_token(&client).await;
+    let lockouts = client
+        .get(format!("{}/admin/lockouts", BASE_URL))
+        .header("Authorization", format!("Bearer {}", admin))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(lockouts.status(), StatusCode::OK);
+    let lockouts = lockouts.json::<serde_json::Value>().await.unwrap();
+    let lockout = lockouts
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|l| l["user_id"].as_i64() == Some(user_id))
+        .expect("Lockout not listed");
+    assert_eq!(lockout["email"], email);
+    assert_eq!(lockout["failed_attempts"], MAX_FAILURES_PER_EMAIL as i64);
+    assert_eq!(lockout["ip_address"], ip);
+
+    let response = client
+        .delete(format!("{}/admin/users/{}/lockout", BASE_URL, user_id))
+        .header("Authorization", format!("Bearer {}", admin))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = login(&client, &email, "password123", &ip).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_unlock_404_when_not_locked() {
+    let client = reqwest::Client::new();
+    let user_id = register_user(&client, &unique_email("lockout_none")).await["user"]["id"]
+        .as_i64()
+        .unwrap();
+    let admin = admin_token(&client).await;
+
+    let response = client
+        .delete(format!("{}/admin/users/{}/lockout", BASE_URL, user_id))
+        .header("Authorization", format!("Bearer {}", admin))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_list_lockouts_403_for_non_admin() {
+    let client = reqwest::Client::new();
+    let token = register_user(&client, &unique_email("lockout_guest")).await["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = client
+        .get(format!("{}/admin/lockouts", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_verify_two_factor_429_after_repeated_wrong_codes() {
+    let client = reqwest::Client::new();
+    let email = unique_email("lockout_2fa");
+    let secret = enable_two_factor(&client, &register_user(&client, &email).await).await;
+    let ip = unique_ip();
+
+    let first = challenge(&client, &email, &ip).await;
+    for _ in 0..MAX_FAILURES_PER_EMAIL - 1 {
+        let response = verify(&client, &first, "not-a-code", &ip).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+    // A fresh challenge does not reset the count; this failure locks the account.
+    let second = challenge(&client, &email, &ip).await;
+    let response = verify(&client, &second, "not-a-code", &ip).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Neither the right code nor the password gets through now.
+    let response = verify(&client, &second, &current_code(&secret), &ip).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key("retry-after"));
+    let response = login(&client, &email, "password123", &unique_ip()).await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
@@ -0,0 +1,201 @@
+mod common;
+
+use common::{BASE_URL, register_user, unique_email};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn login(client: &reqwest::Client, email: &str, password: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": password }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn change_password(
+    client: &reqwest::Client,
+    token: &serde_json::Value,
+    current_password: &str,
+    new_password: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/password/change", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", token.as_str().unwrap()),
+        )
+        .json(&json!({
+            "current_password": current_password,
+            "new_password": new_password
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_forgot_password_202_known_email() {
+    let client = reqwest::Client::new();
+    let email = unique_email("pw_forgot");
+    register_user(&client, &email).await;
+
+    let response = client
+        .post(format!("{}/auth/password/forgot", BASE_URL))
+        .json(&json!({ "email": email }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn test_forgot_password_202_unknown_email() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/auth/password/forgot", BASE_URL))
+        .json(&json!({ "email": unique_email("pw_forgot_unknown") }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn test_reset_password_400_invalid_token() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/auth/password/reset", BASE_URL))
+        .json(&json!({ "token": "not-a-token", "new_password": "newpassword123" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_reset_password_422_short_password() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/auth/password/reset", BASE_URL))
+        .json(&json!({ "token": "not-a-token", "new_password": "123" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_change_password_200() {
+    let client = reqwest::Client::new();
+    let email = unique_email("pw_change");
+    let registered = register_user(&client, &email).await;
+
+    let response = change_password(
+        &client,
+        &registered["token"],
+        "password123",
+        "newpassword123",
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(body["token"].is_string());
+    assert!(body["refresh_token"].is_string());
+    assert_eq!(
+        login(&client, &email, "password123").await.status(),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        login(&client, &email, "newpassword123").await.status(),
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn test_change_password_revokes_existing_sessions() {
+    let client = reqwest::Client::new();
+    let email = unique_email("pw_change_sessions");
+    let registered = register_user(&client, &email).await;
+
+    let changed: serde_json::Value = change_password(
+        &client,
+        &registered["token"],
+        "password123",
+        "newpassword123",
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+
+    let old_profile = client
+        .get(format!("{}/auth/profile", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", registered["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+    let old_refresh = client
+        .post(format!("{}/auth/refresh", BASE_URL))
+        .json(&json!({ "refresh_token": registered["refresh_token"] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let new_profile = client
+        .get(format!("{}/auth/profile", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", changed["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(old_profile.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(old_refresh.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(new_profile.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_change_password_400_wrong_current_password() {
+    let client = reqwest::Client::new();
+    let registered = register_user(&client, &unique_email("pw_change_wrong")).await;
+
+    let response = change_password(
+        &client,
+        &registered["token"],
+        "wrongpassword",
+        "newpassword123",
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_change_password_401_no_token() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/auth/password/change", BASE_URL))
+        .json(&json!({
+            "current_password": "password123",
+            "new_password": "newpassword123"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,465 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::{BASE_URL, create_inventory, days_from_today, register, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn configure_taxes(client: &reqwest::Client, token: &str, hotel_id: i64) {
+    let taxes = client
+        .put(format!("{}/hotels/{}/taxes", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "city_tax_per_person_night": "2.50",
+            "vat_percent": "10.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to update taxes");
+    assert_eq!(taxes.status(), StatusCode::OK);
+
+    let fee = client
+        .post(format!("{}/hotels/{}/fees", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "Cleaning", "amount": "15.00" }))
+        .send()
+        .await
+        .expect("Failed to create fee");
+    assert_eq!(fee.status(), StatusCode::CREATED);
+}
+
+async fn quote(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    check_in: NaiveDate,
+    check_out: NaiveDate,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/quotes", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "check_in": check_in,
+            "check_out": check_out,
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn book_with_quote(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    quote_id: &serde_json::Value,
+    check_in: NaiveDate,
+    check_out: NaiveDate,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "quote_id": quote_id,
+            "check_in": check_in,
+            "check_out": check_out,
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_create_quote_201_line_items() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    configure_taxes(&client, &token, hotel_id).await;
+
+    let response = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(10),
+        days_from_today(12),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert!(body["id"].as_str().is_some());
+    assert_eq!(body["room_total"], "200.00");
+    assert_eq!(body["fees_total"], "15.00");
+    assert_eq!(body["taxes_total"], "31.50");
+    assert_eq!(body["total_price"], "246.50");
+
+    let kinds: Vec<&str> = body["lines"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|line| line["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        kinds,
+        vec!["room_night", "room_night", "fee", "city_tax", "vat"]
+    );
+    let city_tax = &body["lines"][3];
+    assert_eq!(city_tax["quantity"], 4);
+    assert_eq!(city_tax["amount"], "10.00");
+    assert_eq!(body["lines"][4]["amount"], "21.50");
+}
+
+#[tokio::test]
+async fn test_create_quote_without_taxes() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_plain").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(4),
+        days_from_today(7),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["lines"].as_array().unwrap().len(), 3);
+    assert_eq!(body["taxes_total"], "0.00");
+    assert_eq!(body["total_price"], "300.00");
+}
+
+#[tokio::test]
+async fn test_create_quote_400_invalid_dates() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_dates").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(5),
+        days_from_today(3),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_quote_404_room_type_not_found() {
+    let client = reqwest::Client::new();
+    let token = register(&client, "quoter_missing").await;
+
+    let response = quote(
+        &client,
+        &token,
+        999999,
+        999999,
+        days_from_today(3),
+        days_from_today(4),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_quote_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/quotes", BASE_URL))
+        .json(&json!({
+            "hotel_id": 1,
+            "room_type_id": 1,
+            "check_in": days_from_today(3),
+            "check_out": days_from_today(4),
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_get_quote_200_ok() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_get").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let created = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(8),
+        days_from_today(9),
+    )
+    .await;
+    let quote_id = created.json::<serde_json::Value>().await.unwrap()["id"].clone();
+
+    let response = client
+        .get(format!(
+            "{}/quotes/{}",
+            BASE_URL,
+            quote_id.as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["id"], quote_id);
+    assert_eq!(body["total_price"], "100.00");
+}
+
+#[tokio::test]
+async fn test_booking_uses_quoted_total() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_booking").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    configure_taxes(&client, &token, hotel_id).await;
+    let created = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(15),
+        days_from_today(17),
+    )
+    .await;
+    let quote_id = created.json::<serde_json::Value>().await.unwrap()["id"].clone();
+
+    client
+        .put(format!("{}/hotels/{}/taxes", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "vat_percent": "20.00" }))
+        .send()
+        .await
+        .expect("Failed to update taxes");
+
+    let response = book_with_quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        &quote_id,
+        days_from_today(15),
+        days_from_today(17),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["quote_id"], quote_id);
+    assert_eq!(body["total_price"], "246.50");
+    assert_eq!(body["nightly_rates"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_booking_400_quote_mismatch() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_mismatch").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let created = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(20),
+        days_from_today(22),
+    )
+    .await;
+    let quote_id = created.json::<serde_json::Value>().await.unwrap()["id"].clone();
+
+    let response = book_with_quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        &quote_id,
+        days_from_today(20),
+        days_from_today(23),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_booking_410_quote_already_used() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_replay").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 2).await;
+    let created = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(25),
+        days_from_today(26),
+    )
+    .await;
+    let quote_id = created.json::<serde_json::Value>().await.unwrap()["id"].clone();
+
+    let first = book_with_quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        &quote_id,
+        days_from_today(25),
+        days_from_today(26),
+    )
+    .await;
+    assert_eq!(first.status(), StatusCode::CREATED);
+
+    let replay = book_with_quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        &quote_id,
+        days_from_today(25),
+        days_from_today(26),
+    )
+    .await;
+
+    assert_eq!(replay.status(), StatusCode::GONE);
+}
+
+#[tokio::test]
+async fn test_quote_404_other_user() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_owner").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let created = quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        days_from_today(27),
+        days_from_today(28),
+    )
+    .await;
+    let quote_id = created.json::<serde_json::Value>().await.unwrap()["id"].clone();
+    let other_token = register(&client, "quoter_other").await;
+
+    let fetched = client
+        .get(format!(
+            "{}/quotes/{}",
+            BASE_URL,
+            quote_id.as_str().unwrap()
+        ))
+        .header("Authorization", format!("Bearer {}", other_token))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(fetched.status(), StatusCode::NOT_FOUND);
+
+    let response = book_with_quote(
+        &client,
+        &other_token,
+        hotel_id,
+        room_type_id,
+        &quote_id,
+        days_from_today(27),
+        days_from_today(28),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_booking_404_unknown_quote() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "quoter_unknown").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = book_with_quote(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        &json!("00000000-0000-0000-0000-000000000000"),
+        days_from_today(2),
+        days_from_today(3),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_update_taxes_400_invalid_vat() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "taxes_invalid").await;
+    let (hotel_id, _) = create_inventory(&client, &token, 1).await;
+
+    let response = client
+        .put(format!("{}/hotels/{}/taxes", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "vat_percent": "120.00" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_fee_422_invalid_fields() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "fees_invalid").await;
+    let (hotel_id, _) = create_inventory(&client, &token, 1).await;
+
+    let response = client
+        .post(format!("{}/hotels/{}/fees", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "name": "   ", "amount": "100000000000.00" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let fields: Vec<&str> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["amount", "name"]);
+}
+
+#[tokio::test]
+async fn test_update_taxes_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .put(format!("{}/hotels/1/taxes", BASE_URL))
+        .json(&json!({ "vat_percent": "10.00" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::{BASE_URL, unique_email};
+use reqwest::StatusCode;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Client address sent through X-Forwarded-For so each test gets fresh buckets.
+fn unique_ip() -> String {
+    let n: u128 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos()
+        + COUNTER.fetch_add(1, Ordering::SeqCst) as u128;
+    format!("172.{}.{}.{}", 16 + (n >> 16) % 16, (n >> 8) % 256, n % 256)
+}
+
+fn header(response: &reqwest::Response, name: &str) -> i64 {
+    response.headers()[name].to_str().unwrap().parse().unwrap()
+}
+
+async fn refresh(client: &reqwest::Client, ip: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/refresh", BASE_URL))
+        .header("X-Forwarded-For", ip)
+        .json(&json!({ "refresh_token": "not-a-token" }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn list_hotels(client: &reqwest::Client, ip: &str, token: Option<&str>) -> reqwest::Response {
+    let mut request = client
+        .get(format!("{}/hotels", BASE_URL))
+        .header("X-Forwarded-For", ip);
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Bearer {}", token));
+    }
+    request.send().await.expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_rate_limit_headers() {
+    let client = reqwest::Client::new();
+    let ip = unique_ip();
+
+    let response = refresh(&client, &ip).await;
+    let limit = header(&response, "x-ratelimit-limit");
+    assert!(limit > 0);
+    assert_eq!(header(&response, "x-ratelimit-remaining"), limit - 1);
+    assert!(header(&response, "x-ratelimit-reset") >= 1);
+    assert!(!response.headers().contains_key("retry-after"));
+}
+
+#[tokio::test]
+async fn test_rate_limit_429_when_exhausted() {
+    let client = reqwest::Client::new();
+    let ip = unique_ip();
+
+    let limit = header(&refresh(&client, &ip).await, "x-ratelimit-limit");
+
+    // The bucket refills while we drain it, so allow for some extra requests.
+    let mut limited = None;
+    for _ in 0..limit * 2 {
+        let response = refresh(&client, &ip).await;
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            limited = Some(response);
+            break;
+        }
+    }
+    let limited = limited.expect("Never rate limited");
+    assert_eq!(header(&limited, "x-ratelimit-remaining"), 0);
+    assert!(header(&limited, "retry-after") >= 1);
+
+    // Other groups and other clients have their own buckets.
+    let response = list_hotels(&client, &ip, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = refresh(&client, &unique_ip()).await;
+    assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_rate_limit_per_user_when_authenticated() {
+    let client = reqwest::Client::new();
+    let ip = unique_ip();
+
+    let token = client
+        .post(format!("{}/auth/register", BASE_URL))
+        .header("X-Forwarded-For", &ip)
+        .json(&json!({
+            "email": unique_email("rate_limit"),
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = list_hotels(&client, &ip, Some(&token)).await;
+    let limit = header(&response, "x-ratelimit-limit");
+    assert_eq!(header(&response, "x-ratelimit-remaining"), limit - 1);
+
+    // The user's request didn't count against their address.
+    let response = list_hotels(&client, &ip, None).await;
+    assert_eq!(header(&response, "x-ratelimit-remaining"), limit - 1);
+}
+
+#[tokio::test]
+async fn test_health_not_rate_limited() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/health/live", BASE_URL))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!response.headers().contains_key("x-ratelimit-limit"));
+}
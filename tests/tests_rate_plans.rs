@@ -0,0 +1,441 @@
+mod common;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use common::{BASE_URL, create_inventory, days_from_today, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+fn next_thursday() -> NaiveDate {
+    let mut date = days_from_today(7);
+    while date.weekday() != Weekday::Thu {
+        date += Duration::days(1);
+    }
+    date
+}
+
+async fn create_rate_plan(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    payload: serde_json::Value,
+) -> reqwest::Response {
+    client
+        .post(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans",
+            BASE_URL, hotel_id, room_type_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn price(
+    client: &reqwest::Client,
+    hotel_id: i64,
+    room_type_id: i64,
+    rate_plan_id: Option<i64>,
+    check_in: NaiveDate,
+    check_out: NaiveDate,
+) -> reqwest::Response {
+    let mut query = vec![
+        ("check_in", check_in.to_string()),
+        ("check_out", check_out.to_string()),
+    ];
+    if let Some(rate_plan_id) = rate_plan_id {
+        query.push(("rate_plan_id", rate_plan_id.to_string()));
+    }
+
+    client
+        .get(format!(
+            "{}/hotels/{}/room-types/{}/price",
+            BASE_URL, hotel_id, room_type_id
+        ))
+        .query(&query)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+fn night_prices(body: &serde_json::Value, key: &str) -> Vec<String> {
+    body[key]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|night| night["price"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_create_rate_plan_201_created() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "rate_plan").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({
+            "name": "Breakfast included",
+            "base_price": "120.00",
+            "weekend_price": "150.00"
+        }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["name"], "Breakfast included");
+    assert_eq!(body["base_price"], "120.00");
+    assert_eq!(body["seasons"].as_array().unwrap().len(), 0);
+
+    let list = client
+        .get(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans",
+            BASE_URL, hotel_id, room_type_id
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(list.status(), StatusCode::OK);
+    let list_body = list.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(list_body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_create_rate_plan_400_negative_price() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "rate_plan_negative").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({
+            "name": "Broken",
+            "base_price": "100.00",
+            "weekend_price": "-1.00"
+        }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_rate_plan_422_invalid_fields() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "rate_plan_invalid").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({
+            "name": "x".repeat(101),
+            "base_price": "100000000.00",
+            "weekend_price": "123456789.00"
+        }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let fields: Vec<&str> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["base_price", "name", "weekend_price"]);
+}
+
+#[tokio::test]
+async fn test_create_rate_plan_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/hotels/1/room-types/1/rate-plans", BASE_URL))
+        .json(&json!({
+            "name": "Flexible",
+            "base_price": "100.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_price_without_rate_plan_uses_base_price() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "price_base").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = price(
+        &client,
+        hotel_id,
+        room_type_id,
+        None,
+        days_from_today(3),
+        days_from_today(5),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(night_prices(&body, "nights"), vec!["100.00", "100.00"]);
+    assert_eq!(body["total_price"], "200.00");
+}
+
+#[tokio::test]
+async fn test_price_weekend_and_seasonal_overrides() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "price_season").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let plan = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({
+            "name": "Flexible",
+            "base_price": "100.00",
+            "weekend_price": "150.00"
+        }),
+    )
+    .await;
+    let rate_plan_id = plan.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    let thursday = next_thursday();
+
+    let weekly = price(
+        &client,
+        hotel_id,
+        room_type_id,
+        Some(rate_plan_id),
+        thursday,
+        thursday + Duration::days(4),
+    )
+    .await;
+    assert_eq!(weekly.status(), StatusCode::OK);
+    let weekly_body = weekly.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        night_prices(&weekly_body, "nights"),
+        vec!["100.00", "150.00", "150.00", "100.00"]
+    );
+    assert_eq!(weekly_body["total_price"], "500.00");
+
+    let season = client
+        .post(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans/{}/seasons",
+            BASE_URL, hotel_id, room_type_id, rate_plan_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Festival",
+            "start_date": thursday + Duration::days(1),
+            "end_date": thursday + Duration::days(3),
+            "price": "200.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to create season");
+    assert_eq!(season.status(), StatusCode::CREATED);
+
+    let seasonal = price(
+        &client,
+        hotel_id,
+        room_type_id,
+        Some(rate_plan_id),
+        thursday,
+        thursday + Duration::days(5),
+    )
+    .await;
+    let seasonal_body = seasonal.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(
+        night_prices(&seasonal_body, "nights"),
+        vec!["100.00", "200.00", "200.00", "200.00", "100.00"]
+    );
+    assert_eq!(seasonal_body["total_price"], "800.00");
+}
+
+#[tokio::test]
+async fn test_create_season_400_invalid_dates() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "season_dates").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let plan = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({ "name": "Flexible", "base_price": "100.00" }),
+    )
+    .await;
+    let rate_plan_id = plan.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let response = client
+        .post(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans/{}/seasons",
+            BASE_URL, hotel_id, room_type_id, rate_plan_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Backwards",
+            "start_date": days_from_today(10),
+            "end_date": days_from_today(9),
+            "price": "90.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_season_422_invalid_fields() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "season_invalid").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let plan = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({ "name": "Flexible", "base_price": "100.00" }),
+    )
+    .await;
+    let rate_plan_id = plan.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let response = client
+        .post(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans/{}/seasons",
+            BASE_URL, hotel_id, room_type_id, rate_plan_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "   ",
+            "start_date": days_from_today(9),
+            "end_date": days_from_today(10),
+            "price": "100000000.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let fields: Vec<&str> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["name", "price"]);
+}
+
+#[tokio::test]
+async fn test_booking_keeps_quoted_price_after_rate_change() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "price_snapshot").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+    let plan = create_rate_plan(
+        &client,
+        &token,
+        hotel_id,
+        room_type_id,
+        json!({ "name": "Non-refundable", "base_price": "80.00" }),
+    )
+    .await;
+    let rate_plan_id = plan.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let created = client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "rate_plan_id": rate_plan_id,
+            "check_in": days_from_today(12),
+            "check_out": days_from_today(14),
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to create booking");
+    assert_eq!(created.status(), StatusCode::CREATED);
+    let created_body = created.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(created_body["rate_plan_id"], rate_plan_id);
+    assert_eq!(created_body["total_price"], "160.00");
+    assert_eq!(
+        night_prices(&created_body, "nightly_rates"),
+        vec!["80.00", "80.00"]
+    );
+
+    let updated = client
+        .put(format!(
+            "{}/hotels/{}/room-types/{}/rate-plans/{}",
+            BASE_URL, hotel_id, room_type_id, rate_plan_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "base_price": "95.00" }))
+        .send()
+        .await
+        .expect("Failed to update rate plan");
+    assert_eq!(updated.status(), StatusCode::OK);
+
+    let fetched = client
+        .get(format!("{}/bookings/{}", BASE_URL, created_body["id"]))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to fetch booking");
+    let fetched_body = fetched.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(fetched_body["total_price"], "160.00");
+    assert_eq!(
+        night_prices(&fetched_body, "nightly_rates"),
+        vec!["80.00", "80.00"]
+    );
+}
+
+#[tokio::test]
+async fn test_booking_404_unknown_rate_plan() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "price_missing_plan").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &token, 1).await;
+
+    let response = client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "rate_plan_id": 999999,
+            "check_in": days_from_today(2),
+            "check_out": days_from_today(3),
+            "guests": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,331 @@
+mod common;
+
+use common::{BASE_URL, create_inventory, days_from_today, register, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn book(
+    client: &reqwest::Client,
+    staff_token: &str,
+    guest_token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+    offset: i64,
+    actions: &[&str],
+) -> i64 {
+    let create_resp = client
+        .post(format!("{}/bookings", BASE_URL))
+        .header("Authorization", format!("Bearer {}", guest_token))
+        .json(&json!({
+            "hotel_id": hotel_id,
+            "room_type_id": room_type_id,
+            "check_in": days_from_today(offset),
+            "check_out": days_from_today(offset + 1),
+            "guests": 2
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let booking_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    // Lifecycle transitions belong to the hotel's staff, not the guest.
+    for action in actions {
+        let response = client
+            .post(format!(
+                "{}/hotels/{}/bookings/{}/{}",
+                BASE_URL, hotel_id, booking_id, action
+            ))
+            .header("Authorization", format!("Bearer {}", staff_token))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    booking_id
+}
+
+/// Books tonight's stay and walks it through check-out, since check-in is only
+/// accepted from the arrival date on.
+async fn checked_out_booking(
+    client: &reqwest::Client,
+    staff_token: &str,
+    guest_token: &str,
+    hotel_id: i64,
+    room_type_id: i64,
+) -> i64 {
+    book(
+        client,
+        staff_token,
+        guest_token,
+        hotel_id,
+        room_type_id,
+        0,
+        &["confirm", "check-in", "check-out"],
+    )
+    .await
+}
+
+async fn post_review(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    booking_id: i64,
+    score: i64,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/hotels/{}/reviews", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "booking_id": booking_id,
+            "score": score,
+            "comment": "Quiet room and friendly staff"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn get_hotel(client: &reqwest::Client, hotel_id: i64) -> serde_json::Value {
+    client
+        .get(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_create_review_201_created() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_create_staff").await;
+    let token = register(&client, "reviewer_create").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let booking_id =
+        checked_out_booking(&client, &manager_token, &token, hotel_id, room_type_id).await;
+
+    let response = post_review(&client, &token, hotel_id, booking_id, 5).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["booking_id"], booking_id);
+    assert_eq!(body["score"], 5);
+    assert_eq!(body["comment"], "Quiet room and friendly staff");
+
+    let hotel = get_hotel(&client, hotel_id).await;
+    assert_eq!(hotel["rating"], "5.00");
+    assert_eq!(hotel["total_reviews"], 1);
+}
+
+#[tokio::test]
+async fn test_create_review_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/hotels/1/reviews", BASE_URL))
+        .json(&json!({ "booking_id": 1, "score": 5, "comment": "Great" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_create_review_400_invalid_score() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_score_staff").await;
+    let token = register(&client, "reviewer_score").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let booking_id =
+        checked_out_booking(&client, &manager_token, &token, hotel_id, room_type_id).await;
+
+    let response = post_review(&client, &token, hotel_id, booking_id, 6).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_review_409_not_checked_out() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_early_staff").await;
+    let token = register(&client, "reviewer_early").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let booking_id = book(
+        &client,
+        &manager_token,
+        &token,
+        hotel_id,
+        room_type_id,
+        10,
+        &["confirm"],
+    )
+    .await;
+
+    let response = post_review(&client, &token, hotel_id, booking_id, 4).await;
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_create_review_409_duplicate() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_twice_staff").await;
+    let token = register(&client, "reviewer_twice").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let booking_id =
+        checked_out_booking(&client, &manager_token, &token, hotel_id, room_type_id).await;
+    post_review(&client, &token, hotel_id, booking_id, 4).await;
+
+    let response = post_review(&client, &token, hotel_id, booking_id, 2).await;
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(get_hotel(&client, hotel_id).await["total_reviews"], 1);
+}
+
+#[tokio::test]
+async fn test_create_review_404_other_users_booking() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_guest_staff").await;
+    let token = register(&client, "reviewer_guest").await;
+    let other_token = register(&client, "reviewer_stranger").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let booking_id =
+        checked_out_booking(&client, &manager_token, &token, hotel_id, room_type_id).await;
+
+    let response = post_review(&client, &other_token, hotel_id, booking_id, 1).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_list_reviews_paginated_with_average_rating() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_list_staff").await;
+    let token = register(&client, "reviewer_list").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 3).await;
+    for score in [5, 4, 2] {
+        let booking_id =
+            checked_out_booking(&client, &manager_token, &token, hotel_id, room_type_id).await;
+        post_review(&client, &token, hotel_id, booking_id, score).await;
+    }
+
+    let response = client
+        .get(format!(
+            "{}/hotels/{}/reviews?page=2&per_page=2",
+            BASE_URL, hotel_id
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["page"], 2);
+    assert_eq!(body["items"].as_array().unwrap().len(), 1);
+    assert_eq!(body["items"][0]["score"], 5);
+
+    let hotel = get_hotel(&client, hotel_id).await;
+    assert_eq!(hotel["rating"], "3.67");
+    assert_eq!(hotel["total_reviews"], 3);
+}
+
+#[tokio::test]
+async fn test_list_reviews_400_invalid_page() {
+    let client = reqwest::Client::new();
+
+    for page in ["0", "10001", "9223372036854775807"] {
+        let response = client
+            .get(format!(
+                "{}/hotels/1/reviews?page={}&per_page=100",
+                BASE_URL, page
+            ))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", page);
+    }
+}
+
+#[tokio::test]
+async fn test_update_and_delete_review_recompute_rating() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_edit_staff").await;
+    let token = register(&client, "reviewer_edit").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let booking_id =
+        checked_out_booking(&client, &manager_token, &token, hotel_id, room_type_id).await;
+    let review_id = post_review(&client, &token, hotel_id, booking_id, 5)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let update_resp = client
+        .put(format!(
+            "{}/hotels/{}/reviews/{}",
+            BASE_URL, hotel_id, review_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "score": 3 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(update_resp.status(), StatusCode::OK);
+    assert_eq!(get_hotel(&client, hotel_id).await["rating"], "3.00");
+
+    let delete_resp = client
+        .delete(format!(
+            "{}/hotels/{}/reviews/{}",
+            BASE_URL, hotel_id, review_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(delete_resp.status(), StatusCode::NO_CONTENT);
+    let hotel = get_hotel(&client, hotel_id).await;
+    assert!(hotel["rating"].is_null());
+    assert_eq!(hotel["total_reviews"], 0);
+}
+
+#[tokio::test]
+async fn test_update_review_404_other_user() {
+    let client = reqwest::Client::new();
+    let manager_token = register_manager(&client, "reviewer_author_staff").await;
+    let token = register(&client, "reviewer_author").await;
+    let other_token = register(&client, "reviewer_editor").await;
+    let (hotel_id, room_type_id) = create_inventory(&client, &manager_token, 1).await;
+    let booking_id =
+        checked_out_booking(&client, &manager_token, &token, hotel_id, room_type_id).await;
+    let review_id = post_review(&client, &token, hotel_id, booking_id, 5)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let response = client
+        .put(format!(
+            "{}/hotels/{}/reviews/{}",
+            BASE_URL, hotel_id, review_id
+        ))
+        .header("Authorization", format!("Bearer {}", other_token))
+        .json(&json!({ "score": 1 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
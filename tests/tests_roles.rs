@@ -0,0 +1,287 @@
+mod common;
+
+use common::{
+    BASE_URL, admin_token, login, promote_to_manager, register, register_manager, register_user,
+    unique_email,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn profile(client: &reqwest::Client, token: &str) -> serde_json::Value {
+    client
+        .get(format!("{}/auth/profile", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to fetch profile")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()
+}
+
+async fn create_hotel(
+    client: &reqwest::Client,
+    token: &str,
+    owner_id: Option<i64>,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Managed Hotel",
+            "address": "1 Manager St",
+            "city": "Lisbon",
+            "country": "Portugal",
+            "owner_id": owner_id
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn update_hotel(client: &reqwest::Client, token: &str, hotel_id: i64) -> reqwest::Response {
+    client
+        .put(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Renamed Hotel",
+            "address": "2 Manager St",
+            "city": "Lisbon",
+            "country": "Portugal"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_register_assigns_guest_role() {
+    let client = reqwest::Client::new();
+    let token = register(&client, "role_guest").await;
+
+    let body = profile(&client, &token).await;
+
+    assert_eq!(body["role"], "guest");
+}
+
+#[tokio::test]
+async fn test_admin_emails_register_as_admin() {
+    let client = reqwest::Client::new();
+    let token = admin_token(&client).await;
+
+    let body = profile(&client, &token).await;
+
+    assert_eq!(body["role"], "admin");
+}
+
+#[tokio::test]
+async fn test_create_hotel_403_guest() {
+    let client = reqwest::Client::new();
+    let token = register(&client, "role_guest_creator").await;
+
+    let response = create_hotel(&client, &token, None).await;
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_create_hotel_makes_creating_manager_owner() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "role_manager_creator").await;
+    let manager_id = profile(&client, &token).await["id"].as_i64().unwrap();
+
+    let response = create_hotel(&client, &token, None).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let hotel_id = response.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    let staff = client
+        .get(format!("{}/hotels/{}/staff", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert_eq!(staff[0]["user_id"], manager_id);
+    assert_eq!(staff[0]["role"], "owner");
+}
+
+#[tokio::test]
+async fn test_update_hotel_403_other_manager() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "role_owner").await;
+    let other_token = register_manager(&client, "role_other_manager").await;
+    let hotel_id = create_hotel(&client, &token, None)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let forbidden = update_hotel(&client, &other_token, hotel_id).await;
+    let allowed = update_hotel(&client, &token, hotel_id).await;
+
+    assert_eq!(forbidden.status(), StatusCode::FORBIDDEN);
+    assert_eq!(allowed.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_hotel_management_403_guest() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "role_room_owner").await;
+    let guest_token = register(&client, "role_room_guest").await;
+    let hotel_id = create_hotel(&client, &token, None)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let room_type = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", guest_token))
+        .json(&json!({
+            "name": "Double",
+            "capacity": 2,
+            "bed_configuration": "1 queen",
+            "base_price": "100.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let delete = client
+        .delete(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", guest_token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(room_type.status(), StatusCode::FORBIDDEN);
+    assert_eq!(delete.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_admin_manages_any_hotel() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "role_admin_target").await;
+    let admin = admin_token(&client).await;
+    let hotel_id = create_hotel(&client, &token, None)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let update = update_hotel(&client, &admin, hotel_id).await;
+    let delete = client
+        .delete(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", admin))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(update.status(), StatusCode::OK);
+    assert_eq!(delete.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_admin_assigns_hotel_manager() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "role_assigned").await;
+    let guest_token = register(&client, "role_not_manager").await;
+    let admin = admin_token(&client).await;
+    let manager_id = profile(&client, &token).await["id"].as_i64().unwrap();
+    let guest_id = profile(&client, &guest_token).await["id"].as_i64().unwrap();
+
+    let assigned = create_hotel(&client, &admin, Some(manager_id)).await;
+    let invalid = create_hotel(&client, &admin, Some(guest_id)).await;
+
+    assert_eq!(assigned.status(), StatusCode::CREATED);
+    let hotel_id = assigned.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    let response = update_hotel(&client, &token, hotel_id).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_update_role_403_not_admin() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "role_escalator").await;
+    let user_id = profile(&client, &token).await["id"].as_i64().unwrap();
+
+    let response = client
+        .put(format!("{}/admin/users/{}/role", BASE_URL, user_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "role": "admin" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_update_role_404_not_found() {
+    let client = reqwest::Client::new();
+    let admin = admin_token(&client).await;
+
+    let response = client
+        .put(format!("{}/admin/users/{}/role", BASE_URL, i32::MAX))
+        .header("Authorization", format!("Bearer {}", admin))
+        .json(&json!({ "role": "hotel_manager" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_update_role_revokes_existing_tokens() {
+    let client = reqwest::Client::new();
+    let email = unique_email("role_demoted");
+    let register_body = register_user(&client, &email).await;
+    promote_to_manager(&client, &register_body, &email).await;
+    let session = client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": "password123" }))
+        .send()
+        .await
+        .expect("Failed to login")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    let token = session["token"].as_str().unwrap();
+    let user_id = register_body["user"]["id"].as_i64().unwrap();
+    let admin = admin_token(&client).await;
+
+    let demoted = client
+        .put(format!("{}/admin/users/{}/role", BASE_URL, user_id))
+        .header("Authorization", format!("Bearer {}", admin))
+        .json(&json!({ "role": "guest" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let stale = create_hotel(&client, token, None).await;
+    let refreshed = client
+        .post(format!("{}/auth/refresh", BASE_URL))
+        .json(&json!({ "refresh_token": session["refresh_token"] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let fresh_token = login(&client, &email).await;
+    let fresh = create_hotel(&client, &fresh_token, None).await;
+
+    assert_eq!(demoted.status(), StatusCode::OK);
+    assert_eq!(stale.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(refreshed.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(fresh.status(), StatusCode::FORBIDDEN);
+}
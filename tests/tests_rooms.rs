@@ -0,0 +1,362 @@
+mod common;
+
+use common::{BASE_URL, register_manager};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn create_hotel(client: &reqwest::Client, token: &str) -> i64 {
+    let create_resp = client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Room Test Hotel",
+            "description": "A hotel with rooms",
+            "address": "1 Room St",
+            "city": "Berlin",
+            "country": "Germany"
+        }))
+        .send()
+        .await
+        .expect("Failed to create hotel");
+    let create_body = create_resp.json::<serde_json::Value>().await.unwrap();
+    create_body["id"].as_i64().unwrap()
+}
+
+async fn create_room_type(client: &reqwest::Client, token: &str, hotel_id: i64) -> i64 {
+    let create_resp = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Double",
+            "capacity": 2,
+            "bed_configuration": "1 queen",
+            "base_price": "120.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to create room type");
+    let create_body = create_resp.json::<serde_json::Value>().await.unwrap();
+    create_body["id"].as_i64().unwrap()
+}
+
+#[tokio::test]
+async fn test_create_room_type_201_created() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_type_creator").await;
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Deluxe Suite",
+            "description": "Sea view",
+            "capacity": 3,
+            "bed_configuration": "1 king + 1 sofa bed",
+            "base_price": "249.90"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["hotel_id"], hotel_id);
+    assert_eq!(body["name"], "Deluxe Suite");
+    assert_eq!(body["capacity"], 3);
+    assert_eq!(body["base_price"], "249.90");
+}
+
+#[tokio::test]
+async fn test_create_room_type_400_invalid_capacity() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_type_capacity").await;
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Broken",
+            "capacity": 0,
+            "bed_configuration": "none",
+            "base_price": "10.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_create_room_type_422_invalid_fields() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_type_invalid").await;
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "x".repeat(101),
+            "capacity": 2,
+            "bed_configuration": "   ",
+            "base_price": "100000000.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let fields: Vec<&str> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["base_price", "bed_configuration", "name"]);
+}
+
+#[tokio::test]
+async fn test_create_room_type_401_unauthorized() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/hotels/1/room-types", BASE_URL))
+        .json(&json!({
+            "name": "Double",
+            "capacity": 2,
+            "bed_configuration": "1 queen",
+            "base_price": "120.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_create_room_type_404_hotel_not_found() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_type_missing_hotel").await;
+
+    let response = client
+        .post(format!("{}/hotels/999999/room-types", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Double",
+            "capacity": 2,
+            "bed_configuration": "1 queen",
+            "base_price": "120.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_list_room_types_200_ok() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_type_lister").await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let room_type_id = create_room_type(&client, &token, hotel_id).await;
+
+    let response = client
+        .get(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    let room_types = body.as_array().unwrap();
+    assert_eq!(room_types.len(), 1);
+    assert_eq!(room_types[0]["id"], room_type_id);
+}
+
+#[tokio::test]
+async fn test_update_room_type_200_ok() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_type_updater").await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let room_type_id = create_room_type(&client, &token, hotel_id).await;
+
+    let response = client
+        .put(format!(
+            "{}/hotels/{}/room-types/{}",
+            BASE_URL, hotel_id, room_type_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({"base_price": "135.50"}))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["base_price"], "135.50");
+    assert_eq!(body["name"], "Double");
+}
+
+#[tokio::test]
+async fn test_get_room_type_404_wrong_hotel() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_type_wrong_hotel").await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let other_hotel_id = create_hotel(&client, &token).await;
+    let room_type_id = create_room_type(&client, &token, hotel_id).await;
+
+    let response = client
+        .get(format!(
+            "{}/hotels/{}/room-types/{}",
+            BASE_URL, other_hotel_id, room_type_id
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_room_201_created() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_creator").await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let room_type_id = create_room_type(&client, &token, hotel_id).await;
+
+    let response = client
+        .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "room_type_id": room_type_id,
+            "room_number": "101",
+            "floor": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["room_type_id"], room_type_id);
+    assert_eq!(body["room_number"], "101");
+    assert_eq!(body["status"], "available");
+}
+
+#[tokio::test]
+async fn test_create_room_404_room_type_of_other_hotel() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_foreign_type").await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let other_hotel_id = create_hotel(&client, &token).await;
+    let room_type_id = create_room_type(&client, &token, other_hotel_id).await;
+
+    let response = client
+        .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "room_type_id": room_type_id,
+            "room_number": "101"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_room_409_duplicate_number() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_duplicate").await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let room_type_id = create_room_type(&client, &token, hotel_id).await;
+    let payload = json!({
+        "room_type_id": room_type_id,
+        "room_number": "202"
+    });
+    client
+        .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send first request");
+
+    let response = client
+        .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send second request");
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_update_and_delete_room() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "room_lifecycle").await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let room_type_id = create_room_type(&client, &token, hotel_id).await;
+    let create_resp = client
+        .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "room_type_id": room_type_id,
+            "room_number": "303",
+            "floor": 3
+        }))
+        .send()
+        .await
+        .expect("Failed to create room");
+    let room_id = create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap();
+
+    let update_resp = client
+        .put(format!(
+            "{}/hotels/{}/rooms/{}",
+            BASE_URL, hotel_id, room_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({"status": "maintenance"}))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(update_resp.status(), StatusCode::OK);
+    let body = update_resp.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["status"], "maintenance");
+    assert_eq!(body["room_number"], "303");
+
+    let delete_resp = client
+        .delete(format!(
+            "{}/hotels/{}/rooms/{}",
+            BASE_URL, hotel_id, room_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(delete_resp.status(), StatusCode::NO_CONTENT);
+
+    let get_resp = client
+        .get(format!(
+            "{}/hotels/{}/rooms/{}",
+            BASE_URL, hotel_id, room_id
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(get_resp.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,221 @@
+mod common;
+
+use common::{BASE_URL, admin_token};
+use reqwest::StatusCode;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A word no other hotel contains, so each test only sees its own hotels.
+fn unique_word() -> String {
+    let n: u128 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis()
+        * 100
+        + COUNTER.fetch_add(1, Ordering::SeqCst) as u128;
+    let mut word = String::from("zq");
+    let mut rest = n;
+    while rest > 0 {
+        word.push((b'a' + (rest % 26) as u8) as char);
+        rest /= 26;
+    }
+    word
+}
+
+async fn create_hotel(client: &reqwest::Client, token: &str, hotel: serde_json::Value) -> i64 {
+    let response = client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&hotel)
+        .send()
+        .await
+        .expect("Failed to create hotel");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    response.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap()
+}
+
+async fn search(client: &reqwest::Client, query: &[(&str, &str)]) -> reqwest::Response {
+    client
+        .get(format!("{}/hotels/search", BASE_URL))
+        .query(query)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+fn ids(body: &serde_json::Value) -> Vec<i64> {
+    body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["hotel"]["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_search_200_ranked_with_snippets() {
+    let client = reqwest::Client::new();
+    let admin = admin_token(&client).await;
+    let city = unique_word();
+
+    let best = create_hotel(
+        &client,
+        &admin,
+        json!({
+            "name": "Golden Key",
+            "description": "Quiet rooms in the heart of the old town, steps from the castle.",
+            "address": "Nerudova 27",
+            "city": city,
+            "country": "Czechia"
+        }),
+    )
+    .await;
+    create_hotel(
+        &client,
+        &admin,
+        json!({
+            "name": "Party Hostel",
+            "description": "Loud bar and dancing every night.",
+            "address": "Wenceslas Square 1",
+            "city": city,
+            "country": "Czechia"
+        }),
+    )
+    .await;
+
+    let query = format!("quiet hotel near old town {}", city);
+    let response = search(&client, &[("q", &query)]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["fuzzy"], false);
+    assert_eq!(ids(&body)[0], best);
+    assert!(
+        body["items"][0]["rank"].as_f64().unwrap() > body["items"][1]["rank"].as_f64().unwrap()
+    );
+    let snippet = body["items"][0]["snippet"].as_str().unwrap();
+    assert!(snippet.contains("<mark>Quiet</mark>"));
+    assert!(snippet.contains("<mark>old</mark> <mark>town</mark>"));
+}
+
+#[tokio::test]
+async fn test_search_200_stems_in_hotel_language() {
+    let client = reqwest::Client::new();
+    let admin = admin_token(&client).await;
+    let city = unique_word();
+
+    let hotel = create_hotel(
+        &client,
+        &admin,
+        json!({
+            "name": "Haus am Graben",
+            "description": "Ruhige Zimmer mitten in der Altstadt.",
+            "address": "Graben 1",
+            "city": city,
+            "country": "Österreich",
+            "language": "de"
+        }),
+    )
+    .await;
+
+    // Only the German stemmer reduces "ruhigen" and "ruhige" to the same word.
+    let query = format!("ruhigen {}", city);
+    let response = search(&client, &[("q", &query), ("lang", "de")]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(ids(&body), vec![hotel]);
+    assert_eq!(body["items"][0]["hotel"]["language"], "de");
+    assert!(
+        body["items"][0]["snippet"]
+            .as_str()
+            .unwrap()
+            .contains("<mark>Ruhige</mark>")
+    );
+}
+
+#[tokio::test]
+async fn test_search_200_fuzzy_fallback_for_typos() {
+    let client = reqwest::Client::new();
+    let admin = admin_token(&client).await;
+    let name = unique_word();
+
+    let hotel = create_hotel(
+        &client,
+        &admin,
+        json!({
+            "name": format!("{}view", name),
+            "address": "1 Lake Road",
+            "city": "Bled",
+            "country": "Slovenia"
+        }),
+    )
+    .await;
+
+    let typo = format!("{}veiw", name);
+    let response = search(&client, &[("q", &typo)]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["fuzzy"], true);
+    assert_eq!(ids(&body)[0], hotel);
+}
+
+#[tokio::test]
+async fn test_search_200_escapes_snippets_and_query_syntax() {
+    let client = reqwest::Client::new();
+    let admin = admin_token(&client).await;
+    let city = unique_word();
+
+    let hotel = create_hotel(
+        &client,
+        &admin,
+        json!({
+            "name": "Harbour Inn",
+            "description": "Fish & chips for < 5 euros, served \"fresh\" at 10:30.",
+            "address": "Quay 4",
+            "city": city,
+            "country": "Portugal"
+        }),
+    )
+    .await;
+
+    // Characters that mean something in tsquery syntax are plain text here.
+    let query = format!("chips! ({}) 10:30 & | fish's", city);
+    let response = search(&client, &[("q", &query)]).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["fuzzy"], false);
+    assert_eq!(ids(&body), vec![hotel]);
+    let snippet = body["items"][0]["snippet"].as_str().unwrap();
+    assert!(snippet.contains("<mark>Fish</mark> &amp; <mark>chips</mark>"));
+    assert!(snippet.contains("&lt; 5 euros"));
+    assert!(snippet.contains("&quot;fresh&quot;"));
+}
+
+#[tokio::test]
+async fn test_search_422_invalid_query() {
+    let client = reqwest::Client::new();
+
+    let response = search(&client, &[("q", "")]).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "q");
+
+    let response = search(&client, &[("q", "   ")]).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "q");
+    assert_eq!(body["errors"][0]["code"], "blank");
+
+    let response = search(&client, &[("q", "prague"), ("lang", "english")]).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "lang");
+    assert_eq!(body["errors"][0]["code"], "language");
+}
@@ -0,0 +1,352 @@
+mod common;
+
+use common::{BASE_URL, admin_token, register_manager, register_user, unique_email};
+use reqwest::StatusCode;
+use serde_json::json;
+
+async fn create_hotel(client: &reqwest::Client, token: &str) -> i64 {
+    let create_resp = client
+        .post(format!("{}/hotels", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Staffed Hotel",
+            "address": "1 Staff St",
+            "city": "Porto",
+            "country": "Portugal"
+        }))
+        .send()
+        .await
+        .expect("Failed to create hotel");
+    create_resp.json::<serde_json::Value>().await.unwrap()["id"]
+        .as_i64()
+        .unwrap()
+}
+
+async fn invite(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    email: &str,
+    role: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("{}/hotels/{}/staff", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "email": email, "role": role }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn remove(
+    client: &reqwest::Client,
+    token: &str,
+    hotel_id: i64,
+    user_id: i64,
+) -> reqwest::Response {
+    client
+        .delete(format!(
+            "{}/hotels/{}/staff/{}",
+            BASE_URL, hotel_id, user_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn list_staff(client: &reqwest::Client, token: &str, hotel_id: i64) -> reqwest::Response {
+    client
+        .get(format!("{}/hotels/{}/staff", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn update_hotel(client: &reqwest::Client, token: &str, hotel_id: i64) -> reqwest::Response {
+    client
+        .put(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Renamed Staffed Hotel",
+            "address": "2 Staff St",
+            "city": "Porto",
+            "country": "Portugal"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn test_list_staff_200_creator_is_owner() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_owner").await;
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = list_staff(&client, &token, hotel_id).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["role"], "owner");
+}
+
+#[tokio::test]
+async fn test_invite_staff_201_created() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_inviter").await;
+    let email = unique_email("staff_front_desk");
+    let registered = register_user(&client, &email).await;
+    let user_id = registered["user"]["id"].as_i64().unwrap();
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = invite(&client, &token, hotel_id, &email, "front_desk").await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["user_id"], user_id);
+    assert_eq!(body["role"], "front_desk");
+    let staff = list_staff(&client, &token, hotel_id)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    assert_eq!(staff.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_invite_staff_404_unknown_user() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_unknown").await;
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = invite(
+        &client,
+        &token,
+        hotel_id,
+        &unique_email("staff_nobody"),
+        "housekeeping",
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_invite_staff_409_already_member() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_duplicate").await;
+    let email = unique_email("staff_twice");
+    register_user(&client, &email).await;
+    let hotel_id = create_hotel(&client, &token).await;
+    invite(&client, &token, hotel_id, &email, "housekeeping").await;
+
+    let response = invite(&client, &token, hotel_id, &email, "front_desk").await;
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_front_desk_403_hotel_management() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_desk_owner").await;
+    let email = unique_email("staff_desk");
+    let registered = register_user(&client, &email).await;
+    let desk_token = registered["token"].as_str().unwrap().to_string();
+    let hotel_id = create_hotel(&client, &token).await;
+    invite(&client, &token, hotel_id, &email, "front_desk").await;
+
+    let staff = list_staff(&client, &desk_token, hotel_id).await;
+    let update = update_hotel(&client, &desk_token, hotel_id).await;
+    let room_type = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", desk_token))
+        .json(&json!({
+            "name": "Double",
+            "capacity": 2,
+            "bed_configuration": "1 queen",
+            "base_price": "100.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(staff.status(), StatusCode::OK);
+    assert_eq!(update.status(), StatusCode::FORBIDDEN);
+    assert_eq!(room_type.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_housekeeping_updates_room_status() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_rooms_owner").await;
+    let email = unique_email("staff_housekeeping");
+    let registered = register_user(&client, &email).await;
+    let housekeeping_token = registered["token"].as_str().unwrap().to_string();
+    let hotel_id = create_hotel(&client, &token).await;
+    invite(&client, &token, hotel_id, &email, "housekeeping").await;
+    let room_type_id = client
+        .post(format!("{}/hotels/{}/room-types", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "name": "Single",
+            "capacity": 1,
+            "bed_configuration": "1 single",
+            "base_price": "80.00"
+        }))
+        .send()
+        .await
+        .expect("Failed to create room type")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    let room_id = client
+        .post(format!("{}/hotels/{}/rooms", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({
+            "room_type_id": room_type_id,
+            "room_number": "101"
+        }))
+        .send()
+        .await
+        .expect("Failed to create room")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["id"]
+        .as_i64()
+        .unwrap();
+    let room_url = format!("{}/hotels/{}/rooms/{}", BASE_URL, hotel_id, room_id);
+
+    let update = client
+        .put(&room_url)
+        .header("Authorization", format!("Bearer {}", housekeeping_token))
+        .json(&json!({ "status": "maintenance" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let delete = client
+        .delete(&room_url)
+        .header("Authorization", format!("Bearer {}", housekeeping_token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(update.status(), StatusCode::OK);
+    assert_eq!(delete.status(), StatusCode::FORBIDDEN);
+}
+
+async fn owner_id(client: &reqwest::Client, token: &str, hotel_id: i64) -> i64 {
+    let staff = list_staff(client, token, hotel_id)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    staff[0]["user_id"].as_i64().unwrap()
+}
+
+#[tokio::test]
+async fn test_manager_manages_hotel_but_not_owners() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_mgr_owner").await;
+    let email = unique_email("staff_mgr");
+    let registered = register_user(&client, &email).await;
+    let manager_token = registered["token"].as_str().unwrap().to_string();
+    let desk_email = unique_email("staff_mgr_desk");
+    register_user(&client, &desk_email).await;
+    let owner_email = unique_email("staff_mgr_new_owner");
+    register_user(&client, &owner_email).await;
+    let hotel_id = create_hotel(&client, &token).await;
+    let owner_id = owner_id(&client, &token, hotel_id).await;
+    invite(&client, &token, hotel_id, &email, "manager").await;
+
+    let update = update_hotel(&client, &manager_token, hotel_id).await;
+    let invite_desk = invite(&client, &manager_token, hotel_id, &desk_email, "front_desk").await;
+    let invite_owner = invite(&client, &manager_token, hotel_id, &owner_email, "owner").await;
+    let remove_owner = remove(&client, &manager_token, hotel_id, owner_id).await;
+    let delete = client
+        .delete(format!("{}/hotels/{}", BASE_URL, hotel_id))
+        .header("Authorization", format!("Bearer {}", manager_token))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(update.status(), StatusCode::OK);
+    assert_eq!(invite_desk.status(), StatusCode::CREATED);
+    assert_eq!(invite_owner.status(), StatusCode::FORBIDDEN);
+    assert_eq!(remove_owner.status(), StatusCode::FORBIDDEN);
+    assert_eq!(delete.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_remove_staff_204_revokes_access() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_remover").await;
+    let email = unique_email("staff_removed");
+    let registered = register_user(&client, &email).await;
+    let desk_token = registered["token"].as_str().unwrap().to_string();
+    let user_id = registered["user"]["id"].as_i64().unwrap();
+    let hotel_id = create_hotel(&client, &token).await;
+    invite(&client, &token, hotel_id, &email, "front_desk").await;
+
+    let response = remove(&client, &token, hotel_id, user_id).await;
+    let after = list_staff(&client, &desk_token, hotel_id).await;
+    let again = remove(&client, &token, hotel_id, user_id).await;
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(after.status(), StatusCode::FORBIDDEN);
+    assert_eq!(again.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_remove_staff_409_last_owner() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_last_owner").await;
+    let email = unique_email("staff_second_owner");
+    let registered = register_user(&client, &email).await;
+    let second_token = registered["token"].as_str().unwrap().to_string();
+    let second_id = registered["user"]["id"].as_i64().unwrap();
+    let hotel_id = create_hotel(&client, &token).await;
+    let owner_id = owner_id(&client, &token, hotel_id).await;
+
+    let last = remove(&client, &token, hotel_id, owner_id).await;
+    assert_eq!(last.status(), StatusCode::CONFLICT);
+
+    invite(&client, &token, hotel_id, &email, "owner").await;
+    let response = remove(&client, &second_token, hotel_id, owner_id).await;
+    let last_again = remove(&client, &second_token, hotel_id, second_id).await;
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(last_again.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_staff_403_non_member() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_private").await;
+    let other_token = register_manager(&client, "staff_outsider").await;
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = list_staff(&client, &other_token, hotel_id).await;
+    let missing = list_staff(&client, &other_token, i32::MAX as i64).await;
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_admin_manages_staff_of_any_hotel() {
+    let client = reqwest::Client::new();
+    let token = register_manager(&client, "staff_admin_target").await;
+    let email = unique_email("staff_admin_invitee");
+    register_user(&client, &email).await;
+    let admin = admin_token(&client).await;
+    let hotel_id = create_hotel(&client, &token).await;
+
+    let response = invite(&client, &admin, hotel_id, &email, "owner").await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
@@ -0,0 +1,344 @@
+mod common;
+
+use common::{BASE_URL, register, register_user, unique_email};
+use reqwest::StatusCode;
+use serde_json::json;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+fn totp(secret: &str) -> TOTP {
+    TOTP::new_unchecked(
+        Algorithm::SHA1,
+        6,
+        1,
+        30,
+        Secret::Encoded(secret.to_string()).to_bytes().unwrap(),
+        None,
+        String::new(),
+    )
+}
+
+fn current_code(secret: &str) -> String {
+    totp(secret).generate_current().unwrap()
+}
+
+/// Code from the previous time step, still accepted thanks to clock skew.
+fn previous_code(secret: &str) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    totp(secret).generate(now - 30)
+}
+
+async fn setup(client: &reqwest::Client, token: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/2fa/setup", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn confirm(client: &reqwest::Client, token: &str, code: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/2fa/confirm", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "code": code }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+/// Registers a user with 2FA enabled, returning their email, TOTP secret and
+/// recovery codes.
+async fn register_with_two_factor(
+    client: &reqwest::Client,
+    prefix: &str,
+) -> (String, String, Vec<String>) {
+    let email = unique_email(prefix);
+    let token = register_user(client, &email).await["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let secret = setup(client, &token)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["secret"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    // Codes are single use, so leave the current one for logging in.
+    let recovery_codes = confirm(client, &token, &previous_code(&secret))
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["recovery_codes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|code| code.as_str().unwrap().to_string())
+        .collect();
+
+    (email, secret, recovery_codes)
+}
+
+async fn login(client: &reqwest::Client, email: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/login", BASE_URL))
+        .json(&json!({ "email": email, "password": "password123" }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn verify(client: &reqwest::Client, challenge_token: &str, code: &str) -> reqwest::Response {
+    client
+        .post(format!("{}/auth/2fa/verify", BASE_URL))
+        .json(&json!({ "challenge_token": challenge_token, "code": code }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn challenge(client: &reqwest::Client, email: &str) -> String {
+    login(client, email)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["challenge_token"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_setup_returns_otpauth_uri() {
+    let client = reqwest::Client::new();
+    let token = register(&client, "2fa_setup").await;
+
+    let response = setup(&client, &token).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(body["secret"].is_string());
+    assert!(
+        body["otpauth_uri"]
+            .as_str()
+            .unwrap()
+            .starts_with("otpauth://totp/")
+    );
+}
+
+#[tokio::test]
+async fn test_setup_401_no_token() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/auth/2fa/setup", BASE_URL))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_confirm_409_without_setup() {
+    let client = reqwest::Client::new();
+    let token = register(&client, "2fa_no_setup").await;
+
+    let response = confirm(&client, &token, "123456").await;
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_confirm_400_wrong_code() {
+    let client = reqwest::Client::new();
+    let token = register(&client, "2fa_wrong_code").await;
+    let secret = setup(&client, &token)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["secret"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let wrong_code = if current_code(&secret) == "000000" {
+        "111111"
+    } else {
+        "000000"
+    };
+
+    let response = confirm(&client, &token, wrong_code).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_confirm_enables_two_factor() {
+    let client = reqwest::Client::new();
+    let email = unique_email("2fa_confirm");
+    let token = register_user(&client, &email).await["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let secret = setup(&client, &token)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["secret"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = confirm(&client, &token, &current_code(&secret)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["recovery_codes"].as_array().unwrap().len(), 10);
+
+    let profile: serde_json::Value = client
+        .get(format!("{}/auth/profile", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(profile["two_factor_enabled"], true);
+    assert_eq!(setup(&client, &token).await.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_login_202_requires_second_factor() {
+    let client = reqwest::Client::new();
+    let (email, _, _) = register_with_two_factor(&client, "2fa_login").await;
+
+    let response = login(&client, &email).await;
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(body["challenge_token"].is_string());
+    assert!(body["token"].is_null());
+}
+
+#[tokio::test]
+async fn test_verify_200_with_totp_code() {
+    let client = reqwest::Client::new();
+    let (email, secret, _) = register_with_two_factor(&client, "2fa_verify").await;
+    let challenge_token = challenge(&client, &email).await;
+
+    let response = verify(&client, &challenge_token, &current_code(&secret)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert!(body["token"].is_string());
+    assert!(body["refresh_token"].is_string());
+    assert_eq!(
+        verify(&client, &challenge_token, &current_code(&secret))
+            .await
+            .status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn test_verify_totp_code_is_single_use() {
+    let client = reqwest::Client::new();
+    let (email, secret, _) = register_with_two_factor(&client, "2fa_totp_replay").await;
+    let code = current_code(&secret);
+
+    let first = verify(&client, &challenge(&client, &email).await, &code).await;
+    let replayed = verify(&client, &challenge(&client, &email).await, &code).await;
+    let earlier = verify(
+        &client,
+        &challenge(&client, &email).await,
+        &previous_code(&secret),
+    )
+    .await;
+
+    assert_eq!(first.status(), StatusCode::OK);
+    assert_eq!(replayed.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(earlier.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_verify_recovery_code_is_single_use() {
+    let client = reqwest::Client::new();
+    let (email, _, recovery_codes) = register_with_two_factor(&client, "2fa_recovery").await;
+
+    let first = verify(
+        &client,
+        &challenge(&client, &email).await,
+        &recovery_codes[0],
+    )
+    .await;
+    let second = verify(
+        &client,
+        &challenge(&client, &email).await,
+        &recovery_codes[0],
+    )
+    .await;
+
+    assert_eq!(first.status(), StatusCode::OK);
+    assert_eq!(second.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_verify_400_wrong_code() {
+    let client = reqwest::Client::new();
+    let (email, _, _) = register_with_two_factor(&client, "2fa_verify_wrong").await;
+
+    let response = verify(&client, &challenge(&client, &email).await, "not-a-code").await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_verify_401_unknown_challenge() {
+    let client = reqwest::Client::new();
+
+    let response = verify(&client, "not-a-challenge", "123456").await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_verify_retry_after_wrong_code() {
+    let client = reqwest::Client::new();
+    let (email, _, recovery_codes) = register_with_two_factor(&client, "2fa_retry").await;
+    let challenge_token = challenge(&client, &email).await;
+
+    let wrong = verify(&client, &challenge_token, "not-a-code").await;
+    let retry = verify(&client, &challenge_token, &recovery_codes[0]).await;
+
+    assert_eq!(wrong.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(retry.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_verify_concurrent_codes_spend_one() {
+    let client = reqwest::Client::new();
+    let (email, _, recovery_codes) = register_with_two_factor(&client, "2fa_concurrent").await;
+    let challenge_token = challenge(&client, &email).await;
+
+    let (first, second) = tokio::join!(
+        verify(&client, &challenge_token, &recovery_codes[0]),
+        verify(&client, &challenge_token, &recovery_codes[1]),
+    );
+    let statuses = [first.status(), second.status()];
+    assert_eq!(statuses.iter().filter(|s| **s == StatusCode::OK).count(), 1);
+
+    // The losing request never got to spend its code.
+    let unspent = if first.status() == StatusCode::OK {
+        &recovery_codes[1]
+    } else {
+        &recovery_codes[0]
+    };
+    let response = verify(&client, &challenge(&client, &email).await, unspent).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
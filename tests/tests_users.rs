@@ -1,8 +1,9 @@
+mod common;
+
+use common::BASE_URL;
 use reqwest::StatusCode;
 use serde_json::json;
 
-const BASE_URL: &str = "http://localhost:8000";
-
 #[tokio::test]
 async fn test_register_201_created() {
     let client = reqwest::Client::new();
@@ -31,7 +32,7 @@ async fn test_register_201_created() {
 }
 
 #[tokio::test]
-async fn test_register_400_invalid_email() {
+async fn test_register_422_invalid_email() {
     let client = reqwest::Client::new();
     let payload = json!({
         "email": "invalid-email",
@@ -47,13 +48,15 @@ async fn test_register_400_invalid_email() {
         .await
         .expect("Failed to send request");
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-    let body = response.text().await.unwrap();
-    assert!(body.contains("Invalid email") || body.contains("email"));
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["code"], "validation_failed");
+    assert_eq!(body["errors"][0]["field"], "email");
+    assert_eq!(body["errors"][0]["code"], "email");
 }
 
 #[tokio::test]
-async fn test_register_400_short_password() {
+async fn test_register_422_short_password() {
     let client = reqwest::Client::new();
     let payload = json!({
         "email": format!("user_{}@example.com", std::time::SystemTime::now()
@@ -72,13 +75,40 @@ async fn test_register_400_short_password() {
         .await
         .expect("Failed to send request");
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-    let body = response.text().await.unwrap();
-    assert!(body.contains("Password") || body.contains("password") || body.contains("6"));
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "password");
+    assert_eq!(body["errors"][0]["code"], "length");
 }
 
 #[tokio::test]
-async fn test_register_400_email_already_exists() {
+async fn test_register_422_blank_name() {
+    let client = reqwest::Client::new();
+    let payload = json!({
+        "email": format!("blank_{}@example.com", std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()),
+        "password": "password123",
+        "first_name": "   ",
+        "last_name": "Doe"
+    });
+
+    let response = client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "first_name");
+    assert_eq!(body["errors"][0]["code"], "blank");
+}
+
+#[tokio::test]
+async fn test_register_409_email_already_exists() {
     let client = reqwest::Client::new();
     let email = format!(
         "duplicate_{}@example.com",
@@ -113,9 +143,15 @@ async fn test_register_400_email_already_exists() {
         .await
         .expect("Failed to send second request");
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-    let body = response.text().await.unwrap();
-    assert!(body.contains("already exists") || body.contains("Email"));
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["status"], 409);
+    assert_eq!(body["code"], "conflict");
+    assert_eq!(body["detail"], "Email already exists");
 }
 
 #[tokio::test]
@@ -266,8 +302,13 @@ async fn test_profile_401_unauthorized_missing_token() {
         .expect("Failed to send request");
 
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
-    let body = response.text().await.unwrap();
-    assert!(body.contains("Missing") || body.contains("token") || body.contains("Unauthorized"));
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["code"], "unauthorized");
+    assert_eq!(body["detail"], "Missing authorization token");
 }
 
 #[tokio::test]
@@ -344,6 +385,102 @@ async fn test_update_profile_200_ok() {
     assert_eq!(body["last_name"], "Smith");
 }
 
+async fn register_token(client: &reqwest::Client, prefix: &str) -> String {
+    client
+        .post(format!("{}/auth/register", BASE_URL))
+        .json(&json!({
+            "email": format!("{}_{}@example.com", prefix, std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis()),
+            "password": "password123",
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .send()
+        .await
+        .expect("Failed to register")
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_update_profile_422_missing_last_name() {
+    let client = reqwest::Client::new();
+    let token = register_token(&client, "profile_put").await;
+
+    let response = client
+        .put(format!("{}/auth/profile", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "first_name": "Jane" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_patch_profile_200_leaves_absent_fields() {
+    let client = reqwest::Client::new();
+    let token = register_token(&client, "profile_patch").await;
+
+    let response = client
+        .patch(format!("{}/auth/profile", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "first_name": "Jane" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["first_name"], "Jane");
+    assert_eq!(body["last_name"], "Doe");
+}
+
+#[tokio::test]
+async fn test_patch_profile_422_null_name() {
+    let client = reqwest::Client::new();
+    let token = register_token(&client, "profile_patch_null").await;
+
+    let response = client
+        .patch(format!("{}/auth/profile", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "last_name": null }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "last_name");
+    assert_eq!(body["errors"][0]["code"], "not_null");
+}
+
+#[tokio::test]
+async fn test_patch_profile_422_blank_name() {
+    let client = reqwest::Client::new();
+    let token = register_token(&client, "profile_patch_blank").await;
+
+    let response = client
+        .patch(format!("{}/auth/profile", BASE_URL))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "first_name": " " }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.json::<serde_json::Value>().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "first_name");
+    assert_eq!(body["errors"][0]["code"], "blank");
+}
+
 #[tokio::test]
 async fn test_update_profile_401_unauthorized() {
     let client = reqwest::Client::new();
@@ -0,0 +1,79 @@
+mod common;
+
+use common::{BASE_URL, register_user, unique_email};
+use reqwest::StatusCode;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_register_starts_unverified() {
+    let client = reqwest::Client::new();
+
+    let body = register_user(&client, &unique_email("verify_register")).await;
+
+    assert_eq!(body["user"]["email_verified"], false);
+}
+
+#[tokio::test]
+async fn test_profile_reports_unverified() {
+    let client = reqwest::Client::new();
+    let registered = register_user(&client, &unique_email("verify_profile")).await;
+
+    let response = client
+        .get(format!("{}/auth/profile", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", registered["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["email_verified"], false);
+}
+
+#[tokio::test]
+async fn test_verify_email_400_invalid_token() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/auth/verify-email", BASE_URL))
+        .json(&json!({ "token": "not-a-token" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_resend_verification_202() {
+    let client = reqwest::Client::new();
+    let registered = register_user(&client, &unique_email("verify_resend")).await;
+
+    let response = client
+        .post(format!("{}/auth/verify-email/resend", BASE_URL))
+        .header(
+            "Authorization",
+            format!("Bearer {}", registered["token"].as_str().unwrap()),
+        )
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn test_resend_verification_401_no_token() {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/auth/verify-email/resend", BASE_URL))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}